@@ -0,0 +1,115 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+// `#[derive(BenchmarkData)]` generates `fn default_instance() -> Self`, filling
+// every field with a fixed, non-default value chosen from its type:
+//
+// - integers: `1`, floats: `1.0`, `bool`: `true`, `char`: `'a'`
+// - `String` and `&str`: `"test"`
+// - `Option<T>`: `Some(..)`, `Vec<T>`, `HashMap<K, V>`, `BTreeMap<K, V>`: one element,
+//   `Box<T>`, tuples and arrays: built from their element types
+// - any other type: `<T>::default_instance()`, so nested payload structs derive it too
+#[proc_macro_derive(BenchmarkData)]
+pub fn derive_benchmark_data(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => {
+                let values = fields.named.iter().map(|field| {
+                    let ident = &field.ident;
+                    let value = value_for(&field.ty);
+                    quote! { #ident: #value }
+                });
+                quote! { #name { #(#values),* } }
+            }
+            Fields::Unnamed(fields) => {
+                let values = fields.unnamed.iter().map(|field| value_for(&field.ty));
+                quote! { #name(#(#values),*) }
+            }
+            Fields::Unit => quote! { #name },
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "BenchmarkData can only be derived for structs")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            pub fn default_instance() -> Self {
+                #body
+            }
+        }
+    }
+    .into()
+}
+
+fn value_for(ty: &Type) -> TokenStream2 {
+    match ty {
+        Type::Reference(reference) if is_str(&reference.elem) => quote! { "test" },
+        Type::Tuple(tuple) => {
+            let values = tuple.elems.iter().map(value_for);
+            quote! { (#(#values,)*) }
+        }
+        Type::Array(array) => {
+            let value = value_for(&array.elem);
+            quote! { ::core::array::from_fn(|_| #value) }
+        }
+        Type::Paren(paren) => value_for(&paren.elem),
+        Type::Group(group) => value_for(&group.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().unwrap();
+            let args = generic_types(&segment.arguments);
+            match (segment.ident.to_string().as_str(), args.as_slice()) {
+                ("u8" | "u16" | "u32" | "u64" | "u128" | "usize"
+                    | "i8" | "i16" | "i32" | "i64" | "i128" | "isize", []) => quote! { 1 },
+                ("f32" | "f64", []) => quote! { 1.0 },
+                ("bool", []) => quote! { true },
+                ("char", []) => quote! { 'a' },
+                ("String", []) => quote! { ::std::string::String::from("test") },
+                ("Option", [inner]) => {
+                    let value = value_for(inner);
+                    quote! { ::std::option::Option::Some(#value) }
+                }
+                ("Box", [inner]) => {
+                    let value = value_for(inner);
+                    quote! { ::std::boxed::Box::new(#value) }
+                }
+                ("Vec", [inner]) => {
+                    let value = value_for(inner);
+                    quote! { ::std::vec![#value] }
+                }
+                ("HashMap" | "BTreeMap", [key, value]) => {
+                    let key = value_for(key);
+                    let value = value_for(value);
+                    quote! { ::std::iter::once((#key, #value)).collect() }
+                }
+                _ => quote! { <#ty>::default_instance() },
+            }
+        }
+        _ => quote! { <#ty>::default_instance() },
+    }
+}
+
+fn is_str(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("str"))
+}
+
+// The type arguments of `Option<T>`, `HashMap<K, V>` and the like
+fn generic_types(arguments: &PathArguments) -> Vec<&Type> {
+    match arguments {
+        PathArguments::AngleBracketed(arguments) => arguments.args.iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
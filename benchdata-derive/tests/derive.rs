@@ -0,0 +1,91 @@
+// `default_instance()` as `#[derive(BenchmarkData)]` expands it, one field per
+// rule in the crate docs
+use benchdata_derive::BenchmarkData;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(BenchmarkData, Debug, PartialEq)]
+struct Scalars {
+    byte: u8,
+    count: u64,
+    offset: i128,
+    size: usize,
+    ratio: f32,
+    score: f64,
+    active: bool,
+    initial: char,
+}
+
+#[derive(BenchmarkData, Debug, PartialEq)]
+struct Containers<'a> {
+    name: String,
+    label: &'a str,
+    parent: Option<u32>,
+    boxed: Box<u32>,
+    tags: Vec<String>,
+    counts: HashMap<String, u32>,
+    sorted: BTreeMap<u16, bool>,
+    pair: (u8, String),
+    rgba: [u8; 4],
+}
+
+#[derive(BenchmarkData, Debug, PartialEq)]
+struct Inner {
+    id: u32,
+}
+
+#[derive(BenchmarkData, Debug, PartialEq)]
+struct Outer {
+    inner: Inner,
+    children: Vec<Inner>,
+    maybe: Option<Box<Inner>>,
+}
+
+#[derive(BenchmarkData, Debug, PartialEq)]
+struct UserId(u64);
+
+#[derive(BenchmarkData, Debug, PartialEq)]
+struct Marker;
+
+#[test]
+fn scalars_get_fixed_non_default_values() {
+    assert_eq!(Scalars::default_instance(), Scalars {
+        byte: 1,
+        count: 1,
+        offset: 1,
+        size: 1,
+        ratio: 1.0,
+        score: 1.0,
+        active: true,
+        initial: 'a',
+    });
+}
+
+#[test]
+fn containers_hold_one_element() {
+    assert_eq!(Containers::default_instance(), Containers {
+        name: "test".to_string(),
+        label: "test",
+        parent: Some(1),
+        boxed: Box::new(1),
+        tags: vec!["test".to_string()],
+        counts: HashMap::from([("test".to_string(), 1)]),
+        sorted: BTreeMap::from([(1, true)]),
+        pair: (1, "test".to_string()),
+        rgba: [1; 4],
+    });
+}
+
+#[test]
+fn other_types_use_their_own_default_instance() {
+    assert_eq!(Outer::default_instance(), Outer {
+        inner: Inner { id: 1 },
+        children: vec![Inner { id: 1 }],
+        maybe: Some(Box::new(Inner { id: 1 })),
+    });
+}
+
+#[test]
+fn tuple_and_unit_structs() {
+    assert_eq!(UserId::default_instance(), UserId(1));
+    assert_eq!(Marker::default_instance(), Marker);
+}
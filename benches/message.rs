@@ -182,22 +182,4917 @@ impl ::protobuf::reflect::ProtobufValue for TestData {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+// @@protoc_insertion_point(message:Login)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Login {
+    // message fields
+    // @@protoc_insertion_point(field:Login.user)
+    pub user: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:Login.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Login {
+    fn default() -> &'a Login {
+        <Login as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Login {
+    pub fn new() -> Login {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "user",
+            |m: &Login| { &m.user },
+            |m: &mut Login| { &mut m.user },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Login>(
+            "Login",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Login {
+    const NAME: &'static str = "Login";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.user = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.user.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.user.is_empty() {
+            os.write_string(1, &self.user)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Login {
+        Login::new()
+    }
+
+    fn clear(&mut self) {
+        self.user.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Login {
+        static instance: Login = Login {
+            user: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Login {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Login").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Login {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Login {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Logout)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Logout {
+    // special fields
+    // @@protoc_insertion_point(special_field:Logout.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Logout {
+    fn default() -> &'a Logout {
+        <Logout as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Logout {
+    pub fn new() -> Logout {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Logout>(
+            "Logout",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Logout {
+    const NAME: &'static str = "Logout";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Logout {
+        Logout::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Logout {
+        static instance: Logout = Logout {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Logout {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Logout").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Logout {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Logout {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Event)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Event {
+    // message oneof groups
+    pub kind: ::std::option::Option<event::Kind>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Event.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Event {
+    fn default() -> &'a Event {
+        <Event as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Event {
+    pub fn new() -> Event {
+        ::std::default::Default::default()
+    }
+
+    // .Login login = 1;
+
+    pub fn login(&self) -> &Login {
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Login(ref v)) => v,
+            _ => <Login as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_login(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_login(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Login(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_login(&mut self, v: Login) {
+        self.kind = ::std::option::Option::Some(event::Kind::Login(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_login(&mut self) -> &mut Login {
+        if let ::std::option::Option::Some(event::Kind::Login(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(event::Kind::Login(Login::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Login(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_login(&mut self) -> Login {
+        if self.has_login() {
+            match self.kind.take() {
+                ::std::option::Option::Some(event::Kind::Login(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Login::new()
+        }
+    }
+
+    // .Logout logout = 2;
+
+    pub fn logout(&self) -> &Logout {
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Logout(ref v)) => v,
+            _ => <Logout as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_logout(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_logout(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Logout(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_logout(&mut self, v: Logout) {
+        self.kind = ::std::option::Option::Some(event::Kind::Logout(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_logout(&mut self) -> &mut Logout {
+        if let ::std::option::Option::Some(event::Kind::Logout(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(event::Kind::Logout(Logout::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Logout(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_logout(&mut self) -> Logout {
+        if self.has_logout() {
+            match self.kind.take() {
+                ::std::option::Option::Some(event::Kind::Logout(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Logout::new()
+        }
+    }
+
+    // uint64 heartbeat = 3;
+
+    pub fn heartbeat(&self) -> u64 {
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Heartbeat(v)) => v,
+            _ => 0,
+        }
+    }
+
+    pub fn clear_heartbeat(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_heartbeat(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(event::Kind::Heartbeat(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_heartbeat(&mut self, v: u64) {
+        self.kind = ::std::option::Option::Some(event::Kind::Heartbeat(v))
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Login>(
+            "login",
+            Event::has_login,
+            Event::login,
+            Event::mut_login,
+            Event::set_login,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Logout>(
+            "logout",
+            Event::has_logout,
+            Event::logout,
+            Event::mut_logout,
+            Event::set_logout,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "heartbeat",
+            Event::has_heartbeat,
+            Event::heartbeat,
+            Event::set_heartbeat,
+        ));
+        oneofs.push(event::Kind::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Event>(
+            "Event",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Event {
+    const NAME: &'static str = "Event";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.kind = ::std::option::Option::Some(event::Kind::Login(is.read_message()?));
+                },
+                18 => {
+                    self.kind = ::std::option::Option::Some(event::Kind::Logout(is.read_message()?));
+                },
+                24 => {
+                    self.kind = ::std::option::Option::Some(event::Kind::Heartbeat(is.read_uint64()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &event::Kind::Login(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &event::Kind::Logout(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &event::Kind::Heartbeat(v) => {
+                    my_size += ::protobuf::rt::uint64_size(3, v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &event::Kind::Login(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &event::Kind::Logout(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &event::Kind::Heartbeat(v) => {
+                    os.write_uint64(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Event {
+        Event::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Event {
+        static instance: Event = Event {
+            kind: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Event {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Event").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Event {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Event {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Event`
+pub mod event {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:Event.kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(oneof_field:Event.login)
+        Login(super::Login),
+        // @@protoc_insertion_point(oneof_field:Event.logout)
+        Logout(super::Logout),
+        // @@protoc_insertion_point(oneof_field:Event.heartbeat)
+        Heartbeat(u64),
+    }
+
+    impl ::protobuf::Oneof for Kind {
+    }
+
+    impl ::protobuf::OneofFull for Kind {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Event as ::protobuf::MessageFull>::descriptor().oneof_by_name("kind").unwrap()).clone()
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Kind>("kind")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:EventLog)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EventLog {
+    // message fields
+    // @@protoc_insertion_point(field:EventLog.events)
+    pub events: ::std::vec::Vec<Event>,
+    // special fields
+    // @@protoc_insertion_point(special_field:EventLog.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EventLog {
+    fn default() -> &'a EventLog {
+        <EventLog as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EventLog {
+    pub fn new() -> EventLog {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &EventLog| { &m.events },
+            |m: &mut EventLog| { &mut m.events },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EventLog>(
+            "EventLog",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EventLog {
+    const NAME: &'static str = "EventLog";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.events.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EventLog {
+        EventLog::new()
+    }
+
+    fn clear(&mut self) {
+        self.events.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EventLog {
+        static instance: EventLog = EventLog {
+            events: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EventLog {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EventLog").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EventLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EventLog {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:TestDataBatch)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TestDataBatch {
+    // message fields
+    // @@protoc_insertion_point(field:TestDataBatch.items)
+    pub items: ::std::vec::Vec<TestData>,
+    // special fields
+    // @@protoc_insertion_point(special_field:TestDataBatch.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TestDataBatch {
+    fn default() -> &'a TestDataBatch {
+        <TestDataBatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TestDataBatch {
+    pub fn new() -> TestDataBatch {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &TestDataBatch| { &m.items },
+            |m: &mut TestDataBatch| { &mut m.items },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TestDataBatch>(
+            "TestDataBatch",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TestDataBatch {
+    const NAME: &'static str = "TestDataBatch";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TestDataBatch {
+        TestDataBatch::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TestDataBatch {
+        static instance: TestDataBatch = TestDataBatch {
+            items: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TestDataBatch {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TestDataBatch").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TestDataBatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TestDataBatch {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Address)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Address {
+    // message fields
+    // @@protoc_insertion_point(field:Address.street)
+    pub street: ::std::string::String,
+    // @@protoc_insertion_point(field:Address.city)
+    pub city: ::std::string::String,
+    // @@protoc_insertion_point(field:Address.zip)
+    pub zip: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:Address.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Address {
+    fn default() -> &'a Address {
+        <Address as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Address {
+    pub fn new() -> Address {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "street",
+            |m: &Address| { &m.street },
+            |m: &mut Address| { &mut m.street },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "city",
+            |m: &Address| { &m.city },
+            |m: &mut Address| { &mut m.city },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "zip",
+            |m: &Address| { &m.zip },
+            |m: &mut Address| { &mut m.zip },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Address>(
+            "Address",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Address {
+    const NAME: &'static str = "Address";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.street = is.read_string()?;
+                },
+                18 => {
+                    self.city = is.read_string()?;
+                },
+                24 => {
+                    self.zip = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.street.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.street);
+        }
+        if !self.city.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.city);
+        }
+        if self.zip != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.zip);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.street.is_empty() {
+            os.write_string(1, &self.street)?;
+        }
+        if !self.city.is_empty() {
+            os.write_string(2, &self.city)?;
+        }
+        if self.zip != 0 {
+            os.write_uint32(3, self.zip)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Address {
+        Address::new()
+    }
+
+    fn clear(&mut self) {
+        self.street.clear();
+        self.city.clear();
+        self.zip = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Address {
+        static instance: Address = Address {
+            street: ::std::string::String::new(),
+            city: ::std::string::String::new(),
+            zip: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Address {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Address").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Address {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Tag)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Tag {
+    // message fields
+    // @@protoc_insertion_point(field:Tag.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:Tag.weight)
+    pub weight: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:Tag.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Tag {
+    fn default() -> &'a Tag {
+        <Tag as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Tag {
+    pub fn new() -> Tag {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &Tag| { &m.name },
+            |m: &mut Tag| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "weight",
+            |m: &Tag| { &m.weight },
+            |m: &mut Tag| { &mut m.weight },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Tag>(
+            "Tag",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Tag {
+    const NAME: &'static str = "Tag";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.weight = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.weight != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.weight);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.weight != 0 {
+            os.write_uint32(2, self.weight)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Tag {
+        Tag::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.weight = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Tag {
+        static instance: Tag = Tag {
+            name: ::std::string::String::new(),
+            weight: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Tag {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Tag").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Tag {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:NestedTestData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct NestedTestData {
+    // message fields
+    // @@protoc_insertion_point(field:NestedTestData.data)
+    pub data: ::protobuf::MessageField<TestData>,
+    // @@protoc_insertion_point(field:NestedTestData.address)
+    pub address: ::protobuf::MessageField<Address>,
+    // @@protoc_insertion_point(field:NestedTestData.tags)
+    pub tags: ::std::vec::Vec<Tag>,
+    // special fields
+    // @@protoc_insertion_point(special_field:NestedTestData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NestedTestData {
+    fn default() -> &'a NestedTestData {
+        <NestedTestData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NestedTestData {
+    pub fn new() -> NestedTestData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TestData>(
+            "data",
+            |m: &NestedTestData| { &m.data },
+            |m: &mut NestedTestData| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Address>(
+            "address",
+            |m: &NestedTestData| { &m.address },
+            |m: &mut NestedTestData| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tags",
+            |m: &NestedTestData| { &m.tags },
+            |m: &mut NestedTestData| { &mut m.tags },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NestedTestData>(
+            "NestedTestData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NestedTestData {
+    const NAME: &'static str = "NestedTestData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.data)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.address)?;
+                },
+                26 => {
+                    self.tags.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.data.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.tags {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.data.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for v in &self.tags {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NestedTestData {
+        NestedTestData::new()
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+        self.address.clear();
+        self.tags.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NestedTestData {
+        static instance: NestedTestData = NestedTestData {
+            data: ::protobuf::MessageField::none(),
+            address: ::protobuf::MessageField::none(),
+            tags: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NestedTestData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NestedTestData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NestedTestData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NestedTestData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:BlobData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BlobData {
+    // message fields
+    // @@protoc_insertion_point(field:BlobData.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:BlobData.payload)
+    pub payload: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:BlobData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BlobData {
+    fn default() -> &'a BlobData {
+        <BlobData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlobData {
+    pub fn new() -> BlobData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &BlobData| { &m.id },
+            |m: &mut BlobData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "payload",
+            |m: &BlobData| { &m.payload },
+            |m: &mut BlobData| { &mut m.payload },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BlobData>(
+            "BlobData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BlobData {
+    const NAME: &'static str = "BlobData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    self.payload = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if !self.payload.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.payload);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if !self.payload.is_empty() {
+            os.write_bytes(2, &self.payload)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BlobData {
+        BlobData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.payload.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BlobData {
+        static instance: BlobData = BlobData {
+            id: 0,
+            payload: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BlobData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BlobData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BlobData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlobData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:MapData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MapData {
+    // message fields
+    // @@protoc_insertion_point(field:MapData.metadata)
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:MapData.readings)
+    pub readings: ::std::collections::HashMap<u64, f64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:MapData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MapData {
+    fn default() -> &'a MapData {
+        <MapData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MapData {
+    pub fn new() -> MapData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "metadata",
+            |m: &MapData| { &m.metadata },
+            |m: &mut MapData| { &mut m.metadata },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "readings",
+            |m: &MapData| { &m.readings },
+            |m: &mut MapData| { &mut m.readings },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MapData>(
+            "MapData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MapData {
+    const NAME: &'static str = "MapData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.metadata.insert(key, value);
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            8 => key = is.read_uint64()?,
+                            17 => value = is.read_double()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.readings.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        for (k, v) in &self.readings {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::uint64_size(1, *k);
+            entry_size += 1 + 8;
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(10)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        for (k, v) in &self.readings {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::uint64_size(1, *k);
+            entry_size += 1 + 8;
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_uint64(1, *k)?;
+            os.write_double(2, *v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MapData {
+        MapData::new()
+    }
+
+    fn clear(&mut self) {
+        self.metadata.clear();
+        self.readings.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MapData {
+        static instance: ::protobuf::rt::Lazy<MapData> = ::protobuf::rt::Lazy::new();
+        instance.get(MapData::new)
+    }
+}
+
+impl ::protobuf::MessageFull for MapData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MapData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MapData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MapData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SparseData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SparseData {
+    // message fields
+    // @@protoc_insertion_point(field:SparseData.id)
+    pub id: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:SparseData.name)
+    pub name: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.email)
+    pub email: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.age)
+    pub age: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:SparseData.score)
+    pub score: ::std::option::Option<f64>,
+    // @@protoc_insertion_point(field:SparseData.active)
+    pub active: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:SparseData.created_at)
+    pub created_at: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:SparseData.updated_at)
+    pub updated_at: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:SparseData.parent_id)
+    pub parent_id: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:SparseData.country)
+    pub country: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.city)
+    pub city: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.zip)
+    pub zip: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:SparseData.phone)
+    pub phone: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.verified)
+    pub verified: ::std::option::Option<bool>,
+    // @@protoc_insertion_point(field:SparseData.rank)
+    pub rank: ::std::option::Option<i32>,
+    // @@protoc_insertion_point(field:SparseData.balance)
+    pub balance: ::std::option::Option<i64>,
+    // @@protoc_insertion_point(field:SparseData.locale)
+    pub locale: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.timezone)
+    pub timezone: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:SparseData.retries)
+    pub retries: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:SparseData.deleted)
+    pub deleted: ::std::option::Option<bool>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SparseData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SparseData {
+    fn default() -> &'a SparseData {
+        <SparseData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SparseData {
+    pub fn new() -> SparseData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(20);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "id",
+            |m: &SparseData| { &m.id },
+            |m: &mut SparseData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "name",
+            |m: &SparseData| { &m.name },
+            |m: &mut SparseData| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "email",
+            |m: &SparseData| { &m.email },
+            |m: &mut SparseData| { &mut m.email },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "age",
+            |m: &SparseData| { &m.age },
+            |m: &mut SparseData| { &mut m.age },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "score",
+            |m: &SparseData| { &m.score },
+            |m: &mut SparseData| { &mut m.score },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "active",
+            |m: &SparseData| { &m.active },
+            |m: &mut SparseData| { &mut m.active },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "created_at",
+            |m: &SparseData| { &m.created_at },
+            |m: &mut SparseData| { &mut m.created_at },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "updated_at",
+            |m: &SparseData| { &m.updated_at },
+            |m: &mut SparseData| { &mut m.updated_at },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "parent_id",
+            |m: &SparseData| { &m.parent_id },
+            |m: &mut SparseData| { &mut m.parent_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "country",
+            |m: &SparseData| { &m.country },
+            |m: &mut SparseData| { &mut m.country },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "city",
+            |m: &SparseData| { &m.city },
+            |m: &mut SparseData| { &mut m.city },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "zip",
+            |m: &SparseData| { &m.zip },
+            |m: &mut SparseData| { &mut m.zip },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "phone",
+            |m: &SparseData| { &m.phone },
+            |m: &mut SparseData| { &mut m.phone },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "verified",
+            |m: &SparseData| { &m.verified },
+            |m: &mut SparseData| { &mut m.verified },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "rank",
+            |m: &SparseData| { &m.rank },
+            |m: &mut SparseData| { &mut m.rank },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "balance",
+            |m: &SparseData| { &m.balance },
+            |m: &mut SparseData| { &mut m.balance },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "locale",
+            |m: &SparseData| { &m.locale },
+            |m: &mut SparseData| { &mut m.locale },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "timezone",
+            |m: &SparseData| { &m.timezone },
+            |m: &mut SparseData| { &mut m.timezone },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "retries",
+            |m: &SparseData| { &m.retries },
+            |m: &mut SparseData| { &mut m.retries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "deleted",
+            |m: &SparseData| { &m.deleted },
+            |m: &mut SparseData| { &mut m.deleted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SparseData>(
+            "SparseData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SparseData {
+    const NAME: &'static str = "SparseData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                18 => {
+                    self.name = ::std::option::Option::Some(is.read_string()?);
+                },
+                26 => {
+                    self.email = ::std::option::Option::Some(is.read_string()?);
+                },
+                32 => {
+                    self.age = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                41 => {
+                    self.score = ::std::option::Option::Some(is.read_double()?);
+                },
+                48 => {
+                    self.active = ::std::option::Option::Some(is.read_bool()?);
+                },
+                56 => {
+                    self.created_at = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                64 => {
+                    self.updated_at = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                72 => {
+                    self.parent_id = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                82 => {
+                    self.country = ::std::option::Option::Some(is.read_string()?);
+                },
+                90 => {
+                    self.city = ::std::option::Option::Some(is.read_string()?);
+                },
+                96 => {
+                    self.zip = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                106 => {
+                    self.phone = ::std::option::Option::Some(is.read_string()?);
+                },
+                112 => {
+                    self.verified = ::std::option::Option::Some(is.read_bool()?);
+                },
+                120 => {
+                    self.rank = ::std::option::Option::Some(is.read_int32()?);
+                },
+                128 => {
+                    self.balance = ::std::option::Option::Some(is.read_int64()?);
+                },
+                138 => {
+                    self.locale = ::std::option::Option::Some(is.read_string()?);
+                },
+                146 => {
+                    self.timezone = ::std::option::Option::Some(is.read_string()?);
+                },
+                152 => {
+                    self.retries = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                160 => {
+                    self.deleted = ::std::option::Option::Some(is.read_bool()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::uint32_size(1, v);
+        }
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.email.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.age {
+            my_size += ::protobuf::rt::uint32_size(4, v);
+        }
+        if let Some(v) = self.score {
+            my_size += 1 + 8;
+        }
+        if let Some(v) = self.active {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.created_at {
+            my_size += ::protobuf::rt::uint64_size(7, v);
+        }
+        if let Some(v) = self.updated_at {
+            my_size += ::protobuf::rt::uint64_size(8, v);
+        }
+        if let Some(v) = self.parent_id {
+            my_size += ::protobuf::rt::uint64_size(9, v);
+        }
+        if let Some(v) = self.country.as_ref() {
+            my_size += ::protobuf::rt::string_size(10, &v);
+        }
+        if let Some(v) = self.city.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v);
+        }
+        if let Some(v) = self.zip {
+            my_size += ::protobuf::rt::uint32_size(12, v);
+        }
+        if let Some(v) = self.phone.as_ref() {
+            my_size += ::protobuf::rt::string_size(13, &v);
+        }
+        if let Some(v) = self.verified {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.rank {
+            my_size += ::protobuf::rt::int32_size(15, v);
+        }
+        if let Some(v) = self.balance {
+            my_size += ::protobuf::rt::int64_size(16, v);
+        }
+        if let Some(v) = self.locale.as_ref() {
+            my_size += ::protobuf::rt::string_size(17, &v);
+        }
+        if let Some(v) = self.timezone.as_ref() {
+            my_size += ::protobuf::rt::string_size(18, &v);
+        }
+        if let Some(v) = self.retries {
+            my_size += ::protobuf::rt::uint32_size(19, v);
+        }
+        if let Some(v) = self.deleted {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.id {
+            os.write_uint32(1, v)?;
+        }
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(2, v)?;
+        }
+        if let Some(v) = self.email.as_ref() {
+            os.write_string(3, v)?;
+        }
+        if let Some(v) = self.age {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.score {
+            os.write_double(5, v)?;
+        }
+        if let Some(v) = self.active {
+            os.write_bool(6, v)?;
+        }
+        if let Some(v) = self.created_at {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(v) = self.updated_at {
+            os.write_uint64(8, v)?;
+        }
+        if let Some(v) = self.parent_id {
+            os.write_uint64(9, v)?;
+        }
+        if let Some(v) = self.country.as_ref() {
+            os.write_string(10, v)?;
+        }
+        if let Some(v) = self.city.as_ref() {
+            os.write_string(11, v)?;
+        }
+        if let Some(v) = self.zip {
+            os.write_uint32(12, v)?;
+        }
+        if let Some(v) = self.phone.as_ref() {
+            os.write_string(13, v)?;
+        }
+        if let Some(v) = self.verified {
+            os.write_bool(14, v)?;
+        }
+        if let Some(v) = self.rank {
+            os.write_int32(15, v)?;
+        }
+        if let Some(v) = self.balance {
+            os.write_int64(16, v)?;
+        }
+        if let Some(v) = self.locale.as_ref() {
+            os.write_string(17, v)?;
+        }
+        if let Some(v) = self.timezone.as_ref() {
+            os.write_string(18, v)?;
+        }
+        if let Some(v) = self.retries {
+            os.write_uint32(19, v)?;
+        }
+        if let Some(v) = self.deleted {
+            os.write_bool(20, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SparseData {
+        SparseData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = ::std::option::Option::None;
+        self.name = ::std::option::Option::None;
+        self.email = ::std::option::Option::None;
+        self.age = ::std::option::Option::None;
+        self.score = ::std::option::Option::None;
+        self.active = ::std::option::Option::None;
+        self.created_at = ::std::option::Option::None;
+        self.updated_at = ::std::option::Option::None;
+        self.parent_id = ::std::option::Option::None;
+        self.country = ::std::option::Option::None;
+        self.city = ::std::option::Option::None;
+        self.zip = ::std::option::Option::None;
+        self.phone = ::std::option::Option::None;
+        self.verified = ::std::option::Option::None;
+        self.rank = ::std::option::Option::None;
+        self.balance = ::std::option::Option::None;
+        self.locale = ::std::option::Option::None;
+        self.timezone = ::std::option::Option::None;
+        self.retries = ::std::option::Option::None;
+        self.deleted = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SparseData {
+        static instance: SparseData = SparseData {
+            id: ::std::option::Option::None,
+            name: ::std::option::Option::None,
+            email: ::std::option::Option::None,
+            age: ::std::option::Option::None,
+            score: ::std::option::Option::None,
+            active: ::std::option::Option::None,
+            created_at: ::std::option::Option::None,
+            updated_at: ::std::option::Option::None,
+            parent_id: ::std::option::Option::None,
+            country: ::std::option::Option::None,
+            city: ::std::option::Option::None,
+            zip: ::std::option::Option::None,
+            phone: ::std::option::Option::None,
+            verified: ::std::option::Option::None,
+            rank: ::std::option::Option::None,
+            balance: ::std::option::Option::None,
+            locale: ::std::option::Option::None,
+            timezone: ::std::option::Option::None,
+            retries: ::std::option::Option::None,
+            deleted: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SparseData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SparseData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SparseData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SparseData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:MetricsData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MetricsData {
+    // message fields
+    // @@protoc_insertion_point(field:MetricsData.timestamps)
+    pub timestamps: ::std::vec::Vec<u64>,
+    // @@protoc_insertion_point(field:MetricsData.values)
+    pub values: ::std::vec::Vec<f64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:MetricsData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MetricsData {
+    fn default() -> &'a MetricsData {
+        <MetricsData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MetricsData {
+    pub fn new() -> MetricsData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "timestamps",
+            |m: &MetricsData| { &m.timestamps },
+            |m: &mut MetricsData| { &mut m.timestamps },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "values",
+            |m: &MetricsData| { &m.values },
+            |m: &mut MetricsData| { &mut m.values },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MetricsData>(
+            "MetricsData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MetricsData {
+    const NAME: &'static str = "MetricsData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    is.read_repeated_packed_uint64_into(&mut self.timestamps)?;
+                },
+                8 => {
+                    self.timestamps.push(is.read_uint64()?);
+                },
+                18 => {
+                    is.read_repeated_packed_double_into(&mut self.values)?;
+                },
+                17 => {
+                    self.values.push(is.read_double()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::vec_packed_uint64_size(1, &self.timestamps);
+        if !self.values.is_empty() {
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size((self.values.len() * 8) as u64) + (self.values.len() * 8) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_repeated_packed_uint64(1, &self.timestamps)?;
+        os.write_repeated_packed_double(2, &self.values)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MetricsData {
+        MetricsData::new()
+    }
+
+    fn clear(&mut self) {
+        self.timestamps.clear();
+        self.values.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MetricsData {
+        static instance: MetricsData = MetricsData {
+            timestamps: ::std::vec::Vec::new(),
+            values: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MetricsData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MetricsData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MetricsData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MetricsData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:UnicodeData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UnicodeData {
+    // message fields
+    // @@protoc_insertion_point(field:UnicodeData.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:UnicodeData.latin)
+    pub latin: ::std::string::String,
+    // @@protoc_insertion_point(field:UnicodeData.cjk)
+    pub cjk: ::std::string::String,
+    // @@protoc_insertion_point(field:UnicodeData.rtl)
+    pub rtl: ::std::string::String,
+    // @@protoc_insertion_point(field:UnicodeData.emoji)
+    pub emoji: ::std::string::String,
+    // @@protoc_insertion_point(field:UnicodeData.escapes)
+    pub escapes: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:UnicodeData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UnicodeData {
+    fn default() -> &'a UnicodeData {
+        <UnicodeData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnicodeData {
+    pub fn new() -> UnicodeData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &UnicodeData| { &m.id },
+            |m: &mut UnicodeData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "latin",
+            |m: &UnicodeData| { &m.latin },
+            |m: &mut UnicodeData| { &mut m.latin },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cjk",
+            |m: &UnicodeData| { &m.cjk },
+            |m: &mut UnicodeData| { &mut m.cjk },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rtl",
+            |m: &UnicodeData| { &m.rtl },
+            |m: &mut UnicodeData| { &mut m.rtl },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "emoji",
+            |m: &UnicodeData| { &m.emoji },
+            |m: &mut UnicodeData| { &mut m.emoji },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "escapes",
+            |m: &UnicodeData| { &m.escapes },
+            |m: &mut UnicodeData| { &mut m.escapes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UnicodeData>(
+            "UnicodeData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UnicodeData {
+    const NAME: &'static str = "UnicodeData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    self.latin = is.read_string()?;
+                },
+                26 => {
+                    self.cjk = is.read_string()?;
+                },
+                34 => {
+                    self.rtl = is.read_string()?;
+                },
+                42 => {
+                    self.emoji = is.read_string()?;
+                },
+                50 => {
+                    self.escapes = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if !self.latin.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.latin);
+        }
+        if !self.cjk.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.cjk);
+        }
+        if !self.rtl.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.rtl);
+        }
+        if !self.emoji.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.emoji);
+        }
+        if !self.escapes.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.escapes);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if !self.latin.is_empty() {
+            os.write_string(2, &self.latin)?;
+        }
+        if !self.cjk.is_empty() {
+            os.write_string(3, &self.cjk)?;
+        }
+        if !self.rtl.is_empty() {
+            os.write_string(4, &self.rtl)?;
+        }
+        if !self.emoji.is_empty() {
+            os.write_string(5, &self.emoji)?;
+        }
+        if !self.escapes.is_empty() {
+            os.write_string(6, &self.escapes)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UnicodeData {
+        UnicodeData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.latin.clear();
+        self.cjk.clear();
+        self.rtl.clear();
+        self.emoji.clear();
+        self.escapes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UnicodeData {
+        static instance: UnicodeData = UnicodeData {
+            id: 0,
+            latin: ::std::string::String::new(),
+            cjk: ::std::string::String::new(),
+            rtl: ::std::string::String::new(),
+            emoji: ::std::string::String::new(),
+            escapes: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UnicodeData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UnicodeData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UnicodeData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnicodeData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:BigString)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BigString {
+    // message fields
+    // @@protoc_insertion_point(field:BigString.text)
+    pub text: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:BigString.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BigString {
+    fn default() -> &'a BigString {
+        <BigString as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BigString {
+    pub fn new() -> BigString {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "text",
+            |m: &BigString| { &m.text },
+            |m: &mut BigString| { &mut m.text },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BigString>(
+            "BigString",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BigString {
+    const NAME: &'static str = "BigString";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.text = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.text);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.text.is_empty() {
+            os.write_string(1, &self.text)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BigString {
+        BigString::new()
+    }
+
+    fn clear(&mut self) {
+        self.text.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BigString {
+        static instance: BigString = BigString {
+            text: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BigString {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BigString").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BigString {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BigString {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:TimestampData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TimestampData {
+    // message fields
+    // @@protoc_insertion_point(field:TimestampData.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:TimestampData.created_at)
+    pub created_at: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:TimestampData.updated_at)
+    pub updated_at: ::protobuf::MessageField<::protobuf::well_known_types::timestamp::Timestamp>,
+    // @@protoc_insertion_point(field:TimestampData.events)
+    pub events: ::std::vec::Vec<::protobuf::well_known_types::timestamp::Timestamp>,
+    // special fields
+    // @@protoc_insertion_point(special_field:TimestampData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TimestampData {
+    fn default() -> &'a TimestampData {
+        <TimestampData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TimestampData {
+    pub fn new() -> TimestampData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &TimestampData| { &m.id },
+            |m: &mut TimestampData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "created_at",
+            |m: &TimestampData| { &m.created_at },
+            |m: &mut TimestampData| { &mut m.created_at },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, ::protobuf::well_known_types::timestamp::Timestamp>(
+            "updated_at",
+            |m: &TimestampData| { &m.updated_at },
+            |m: &mut TimestampData| { &mut m.updated_at },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &TimestampData| { &m.events },
+            |m: &mut TimestampData| { &mut m.events },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TimestampData>(
+            "TimestampData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TimestampData {
+    const NAME: &'static str = "TimestampData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.created_at)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.updated_at)?;
+                },
+                34 => {
+                    self.events.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.updated_at.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if let Some(v) = self.created_at.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.updated_at.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TimestampData {
+        TimestampData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.created_at.clear();
+        self.updated_at.clear();
+        self.events.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TimestampData {
+        static instance: TimestampData = TimestampData {
+            id: 0,
+            created_at: ::protobuf::MessageField::none(),
+            updated_at: ::protobuf::MessageField::none(),
+            events: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TimestampData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TimestampData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TimestampData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TimestampData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:WideInts)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct WideInts {
+    // message fields
+    // @@protoc_insertion_point(field:WideInts.amount)
+    pub amount: ::std::string::String,
+    // @@protoc_insertion_point(field:WideInts.delta)
+    pub delta: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:WideInts.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a WideInts {
+    fn default() -> &'a WideInts {
+        <WideInts as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WideInts {
+    pub fn new() -> WideInts {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "amount",
+            |m: &WideInts| { &m.amount },
+            |m: &mut WideInts| { &mut m.amount },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "delta",
+            |m: &WideInts| { &m.delta },
+            |m: &mut WideInts| { &mut m.delta },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<WideInts>(
+            "WideInts",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for WideInts {
+    const NAME: &'static str = "WideInts";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.amount = is.read_string()?;
+                },
+                18 => {
+                    self.delta = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.amount.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.amount);
+        }
+        if !self.delta.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.delta);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.amount.is_empty() {
+            os.write_string(1, &self.amount)?;
+        }
+        if !self.delta.is_empty() {
+            os.write_string(2, &self.delta)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> WideInts {
+        WideInts::new()
+    }
+
+    fn clear(&mut self) {
+        self.amount.clear();
+        self.delta.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static WideInts {
+        static instance: WideInts = WideInts {
+            amount: ::std::string::String::new(),
+            delta: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for WideInts {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("WideInts").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for WideInts {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WideInts {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DeepNested)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeepNested {
+    // message fields
+    // @@protoc_insertion_point(field:DeepNested.level)
+    pub level: u32,
+    // @@protoc_insertion_point(field:DeepNested.child)
+    pub child: ::protobuf::MessageField<DeepNested>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeepNested.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeepNested {
+    fn default() -> &'a DeepNested {
+        <DeepNested as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeepNested {
+    pub fn new() -> DeepNested {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "level",
+            |m: &DeepNested| { &m.level },
+            |m: &mut DeepNested| { &mut m.level },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, DeepNested>(
+            "child",
+            |m: &DeepNested| { &m.child },
+            |m: &mut DeepNested| { &mut m.child },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeepNested>(
+            "DeepNested",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeepNested {
+    const NAME: &'static str = "DeepNested";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.level = is.read_uint32()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.child)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.level != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.level);
+        }
+        if let Some(v) = self.child.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.level != 0 {
+            os.write_uint32(1, self.level)?;
+        }
+        if let Some(v) = self.child.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeepNested {
+        DeepNested::new()
+    }
+
+    fn clear(&mut self) {
+        self.level = 0;
+        self.child.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeepNested {
+        static instance: DeepNested = DeepNested {
+            level: 0,
+            child: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeepNested {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeepNested").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeepNested {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeepNested {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Rgba)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Rgba {
+    // message fields
+    // @@protoc_insertion_point(field:Rgba.r)
+    pub r: u32,
+    // @@protoc_insertion_point(field:Rgba.g)
+    pub g: u32,
+    // @@protoc_insertion_point(field:Rgba.b)
+    pub b: u32,
+    // @@protoc_insertion_point(field:Rgba.a)
+    pub a: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:Rgba.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Rgba {
+    fn default() -> &'a Rgba {
+        <Rgba as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Rgba {
+    pub fn new() -> Rgba {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "r",
+            |m: &Rgba| { &m.r },
+            |m: &mut Rgba| { &mut m.r },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "g",
+            |m: &Rgba| { &m.g },
+            |m: &mut Rgba| { &mut m.g },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "b",
+            |m: &Rgba| { &m.b },
+            |m: &mut Rgba| { &mut m.b },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "a",
+            |m: &Rgba| { &m.a },
+            |m: &mut Rgba| { &mut m.a },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Rgba>(
+            "Rgba",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Rgba {
+    const NAME: &'static str = "Rgba";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.r = is.read_uint32()?;
+                },
+                16 => {
+                    self.g = is.read_uint32()?;
+                },
+                24 => {
+                    self.b = is.read_uint32()?;
+                },
+                32 => {
+                    self.a = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.r != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.r);
+        }
+        if self.g != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.g);
+        }
+        if self.b != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.b);
+        }
+        if self.a != 0 {
+            my_size += ::protobuf::rt::uint32_size(4, self.a);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.r != 0 {
+            os.write_uint32(1, self.r)?;
+        }
+        if self.g != 0 {
+            os.write_uint32(2, self.g)?;
+        }
+        if self.b != 0 {
+            os.write_uint32(3, self.b)?;
+        }
+        if self.a != 0 {
+            os.write_uint32(4, self.a)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Rgba {
+        Rgba::new()
+    }
+
+    fn clear(&mut self) {
+        self.r = 0;
+        self.g = 0;
+        self.b = 0;
+        self.a = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Rgba {
+        static instance: Rgba = Rgba {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Rgba {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Rgba").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Rgba {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Rgba {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:NewtypeData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct NewtypeData {
+    // message fields
+    // @@protoc_insertion_point(field:NewtypeData.id)
+    pub id: u64,
+    // @@protoc_insertion_point(field:NewtypeData.email)
+    pub email: ::std::string::String,
+    // @@protoc_insertion_point(field:NewtypeData.version)
+    pub version: u32,
+    // @@protoc_insertion_point(field:NewtypeData.color)
+    pub color: ::protobuf::MessageField<Rgba>,
+    // special fields
+    // @@protoc_insertion_point(special_field:NewtypeData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a NewtypeData {
+    fn default() -> &'a NewtypeData {
+        <NewtypeData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NewtypeData {
+    pub fn new() -> NewtypeData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &NewtypeData| { &m.id },
+            |m: &mut NewtypeData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "email",
+            |m: &NewtypeData| { &m.email },
+            |m: &mut NewtypeData| { &mut m.email },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &NewtypeData| { &m.version },
+            |m: &mut NewtypeData| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Rgba>(
+            "color",
+            |m: &NewtypeData| { &m.color },
+            |m: &mut NewtypeData| { &mut m.color },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<NewtypeData>(
+            "NewtypeData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for NewtypeData {
+    const NAME: &'static str = "NewtypeData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint64()?;
+                },
+                18 => {
+                    self.email = is.read_string()?;
+                },
+                24 => {
+                    self.version = is.read_uint32()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.color)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.email);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.version);
+        }
+        if let Some(v) = self.color.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(2, &self.email)?;
+        }
+        if self.version != 0 {
+            os.write_uint32(3, self.version)?;
+        }
+        if let Some(v) = self.color.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> NewtypeData {
+        NewtypeData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.email.clear();
+        self.version = 0;
+        self.color.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static NewtypeData {
+        static instance: NewtypeData = NewtypeData {
+            id: 0,
+            email: ::std::string::String::new(),
+            version: 0,
+            color: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for NewtypeData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("NewtypeData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for NewtypeData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NewtypeData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:UuidTestData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct UuidTestData {
+    // message fields
+    // @@protoc_insertion_point(field:UuidTestData.id)
+    pub id: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:UuidTestData.parent_id)
+    pub parent_id: ::std::vec::Vec<u8>,
+    // @@protoc_insertion_point(field:UuidTestData.name)
+    pub name: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:UuidTestData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a UuidTestData {
+    fn default() -> &'a UuidTestData {
+        <UuidTestData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UuidTestData {
+    pub fn new() -> UuidTestData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &UuidTestData| { &m.id },
+            |m: &mut UuidTestData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "parent_id",
+            |m: &UuidTestData| { &m.parent_id },
+            |m: &mut UuidTestData| { &mut m.parent_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &UuidTestData| { &m.name },
+            |m: &mut UuidTestData| { &mut m.name },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<UuidTestData>(
+            "UuidTestData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for UuidTestData {
+    const NAME: &'static str = "UuidTestData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.id = is.read_bytes()?;
+                },
+                18 => {
+                    self.parent_id = is.read_bytes()?;
+                },
+                26 => {
+                    self.name = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.id);
+        }
+        if !self.parent_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.parent_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.id.is_empty() {
+            os.write_bytes(1, &self.id)?;
+        }
+        if !self.parent_id.is_empty() {
+            os.write_bytes(2, &self.parent_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(3, &self.name)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> UuidTestData {
+        UuidTestData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.parent_id.clear();
+        self.name.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static UuidTestData {
+        static instance: UuidTestData = UuidTestData {
+            id: ::std::vec::Vec::new(),
+            parent_id: ::std::vec::Vec::new(),
+            name: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for UuidTestData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("UuidTestData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for UuidTestData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UuidTestData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Pending)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Pending {
+    // special fields
+    // @@protoc_insertion_point(special_field:Pending.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Pending {
+    fn default() -> &'a Pending {
+        <Pending as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Pending {
+    pub fn new() -> Pending {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Pending>(
+            "Pending",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Pending {
+    const NAME: &'static str = "Pending";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Pending {
+        Pending::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Pending {
+        static instance: Pending = Pending {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Pending {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Pending").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Pending {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Pending {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Suspended)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Suspended {
+    // message fields
+    // @@protoc_insertion_point(field:Suspended.days)
+    pub days: u32,
+    // @@protoc_insertion_point(field:Suspended.note)
+    pub note: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:Suspended.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Suspended {
+    fn default() -> &'a Suspended {
+        <Suspended as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Suspended {
+    pub fn new() -> Suspended {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "days",
+            |m: &Suspended| { &m.days },
+            |m: &mut Suspended| { &mut m.days },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "note",
+            |m: &Suspended| { &m.note },
+            |m: &mut Suspended| { &mut m.note },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Suspended>(
+            "Suspended",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Suspended {
+    const NAME: &'static str = "Suspended";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.days = is.read_uint32()?;
+                },
+                18 => {
+                    self.note = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.days != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.days);
+        }
+        if !self.note.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.note);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.days != 0 {
+            os.write_uint32(1, self.days)?;
+        }
+        if !self.note.is_empty() {
+            os.write_string(2, &self.note)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Suspended {
+        Suspended::new()
+    }
+
+    fn clear(&mut self) {
+        self.days = 0;
+        self.note.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Suspended {
+        static instance: Suspended = Suspended {
+            days: 0,
+            note: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Suspended {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Suspended").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Suspended {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Suspended {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+
+// @@protoc_insertion_point(message:Closed)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Closed {
+    // message fields
+    // @@protoc_insertion_point(field:Closed.reason)
+    pub reason: ::std::string::String,
+    // @@protoc_insertion_point(field:Closed.at)
+    pub at: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:Closed.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Closed {
+    fn default() -> &'a Closed {
+        <Closed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Closed {
+    pub fn new() -> Closed {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reason",
+            |m: &Closed| { &m.reason },
+            |m: &mut Closed| { &mut m.reason },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "at",
+            |m: &Closed| { &m.at },
+            |m: &mut Closed| { &mut m.at },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Closed>(
+            "Closed",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Closed {
+    const NAME: &'static str = "Closed";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.reason = is.read_string()?;
+                },
+                16 => {
+                    self.at = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.reason);
+        }
+        if self.at != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.at);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.reason.is_empty() {
+            os.write_string(1, &self.reason)?;
+        }
+        if self.at != 0 {
+            os.write_uint64(2, self.at)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Closed {
+        Closed::new()
+    }
+
+    fn clear(&mut self) {
+        self.reason.clear();
+        self.at = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Closed {
+        static instance: Closed = Closed {
+            reason: ::std::string::String::new(),
+            at: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Closed {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Closed").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Closed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Closed {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+
+// @@protoc_insertion_point(message:Archived)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Archived {
+    // special fields
+    // @@protoc_insertion_point(special_field:Archived.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Archived {
+    fn default() -> &'a Archived {
+        <Archived as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Archived {
+    pub fn new() -> Archived {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Archived>(
+            "Archived",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Archived {
+    const NAME: &'static str = "Archived";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Archived {
+        Archived::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Archived {
+        static instance: Archived = Archived {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Archived {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Archived").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Archived {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Archived {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:Status)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Status {
+    // message oneof groups
+    pub kind: ::std::option::Option<status::Kind>,
+    // special fields
+    // @@protoc_insertion_point(special_field:Status.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Status {
+    fn default() -> &'a Status {
+        <Status as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Status {
+    pub fn new() -> Status {
+        ::std::default::Default::default()
+    }
+
+    // .Pending pending = 1;
+
+    pub fn pending(&self) -> &Pending {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Pending(ref v)) => v,
+            _ => <Pending as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_pending(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_pending(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Pending(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pending(&mut self, v: Pending) {
+        self.kind = ::std::option::Option::Some(status::Kind::Pending(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_pending(&mut self) -> &mut Pending {
+        if let ::std::option::Option::Some(status::Kind::Pending(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(status::Kind::Pending(Pending::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Pending(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_pending(&mut self) -> Pending {
+        if self.has_pending() {
+            match self.kind.take() {
+                ::std::option::Option::Some(status::Kind::Pending(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Pending::new()
+        }
+    }
+
+    // uint64 active = 2;
+
+    pub fn active(&self) -> u64 {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Active(v)) => v,
+            _ => 0,
+        }
+    }
+
+    pub fn clear_active(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_active(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Active(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_active(&mut self, v: u64) {
+        self.kind = ::std::option::Option::Some(status::Kind::Active(v))
+    }
+
+    // .Suspended suspended = 3;
+
+    pub fn suspended(&self) -> &Suspended {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Suspended(ref v)) => v,
+            _ => <Suspended as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_suspended(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_suspended(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Suspended(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_suspended(&mut self, v: Suspended) {
+        self.kind = ::std::option::Option::Some(status::Kind::Suspended(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_suspended(&mut self) -> &mut Suspended {
+        if let ::std::option::Option::Some(status::Kind::Suspended(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(status::Kind::Suspended(Suspended::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Suspended(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_suspended(&mut self) -> Suspended {
+        if self.has_suspended() {
+            match self.kind.take() {
+                ::std::option::Option::Some(status::Kind::Suspended(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Suspended::new()
+        }
+    }
+
+    // .Closed closed = 4;
+
+    pub fn closed(&self) -> &Closed {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Closed(ref v)) => v,
+            _ => <Closed as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_closed(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_closed(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Closed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_closed(&mut self, v: Closed) {
+        self.kind = ::std::option::Option::Some(status::Kind::Closed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_closed(&mut self) -> &mut Closed {
+        if let ::std::option::Option::Some(status::Kind::Closed(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(status::Kind::Closed(Closed::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Closed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_closed(&mut self) -> Closed {
+        if self.has_closed() {
+            match self.kind.take() {
+                ::std::option::Option::Some(status::Kind::Closed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Closed::new()
+        }
+    }
+
+    // .Archived archived = 5;
+
+    pub fn archived(&self) -> &Archived {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Archived(ref v)) => v,
+            _ => <Archived as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_archived(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_archived(&self) -> bool {
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Archived(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_archived(&mut self, v: Archived) {
+        self.kind = ::std::option::Option::Some(status::Kind::Archived(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_archived(&mut self) -> &mut Archived {
+        if let ::std::option::Option::Some(status::Kind::Archived(_)) = self.kind {
+        } else {
+            self.kind = ::std::option::Option::Some(status::Kind::Archived(Archived::new()));
+        }
+        match self.kind {
+            ::std::option::Option::Some(status::Kind::Archived(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_archived(&mut self) -> Archived {
+        if self.has_archived() {
+            match self.kind.take() {
+                ::std::option::Option::Some(status::Kind::Archived(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Archived::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Pending>(
+            "pending",
+            Status::has_pending,
+            Status::pending,
+            Status::mut_pending,
+            Status::set_pending,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "active",
+            Status::has_active,
+            Status::active,
+            Status::set_active,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Suspended>(
+            "suspended",
+            Status::has_suspended,
+            Status::suspended,
+            Status::mut_suspended,
+            Status::set_suspended,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Closed>(
+            "closed",
+            Status::has_closed,
+            Status::closed,
+            Status::mut_closed,
+            Status::set_closed,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, Archived>(
+            "archived",
+            Status::has_archived,
+            Status::archived,
+            Status::mut_archived,
+            Status::set_archived,
+        ));
+        oneofs.push(status::Kind::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Status>(
+            "Status",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Status {
+    const NAME: &'static str = "Status";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.kind = ::std::option::Option::Some(status::Kind::Pending(is.read_message()?));
+                },
+                16 => {
+                    self.kind = ::std::option::Option::Some(status::Kind::Active(is.read_uint64()?));
+                },
+                26 => {
+                    self.kind = ::std::option::Option::Some(status::Kind::Suspended(is.read_message()?));
+                },
+                34 => {
+                    self.kind = ::std::option::Option::Some(status::Kind::Closed(is.read_message()?));
+                },
+                42 => {
+                    self.kind = ::std::option::Option::Some(status::Kind::Archived(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &status::Kind::Pending(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &status::Kind::Active(v) => {
+                    my_size += ::protobuf::rt::uint64_size(2, v);
+                },
+                &status::Kind::Suspended(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &status::Kind::Closed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &status::Kind::Archived(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &status::Kind::Pending(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &status::Kind::Active(v) => {
+                    os.write_uint64(2, v)?;
+                },
+                &status::Kind::Suspended(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &status::Kind::Closed(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &status::Kind::Archived(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Status {
+        Status::new()
+    }
+
+    fn clear(&mut self) {
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.kind = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Status {
+        static instance: Status = Status {
+            kind: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Status {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Status").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Status {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Status {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Status`
+pub mod status {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:Status.kind)
+    pub enum Kind {
+        // @@protoc_insertion_point(oneof_field:Status.pending)
+        Pending(super::Pending),
+        // @@protoc_insertion_point(oneof_field:Status.active)
+        Active(u64),
+        // @@protoc_insertion_point(oneof_field:Status.suspended)
+        Suspended(super::Suspended),
+        // @@protoc_insertion_point(oneof_field:Status.closed)
+        Closed(super::Closed),
+        // @@protoc_insertion_point(oneof_field:Status.archived)
+        Archived(super::Archived),
+    }
+
+    impl ::protobuf::Oneof for Kind {
+    }
+
+    impl ::protobuf::OneofFull for Kind {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Status as ::protobuf::MessageFull>::descriptor().oneof_by_name("kind").unwrap()).clone()
+        }
+    }
+
+    impl Kind {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Kind>("kind")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:EnumTestData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct EnumTestData {
+    // message fields
+    // @@protoc_insertion_point(field:EnumTestData.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:EnumTestData.status)
+    pub status: ::protobuf::MessageField<Status>,
+    // @@protoc_insertion_point(field:EnumTestData.commands)
+    pub commands: ::std::vec::Vec<::protobuf::EnumOrUnknown<Command>>,
+    // special fields
+    // @@protoc_insertion_point(special_field:EnumTestData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a EnumTestData {
+    fn default() -> &'a EnumTestData {
+        <EnumTestData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EnumTestData {
+    pub fn new() -> EnumTestData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &EnumTestData| { &m.id },
+            |m: &mut EnumTestData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, Status>(
+            "status",
+            |m: &EnumTestData| { &m.status },
+            |m: &mut EnumTestData| { &mut m.status },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "commands",
+            |m: &EnumTestData| { &m.commands },
+            |m: &mut EnumTestData| { &mut m.commands },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<EnumTestData>(
+            "EnumTestData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for EnumTestData {
+    const NAME: &'static str = "EnumTestData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.status)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_repeated_packed_enum_or_unknown_into(is, &mut self.commands)?;
+                },
+                24 => {
+                    self.commands.push(is.read_enum_or_unknown()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        if let Some(v) = self.status.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::vec_packed_enum_or_unknown_size(3, &self.commands);
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        if let Some(v) = self.status.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_repeated_packed_enum_or_unknown(3, &self.commands)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> EnumTestData {
+        EnumTestData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.status.clear();
+        self.commands.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static EnumTestData {
+        static instance: EnumTestData = EnumTestData {
+            id: 0,
+            status: ::protobuf::MessageField::none(),
+            commands: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for EnumTestData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("EnumTestData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for EnumTestData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EnumTestData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:MapTestData)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MapTestData {
+    // message fields
+    // @@protoc_insertion_point(field:MapTestData.id)
+    pub id: u32,
+    // @@protoc_insertion_point(field:MapTestData.attributes)
+    pub attributes: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:MapTestData.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MapTestData {
+    fn default() -> &'a MapTestData {
+        <MapTestData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MapTestData {
+    pub fn new() -> MapTestData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &MapTestData| { &m.id },
+            |m: &mut MapTestData| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor_new::<_, _>(
+            "attributes",
+            |m: &MapTestData| { &m.attributes },
+            |m: &mut MapTestData| { &mut m.attributes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MapTestData>(
+            "MapTestData",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MapTestData {
+    const NAME: &'static str = "MapTestData";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.id = is.read_uint32()?;
+                },
+                18 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.attributes.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.id);
+        }
+        for (k, v) in &self.attributes {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.id != 0 {
+            os.write_uint32(1, self.id)?;
+        }
+        for (k, v) in &self.attributes {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(18)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MapTestData {
+        MapTestData::new()
+    }
+
+    fn clear(&mut self) {
+        self.id = 0;
+        self.attributes.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MapTestData {
+        static instance: ::protobuf::rt::Lazy<MapTestData> = ::protobuf::rt::Lazy::new();
+        instance.get(MapTestData::new)
+    }
+}
+
+impl ::protobuf::MessageFull for MapTestData {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MapTestData").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MapTestData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MapTestData {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:Command)
+pub enum Command {
+    // @@protoc_insertion_point(enum_value:Command.COMMAND_START)
+    COMMAND_START = 0,
+    // @@protoc_insertion_point(enum_value:Command.COMMAND_STOP)
+    COMMAND_STOP = 1,
+    // @@protoc_insertion_point(enum_value:Command.COMMAND_PAUSE)
+    COMMAND_PAUSE = 2,
+    // @@protoc_insertion_point(enum_value:Command.COMMAND_RESUME)
+    COMMAND_RESUME = 3,
+    // @@protoc_insertion_point(enum_value:Command.COMMAND_RESTART)
+    COMMAND_RESTART = 4,
+}
+
+impl ::protobuf::Enum for Command {
+    const NAME: &'static str = "Command";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Command> {
+        match value {
+            0 => ::std::option::Option::Some(Command::COMMAND_START),
+            1 => ::std::option::Option::Some(Command::COMMAND_STOP),
+            2 => ::std::option::Option::Some(Command::COMMAND_PAUSE),
+            3 => ::std::option::Option::Some(Command::COMMAND_RESUME),
+            4 => ::std::option::Option::Some(Command::COMMAND_RESTART),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<Command> {
+        match str {
+            "COMMAND_START" => ::std::option::Option::Some(Command::COMMAND_START),
+            "COMMAND_STOP" => ::std::option::Option::Some(Command::COMMAND_STOP),
+            "COMMAND_PAUSE" => ::std::option::Option::Some(Command::COMMAND_PAUSE),
+            "COMMAND_RESUME" => ::std::option::Option::Some(Command::COMMAND_RESUME),
+            "COMMAND_RESTART" => ::std::option::Option::Some(Command::COMMAND_RESTART),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Command] = &[
+        Command::COMMAND_START,
+        Command::COMMAND_STOP,
+        Command::COMMAND_PAUSE,
+        Command::COMMAND_RESUME,
+        Command::COMMAND_RESTART,
+    ];
+}
+
+impl ::protobuf::EnumFull for Command {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("Command").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Command {
+    fn default() -> Self {
+        Command::COMMAND_START
+    }
+}
+
+impl Command {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Command>("Command")
+    }
+}
+
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\rmessage.proto\"F\n\x08TestData\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\
-    \x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x16\n\x06active\
-    \x18\x03\x20\x01(\x08R\x06activeJ\xfc\x01\n\x06\x12\x04\0\0\x06\x01\n\
-    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x06\x01\n\n\
-    \n\x03\x04\0\x01\x12\x03\x02\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\
-    \x02\x10\n\r\n\x05\x04\0\x02\0\x04\x12\x04\x03\x02\x02\x12\n\x0c\n\x05\
-    \x04\0\x02\0\x05\x12\x03\x03\x02\x08\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\
-    \x03\t\x0b\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x0e\x0f\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x04\x02\x12\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\
-    \x04\x02\x03\x10\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x02\x08\n\x0c\
-    \n\x05\x04\0\x02\x01\x01\x12\x03\x04\t\r\n\x0c\n\x05\x04\0\x02\x01\x03\
-    \x12\x03\x04\x10\x11\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x02\x12\n\r\n\
-    \x05\x04\0\x02\x02\x04\x12\x04\x05\x02\x04\x12\n\x0c\n\x05\x04\0\x02\x02\
-    \x05\x12\x03\x05\x02\x06\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x07\r\
-    \n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05\x10\x11b\x06proto3\
+    \n\rmessage.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"F\n\x08TestDat\
+    a\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\x12\x12\n\x04name\x18\x02\
+    \x20\x01(\tR\x04name\x12\x16\n\x06active\x18\x03\x20\x01(\x08R\x06active\
+    \"\x1b\n\x05Login\x12\x12\n\x04user\x18\x01\x20\x01(\tR\x04user\"\x08\n\
+    \x06Logout\"r\n\x05Event\x12\x1e\n\x05login\x18\x01\x20\x01(\x0b2\x06.Lo\
+    ginH\0R\x05login\x12!\n\x06logout\x18\x02\x20\x01(\x0b2\x07.LogoutH\0R\
+    \x06logout\x12\x1e\n\theartbeat\x18\x03\x20\x01(\x04H\0R\theartbeatB\x06\
+    \n\x04kind\"*\n\x08EventLog\x12\x1e\n\x06events\x18\x01\x20\x03(\x0b2\
+    \x06.EventR\x06events\"0\n\rTestDataBatch\x12\x1f\n\x05items\x18\x01\x20\
+    \x03(\x0b2\t.TestDataR\x05items\"G\n\x07Address\x12\x16\n\x06street\x18\
+    \x01\x20\x01(\tR\x06street\x12\x12\n\x04city\x18\x02\x20\x01(\tR\x04city\
+    \x12\x10\n\x03zip\x18\x03\x20\x01(\rR\x03zip\"1\n\x03Tag\x12\x12\n\x04na\
+    me\x18\x01\x20\x01(\tR\x04name\x12\x16\n\x06weight\x18\x02\x20\x01(\rR\
+    \x06weight\"m\n\x0eNestedTestData\x12\x1d\n\x04data\x18\x01\x20\x01(\x0b\
+    2\t.TestDataR\x04data\x12\"\n\x07address\x18\x02\x20\x01(\x0b2\x08.Addre\
+    ssR\x07address\x12\x18\n\x04tags\x18\x03\x20\x03(\x0b2\x04.TagR\x04tags\
+    \"4\n\x08BlobData\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\x12\x18\n\
+    \x07payload\x18\x02\x20\x01(\x0cR\x07payload\"\xeb\x01\n\x07MapData\x122\
+    \n\x08metadata\x18\x01\x20\x03(\x0b2\x16.MapData.MetadataEntryR\x08metad\
+    ata\x122\n\x08readings\x18\x02\x20\x03(\x0b2\x16.MapData.ReadingsEntryR\
+    \x08readings\x1a;\n\rMetadataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\x1a;\n\
+    \rReadingsEntry\x12\x10\n\x03key\x18\x01\x20\x01(\x04R\x03key\x12\x14\n\
+    \x05value\x18\x02\x20\x01(\x01R\x05value:\x028\x01\"\xa9\x06\n\nSparseDa\
+    ta\x12\x13\n\x02id\x18\x01\x20\x01(\rH\0R\x02id\x88\x01\x01\x12\x17\n\
+    \x04name\x18\x02\x20\x01(\tH\x01R\x04name\x88\x01\x01\x12\x19\n\x05email\
+    \x18\x03\x20\x01(\tH\x02R\x05email\x88\x01\x01\x12\x15\n\x03age\x18\x04\
+    \x20\x01(\rH\x03R\x03age\x88\x01\x01\x12\x19\n\x05score\x18\x05\x20\x01(\
+    \x01H\x04R\x05score\x88\x01\x01\x12\x1b\n\x06active\x18\x06\x20\x01(\x08\
+    H\x05R\x06active\x88\x01\x01\x12\"\n\ncreated_at\x18\x07\x20\x01(\x04H\
+    \x06R\tcreatedAt\x88\x01\x01\x12\"\n\nupdated_at\x18\x08\x20\x01(\x04H\
+    \x07R\tupdatedAt\x88\x01\x01\x12\x20\n\tparent_id\x18\t\x20\x01(\x04H\
+    \x08R\x08parentId\x88\x01\x01\x12\x1d\n\x07country\x18\n\x20\x01(\tH\tR\
+    \x07country\x88\x01\x01\x12\x17\n\x04city\x18\x0b\x20\x01(\tH\nR\x04city\
+    \x88\x01\x01\x12\x15\n\x03zip\x18\x0c\x20\x01(\rH\x0bR\x03zip\x88\x01\
+    \x01\x12\x19\n\x05phone\x18\r\x20\x01(\tH\x0cR\x05phone\x88\x01\x01\x12\
+    \x1f\n\x08verified\x18\x0e\x20\x01(\x08H\rR\x08verified\x88\x01\x01\x12\
+    \x17\n\x04rank\x18\x0f\x20\x01(\x05H\x0eR\x04rank\x88\x01\x01\x12\x1d\n\
+    \x07balance\x18\x10\x20\x01(\x03H\x0fR\x07balance\x88\x01\x01\x12\x1b\n\
+    \x06locale\x18\x11\x20\x01(\tH\x10R\x06locale\x88\x01\x01\x12\x1f\n\x08t\
+    imezone\x18\x12\x20\x01(\tH\x11R\x08timezone\x88\x01\x01\x12\x1d\n\x07re\
+    tries\x18\x13\x20\x01(\rH\x12R\x07retries\x88\x01\x01\x12\x1d\n\x07delet\
+    ed\x18\x14\x20\x01(\x08H\x13R\x07deleted\x88\x01\x01B\x05\n\x03_idB\x07\
+    \n\x05_nameB\x08\n\x06_emailB\x06\n\x04_ageB\x08\n\x06_scoreB\t\n\x07_ac\
+    tiveB\r\n\x0b_created_atB\r\n\x0b_updated_atB\x0c\n\n_parent_idB\n\n\x08\
+    _countryB\x07\n\x05_cityB\x06\n\x04_zipB\x08\n\x06_phoneB\x0b\n\t_verifi\
+    edB\x07\n\x05_rankB\n\n\x08_balanceB\t\n\x07_localeB\x0b\n\t_timezoneB\n\
+    \n\x08_retriesB\n\n\x08_deleted\"E\n\x0bMetricsData\x12\x1e\n\ntimestamp\
+    s\x18\x01\x20\x03(\x04R\ntimestamps\x12\x16\n\x06values\x18\x02\x20\x03(\
+    \x01R\x06values\"\x87\x01\n\x0bUnicodeData\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\rR\x02id\x12\x14\n\x05latin\x18\x02\x20\x01(\tR\x05latin\x12\x10\n\
+    \x03cjk\x18\x03\x20\x01(\tR\x03cjk\x12\x10\n\x03rtl\x18\x04\x20\x01(\tR\
+    \x03rtl\x12\x14\n\x05emoji\x18\x05\x20\x01(\tR\x05emoji\x12\x18\n\x07esc\
+    apes\x18\x06\x20\x01(\tR\x07escapes\"\x1f\n\tBigString\x12\x12\n\x04text\
+    \x18\x01\x20\x01(\tR\x04text\"\xc9\x01\n\rTimestampData\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\rR\x02id\x129\n\ncreated_at\x18\x02\x20\x01(\x0b2\x1a.\
+    google.protobuf.TimestampR\tcreatedAt\x129\n\nupdated_at\x18\x03\x20\x01\
+    (\x0b2\x1a.google.protobuf.TimestampR\tupdatedAt\x122\n\x06events\x18\
+    \x04\x20\x03(\x0b2\x1a.google.protobuf.TimestampR\x06events\"8\n\x08Wide\
+    Ints\x12\x16\n\x06amount\x18\x01\x20\x01(\tR\x06amount\x12\x14\n\x05delt\
+    a\x18\x02\x20\x01(\tR\x05delta\"E\n\nDeepNested\x12\x14\n\x05level\x18\
+    \x01\x20\x01(\rR\x05level\x12!\n\x05child\x18\x02\x20\x01(\x0b2\x0b.Deep\
+    NestedR\x05child\">\n\x04Rgba\x12\x0c\n\x01r\x18\x01\x20\x01(\rR\x01r\
+    \x12\x0c\n\x01g\x18\x02\x20\x01(\rR\x01g\x12\x0c\n\x01b\x18\x03\x20\x01(\
+    \rR\x01b\x12\x0c\n\x01a\x18\x04\x20\x01(\rR\x01a\"j\n\x0bNewtypeData\x12\
+    \x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05email\x18\x02\x20\
+    \x01(\tR\x05email\x12\x18\n\x07version\x18\x03\x20\x01(\rR\x07version\
+    \x12\x1b\n\x05color\x18\x04\x20\x01(\x0b2\x05.RgbaR\x05color\"O\n\x0cUui\
+    dTestData\x12\x0e\n\x02id\x18\x01\x20\x01(\x0cR\x02id\x12\x1b\n\tparent_\
+    id\x18\x02\x20\x01(\x0cR\x08parentId\x12\x12\n\x04name\x18\x03\x20\x01(\
+    \tR\x04name\"\t\n\x07Pending\"3\n\tSuspended\x12\x12\n\x04days\x18\x01\
+    \x20\x01(\rR\x04days\x12\x12\n\x04note\x18\x02\x20\x01(\tR\x04note\"0\n\
+    \x06Closed\x12\x16\n\x06reason\x18\x01\x20\x01(\tR\x06reason\x12\x0e\n\
+    \x02at\x18\x02\x20\x01(\x04R\x02at\"\n\n\x08Archived\"\xc8\x01\n\x06Stat\
+    us\x12$\n\x07pending\x18\x01\x20\x01(\x0b2\x08.PendingH\0R\x07pending\
+    \x12\x18\n\x06active\x18\x02\x20\x01(\x04H\0R\x06active\x12*\n\tsuspende\
+    d\x18\x03\x20\x01(\x0b2\n.SuspendedH\0R\tsuspended\x12!\n\x06closed\x18\
+    \x04\x20\x01(\x0b2\x07.ClosedH\0R\x06closed\x12'\n\x08archived\x18\x05\
+    \x20\x01(\x0b2\t.ArchivedH\0R\x08archivedB\x06\n\x04kind\"e\n\x0cEnumTes\
+    tData\x12\x0e\n\x02id\x18\x01\x20\x01(\rR\x02id\x12\x1f\n\x06status\x18\
+    \x02\x20\x01(\x0b2\x07.StatusR\x06status\x12$\n\x08commands\x18\x03\x20\
+    \x03(\x0e2\x08.CommandR\x08commands\"\x9a\x01\n\x0bMapTestData\x12\x0e\n\
+    \x02id\x18\x01\x20\x01(\rR\x02id\x12<\n\nattributes\x18\x02\x20\x03(\x0b\
+    2\x1c.MapTestData.AttributesEntryR\nattributes\x1a=\n\x0fAttributesEntry\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\
+    \x20\x01(\tR\x05value:\x028\x01*j\n\x07Command\x12\x11\n\rCOMMAND_START\
+    \x10\0\x12\x10\n\x0cCOMMAND_STOP\x10\x01\x12\x11\n\rCOMMAND_PAUSE\x10\
+    \x02\x12\x12\n\x0eCOMMAND_RESUME\x10\x03\x12\x13\n\x0fCOMMAND_RESTART\
+    \x10\x04b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -214,10 +5109,39 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
     static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
     file_descriptor.get(|| {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
-            let mut deps = ::std::vec::Vec::with_capacity(0);
-            let mut messages = ::std::vec::Vec::with_capacity(1);
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(::protobuf::well_known_types::timestamp::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(28);
             messages.push(TestData::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            messages.push(Login::generated_message_descriptor_data());
+            messages.push(Logout::generated_message_descriptor_data());
+            messages.push(Event::generated_message_descriptor_data());
+            messages.push(EventLog::generated_message_descriptor_data());
+            messages.push(TestDataBatch::generated_message_descriptor_data());
+            messages.push(Address::generated_message_descriptor_data());
+            messages.push(Tag::generated_message_descriptor_data());
+            messages.push(NestedTestData::generated_message_descriptor_data());
+            messages.push(BlobData::generated_message_descriptor_data());
+            messages.push(MapData::generated_message_descriptor_data());
+            messages.push(SparseData::generated_message_descriptor_data());
+            messages.push(MetricsData::generated_message_descriptor_data());
+            messages.push(UnicodeData::generated_message_descriptor_data());
+            messages.push(BigString::generated_message_descriptor_data());
+            messages.push(TimestampData::generated_message_descriptor_data());
+            messages.push(WideInts::generated_message_descriptor_data());
+            messages.push(DeepNested::generated_message_descriptor_data());
+            messages.push(Rgba::generated_message_descriptor_data());
+            messages.push(NewtypeData::generated_message_descriptor_data());
+            messages.push(UuidTestData::generated_message_descriptor_data());
+            messages.push(Pending::generated_message_descriptor_data());
+            messages.push(Suspended::generated_message_descriptor_data());
+            messages.push(Closed::generated_message_descriptor_data());
+            messages.push(Archived::generated_message_descriptor_data());
+            messages.push(Status::generated_message_descriptor_data());
+            messages.push(EnumTestData::generated_message_descriptor_data());
+            messages.push(MapTestData::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(Command::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
@@ -0,0 +1,1563 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+// @generated
+
+#![allow(clippy::all)]
+#![allow(dead_code)]
+#![allow(unused_imports)]
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_COMMAND: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_COMMAND: u8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COMMAND: [Command; 5] = [
+  Command::Start,
+  Command::Stop,
+  Command::Pause,
+  Command::Resume,
+  Command::Restart,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Command(pub u8);
+#[allow(non_upper_case_globals)]
+impl Command {
+  pub const Start: Self = Self(0);
+  pub const Stop: Self = Self(1);
+  pub const Pause: Self = Self(2);
+  pub const Resume: Self = Self(3);
+  pub const Restart: Self = Self(4);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Start,
+    Self::Stop,
+    Self::Pause,
+    Self::Resume,
+    Self::Restart,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Start => Some("Start"),
+      Self::Stop => Some("Stop"),
+      Self::Pause => Some("Pause"),
+      Self::Resume => Some("Resume"),
+      Self::Restart => Some("Restart"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Command {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Command {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Command {
+    type Output = Command;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for Command {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Command {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Command {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_STATUS: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_STATUS: u8 = 5;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_STATUS: [Status; 6] = [
+  Status::NONE,
+  Status::Pending,
+  Status::Active,
+  Status::Suspended,
+  Status::Closed,
+  Status::Archived,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Status(pub u8);
+#[allow(non_upper_case_globals)]
+impl Status {
+  pub const NONE: Self = Self(0);
+  pub const Pending: Self = Self(1);
+  pub const Active: Self = Self(2);
+  pub const Suspended: Self = Self(3);
+  pub const Closed: Self = Self(4);
+  pub const Archived: Self = Self(5);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 5;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::Pending,
+    Self::Active,
+    Self::Suspended,
+    Self::Closed,
+    Self::Archived,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::Pending => Some("Pending"),
+      Self::Active => Some("Active"),
+      Self::Suspended => Some("Suspended"),
+      Self::Closed => Some("Closed"),
+      Self::Archived => Some("Archived"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Status {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Status {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Status {
+    type Output = Status;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0) };
+    }
+}
+
+impl flatbuffers::EndianScalar for Status {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Status {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Status {}
+pub struct StatusUnionTableOffset {}
+
+pub enum TestDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TestData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TestData<'a> {
+  type Inner = TestData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> TestData<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_ACTIVE: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TestData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TestDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<TestData<'bldr>> {
+    let mut builder = TestDataBuilder::new(_fbb);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_id(args.id);
+    builder.add_active(args.active);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TestData::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TestData::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn active(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(TestData::VT_ACTIVE, Some(false)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TestData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<bool>("active", Self::VT_ACTIVE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TestDataArgs<'a> {
+    pub id: u32,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub active: bool,
+}
+impl<'a> Default for TestDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TestDataArgs {
+      id: 0,
+      name: None,
+      active: false,
+    }
+  }
+}
+
+pub struct TestDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TestDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(TestData::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TestData::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_active(&mut self, active: bool) {
+    self.fbb_.push_slot::<bool>(TestData::VT_ACTIVE, active, false);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TestDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TestDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TestData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TestData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TestData");
+      ds.field("id", &self.id());
+      ds.field("name", &self.name());
+      ds.field("active", &self.active());
+      ds.finish()
+  }
+}
+pub enum TestDataBatchOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TestDataBatch<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TestDataBatch<'a> {
+  type Inner = TestDataBatch<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> TestDataBatch<'a> {
+  pub const VT_ITEMS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TestDataBatch { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TestDataBatchArgs<'args>
+  ) -> flatbuffers::WIPOffset<TestDataBatch<'bldr>> {
+    let mut builder = TestDataBatchBuilder::new(_fbb);
+    if let Some(x) = args.items { builder.add_items(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn items(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TestData<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TestData>>>>(TestDataBatch::VT_ITEMS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TestDataBatch<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<TestData>>>>("items", Self::VT_ITEMS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TestDataBatchArgs<'a> {
+    pub items: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<TestData<'a>>>>>,
+}
+impl<'a> Default for TestDataBatchArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TestDataBatchArgs {
+      items: None,
+    }
+  }
+}
+
+pub struct TestDataBatchBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TestDataBatchBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_items(&mut self, items: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<TestData<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TestDataBatch::VT_ITEMS, items);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TestDataBatchBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TestDataBatchBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TestDataBatch<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TestDataBatch<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TestDataBatch");
+      ds.field("items", &self.items());
+      ds.finish()
+  }
+}
+pub enum BlobDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BlobData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BlobData<'a> {
+  type Inner = BlobData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> BlobData<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_PAYLOAD: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BlobData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args BlobDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<BlobData<'bldr>> {
+    let mut builder = BlobDataBuilder::new(_fbb);
+    if let Some(x) = args.payload { builder.add_payload(x); }
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(BlobData::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn payload(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BlobData::VT_PAYLOAD, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for BlobData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("payload", Self::VT_PAYLOAD, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BlobDataArgs<'a> {
+    pub id: u32,
+    pub payload: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for BlobDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BlobDataArgs {
+      id: 0,
+      payload: None,
+    }
+  }
+}
+
+pub struct BlobDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BlobDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(BlobData::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_payload(&mut self, payload: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BlobData::VT_PAYLOAD, payload);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> BlobDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    BlobDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BlobData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BlobData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BlobData");
+      ds.field("id", &self.id());
+      ds.field("payload", &self.payload());
+      ds.finish()
+  }
+}
+pub enum PendingOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Pending<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Pending<'a> {
+  type Inner = Pending<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Pending<'a> {
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Pending { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args PendingArgs
+  ) -> flatbuffers::WIPOffset<Pending<'bldr>> {
+    let mut builder = PendingBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for Pending<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PendingArgs {
+}
+impl<'a> Default for PendingArgs {
+  #[inline]
+  fn default() -> Self {
+    PendingArgs {
+    }
+  }
+}
+
+pub struct PendingBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PendingBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PendingBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PendingBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Pending<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Pending<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Pending");
+      ds.finish()
+  }
+}
+pub enum ActiveOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Active<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Active<'a> {
+  type Inner = Active<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Active<'a> {
+  pub const VT_SINCE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Active { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ActiveArgs
+  ) -> flatbuffers::WIPOffset<Active<'bldr>> {
+    let mut builder = ActiveBuilder::new(_fbb);
+    builder.add_since(args.since);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn since(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Active::VT_SINCE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Active<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u64>("since", Self::VT_SINCE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ActiveArgs {
+    pub since: u64,
+}
+impl<'a> Default for ActiveArgs {
+  #[inline]
+  fn default() -> Self {
+    ActiveArgs {
+      since: 0,
+    }
+  }
+}
+
+pub struct ActiveBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ActiveBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_since(&mut self, since: u64) {
+    self.fbb_.push_slot::<u64>(Active::VT_SINCE, since, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ActiveBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ActiveBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Active<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Active<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Active");
+      ds.field("since", &self.since());
+      ds.finish()
+  }
+}
+pub enum SuspendedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Suspended<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Suspended<'a> {
+  type Inner = Suspended<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Suspended<'a> {
+  pub const VT_DAYS: flatbuffers::VOffsetT = 4;
+  pub const VT_NOTE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Suspended { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SuspendedArgs<'args>
+  ) -> flatbuffers::WIPOffset<Suspended<'bldr>> {
+    let mut builder = SuspendedBuilder::new(_fbb);
+    if let Some(x) = args.note { builder.add_note(x); }
+    builder.add_days(args.days);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn days(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Suspended::VT_DAYS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn note(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Suspended::VT_NOTE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Suspended<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u32>("days", Self::VT_DAYS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("note", Self::VT_NOTE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SuspendedArgs<'a> {
+    pub days: u32,
+    pub note: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SuspendedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SuspendedArgs {
+      days: 0,
+      note: None,
+    }
+  }
+}
+
+pub struct SuspendedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SuspendedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_days(&mut self, days: u32) {
+    self.fbb_.push_slot::<u32>(Suspended::VT_DAYS, days, 0);
+  }
+  #[inline]
+  pub fn add_note(&mut self, note: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Suspended::VT_NOTE, note);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SuspendedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SuspendedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Suspended<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Suspended<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Suspended");
+      ds.field("days", &self.days());
+      ds.field("note", &self.note());
+      ds.finish()
+  }
+}
+pub enum ClosedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Closed<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Closed<'a> {
+  type Inner = Closed<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Closed<'a> {
+  pub const VT_REASON: flatbuffers::VOffsetT = 4;
+  pub const VT_AT: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Closed { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ClosedArgs<'args>
+  ) -> flatbuffers::WIPOffset<Closed<'bldr>> {
+    let mut builder = ClosedBuilder::new(_fbb);
+    builder.add_at(args.at);
+    if let Some(x) = args.reason { builder.add_reason(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn reason(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Closed::VT_REASON, None)}
+  }
+  #[inline]
+  pub fn at(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(Closed::VT_AT, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Closed<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("reason", Self::VT_REASON, false)?
+     .visit_field::<u64>("at", Self::VT_AT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ClosedArgs<'a> {
+    pub reason: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub at: u64,
+}
+impl<'a> Default for ClosedArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ClosedArgs {
+      reason: None,
+      at: 0,
+    }
+  }
+}
+
+pub struct ClosedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ClosedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_reason(&mut self, reason: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Closed::VT_REASON, reason);
+  }
+  #[inline]
+  pub fn add_at(&mut self, at: u64) {
+    self.fbb_.push_slot::<u64>(Closed::VT_AT, at, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ClosedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ClosedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Closed<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Closed<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Closed");
+      ds.field("reason", &self.reason());
+      ds.field("at", &self.at());
+      ds.finish()
+  }
+}
+pub enum ArchivedOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Archived<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Archived<'a> {
+  type Inner = Archived<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Archived<'a> {
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Archived { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args ArchivedArgs
+  ) -> flatbuffers::WIPOffset<Archived<'bldr>> {
+    let mut builder = ArchivedBuilder::new(_fbb);
+    builder.finish()
+  }
+
+}
+
+impl flatbuffers::Verifiable for Archived<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ArchivedArgs {
+}
+impl<'a> Default for ArchivedArgs {
+  #[inline]
+  fn default() -> Self {
+    ArchivedArgs {
+    }
+  }
+}
+
+pub struct ArchivedBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArchivedBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ArchivedBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ArchivedBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Archived<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Archived<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Archived");
+      ds.finish()
+  }
+}
+pub enum EnumTestDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EnumTestData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EnumTestData<'a> {
+  type Inner = EnumTestData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EnumTestData<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_STATUS_TYPE: flatbuffers::VOffsetT = 6;
+  pub const VT_STATUS: flatbuffers::VOffsetT = 8;
+  pub const VT_COMMANDS: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EnumTestData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EnumTestDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<EnumTestData<'bldr>> {
+    let mut builder = EnumTestDataBuilder::new(_fbb);
+    if let Some(x) = args.commands { builder.add_commands(x); }
+    if let Some(x) = args.status { builder.add_status(x); }
+    builder.add_id(args.id);
+    builder.add_status_type(args.status_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(EnumTestData::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn status_type(&self) -> Status {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Status>(EnumTestData::VT_STATUS_TYPE, Some(Status::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn status(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(EnumTestData::VT_STATUS, None)}
+  }
+  #[inline]
+  pub fn commands(&self) -> Option<flatbuffers::Vector<'a, Command>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Command>>>(EnumTestData::VT_COMMANDS, None)}
+  }
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn status_as_pending(&self) -> Option<Pending<'a>> {
+    if self.status_type() == Status::Pending {
+      self.status().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Pending::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn status_as_active(&self) -> Option<Active<'a>> {
+    if self.status_type() == Status::Active {
+      self.status().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Active::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn status_as_suspended(&self) -> Option<Suspended<'a>> {
+    if self.status_type() == Status::Suspended {
+      self.status().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Suspended::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn status_as_closed(&self) -> Option<Closed<'a>> {
+    if self.status_type() == Status::Closed {
+      self.status().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Closed::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+
+  #[inline]
+  #[allow(non_snake_case)]
+  pub fn status_as_archived(&self) -> Option<Archived<'a>> {
+    if self.status_type() == Status::Archived {
+      self.status().map(|t| {
+       // Safety:
+       // Created from a valid Table for this object
+       // Which contains a valid union in this slot
+       unsafe { Archived::init_from_table(t) }
+     })
+    } else {
+      None
+    }
+  }
+}
+
+impl flatbuffers::Verifiable for EnumTestData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_union::<Status, _>("status_type", Self::VT_STATUS_TYPE, "status", Self::VT_STATUS, false, |key, v, pos| {
+        match key {
+          Status::Pending => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Pending>>("Status::Pending", pos),
+          Status::Active => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Active>>("Status::Active", pos),
+          Status::Suspended => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Suspended>>("Status::Suspended", pos),
+          Status::Closed => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Closed>>("Status::Closed", pos),
+          Status::Archived => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Archived>>("Status::Archived", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Command>>>("commands", Self::VT_COMMANDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EnumTestDataArgs<'a> {
+    pub id: u32,
+    pub status_type: Status,
+    pub status: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub commands: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Command>>>,
+}
+impl<'a> Default for EnumTestDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EnumTestDataArgs {
+      id: 0,
+      status_type: Status::NONE,
+      status: None,
+      commands: None,
+    }
+  }
+}
+
+pub struct EnumTestDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EnumTestDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(EnumTestData::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_status_type(&mut self, status_type: Status) {
+    self.fbb_.push_slot::<Status>(EnumTestData::VT_STATUS_TYPE, status_type, Status::NONE);
+  }
+  #[inline]
+  pub fn add_status(&mut self, status: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EnumTestData::VT_STATUS, status);
+  }
+  #[inline]
+  pub fn add_commands(&mut self, commands: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Command>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EnumTestData::VT_COMMANDS, commands);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EnumTestDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EnumTestDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EnumTestData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EnumTestData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EnumTestData");
+      ds.field("id", &self.id());
+      ds.field("status_type", &self.status_type());
+      match self.status_type() {
+        Status::Pending => {
+          if let Some(x) = self.status_as_pending() {
+            ds.field("status", &x)
+          } else {
+            ds.field("status", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Status::Active => {
+          if let Some(x) = self.status_as_active() {
+            ds.field("status", &x)
+          } else {
+            ds.field("status", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Status::Suspended => {
+          if let Some(x) = self.status_as_suspended() {
+            ds.field("status", &x)
+          } else {
+            ds.field("status", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Status::Closed => {
+          if let Some(x) = self.status_as_closed() {
+            ds.field("status", &x)
+          } else {
+            ds.field("status", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        Status::Archived => {
+          if let Some(x) = self.status_as_archived() {
+            ds.field("status", &x)
+          } else {
+            ds.field("status", &"InvalidFlatbuffer: Union discriminant does not match value.")
+          }
+        },
+        _ => {
+          let x: Option<()> = None;
+          ds.field("status", &x)
+        },
+      };
+      ds.field("commands", &self.commands());
+      ds.finish()
+  }
+}
+pub enum AttributeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Attribute<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Attribute<'a> {
+  type Inner = Attribute<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Attribute<'a> {
+  pub const VT_KEY: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Attribute { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AttributeArgs<'args>
+  ) -> flatbuffers::WIPOffset<Attribute<'bldr>> {
+    let mut builder = AttributeBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.key { builder.add_key(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Attribute::VT_KEY, None)}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Attribute::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for Attribute<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AttributeArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for AttributeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AttributeArgs {
+      key: None,
+      value: None,
+    }
+  }
+}
+
+pub struct AttributeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AttributeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Attribute::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Attribute::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AttributeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AttributeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Attribute<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Attribute<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Attribute");
+      ds.field("key", &self.key());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum MapTestDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MapTestData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MapTestData<'a> {
+  type Inner = MapTestData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> MapTestData<'a> {
+  pub const VT_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MapTestData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MapTestDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<MapTestData<'bldr>> {
+    let mut builder = MapTestDataBuilder::new(_fbb);
+    if let Some(x) = args.attributes { builder.add_attributes(x); }
+    builder.add_id(args.id);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn id(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(MapTestData::VT_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn attributes(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Attribute<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Attribute>>>>(MapTestData::VT_ATTRIBUTES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for MapTestData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<u32>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Attribute>>>>("attributes", Self::VT_ATTRIBUTES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MapTestDataArgs<'a> {
+    pub id: u32,
+    pub attributes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Attribute<'a>>>>>,
+}
+impl<'a> Default for MapTestDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MapTestDataArgs {
+      id: 0,
+      attributes: None,
+    }
+  }
+}
+
+pub struct MapTestDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MapTestDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_id(&mut self, id: u32) {
+    self.fbb_.push_slot::<u32>(MapTestData::VT_ID, id, 0);
+  }
+  #[inline]
+  pub fn add_attributes(&mut self, attributes: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Attribute<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MapTestData::VT_ATTRIBUTES, attributes);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MapTestDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MapTestDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MapTestData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MapTestData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MapTestData");
+      ds.field("id", &self.id());
+      ds.field("attributes", &self.attributes());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `TestData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_test_data_unchecked`.
+pub fn root_as_test_data(buf: &[u8]) -> Result<TestData<'_>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<TestData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `TestData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_test_data_unchecked`.
+pub fn size_prefixed_root_as_test_data(buf: &[u8]) -> Result<TestData<'_>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<TestData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a TestData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `TestData`.
+pub unsafe fn root_as_test_data_unchecked(buf: &[u8]) -> TestData<'_> {
+  unsafe { flatbuffers::root_unchecked::<TestData>(buf) }
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed TestData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `TestData`.
+pub unsafe fn size_prefixed_root_as_test_data_unchecked(buf: &[u8]) -> TestData<'_> {
+  unsafe { flatbuffers::size_prefixed_root_unchecked::<TestData>(buf) }
+}
+#[inline]
+pub fn finish_test_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<TestData<'a>>) {
+  fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_test_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<TestData<'a>>) {
+  fbb.finish_size_prefixed(root, None);
+}
@@ -0,0 +1,1477 @@
+use alkahest::alkahest;
+use benchdata_derive::BenchmarkData;
+use borsh::{BorshSerialize, BorshDeserialize};
+use chrono::{DateTime, Utc};
+use musli::{Encode, Decode};
+use nanoserde::{DeBin, DeJson, SerBin, SerJson};
+// `rasn::Decode`'s derive output calls `Decoder` methods unqualified
+use rasn::Decoder;
+use savefile_derive::Savefile;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use speedy::{Context, Readable, Reader, Writable, Writer};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use uuid::Uuid;
+
+use crate::test_data::{DerTestData, NanoTestData, TestData};
+use crate::{google, prost_proto, proto, quick_proto};
+
+// Enum payload covering a struct, a unit and a tuple variant, so each format's
+// discriminant encoding (tag bytes, tagged JSON objects, protobuf oneofs) shows up
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub enum Event {
+    Login { user: String },
+    Logout,
+    Heartbeat(u64),
+}
+
+impl Event {
+    // One event of each variant
+    pub fn sample() -> Vec<Event> {
+        vec![
+            Event::Login { user: "Rust".to_string() },
+            Event::Logout,
+            Event::Heartbeat(1_700_000_000),
+        ]
+    }
+}
+
+// Map events onto the `Event.kind` oneof; `Logout` becomes an empty message so
+// every variant still costs a field tag on the wire, like a discriminant
+pub fn proto_events(events: &[Event]) -> proto::EventLog {
+    let mut log = proto::EventLog::new();
+    for event in events {
+        let mut proto_event = proto::Event::new();
+        match event {
+            Event::Login { user } => {
+                let mut login = proto::Login::new();
+                login.user = user.clone();
+                proto_event.set_login(login);
+            }
+            Event::Logout => proto_event.set_logout(proto::Logout::new()),
+            Event::Heartbeat(timestamp) => proto_event.set_heartbeat(*timestamp),
+        }
+        log.events.push(proto_event);
+    }
+    log
+}
+
+// Same mapping for quick-protobuf, borrowing the user names from `events`
+pub fn quick_proto_events(events: &[Event]) -> quick_proto::EventLog<'_> {
+    quick_proto::EventLog {
+        events: events.iter()
+            .map(|event| quick_proto::Event {
+                kind: match event {
+                    Event::Login { user } => quick_proto::mod_Event::OneOfkind::login(quick_proto::Login { user: Cow::Borrowed(user) }),
+                    Event::Logout => quick_proto::mod_Event::OneOfkind::logout(quick_proto::Logout {}),
+                    Event::Heartbeat(timestamp) => quick_proto::mod_Event::OneOfkind::heartbeat(*timestamp),
+                },
+            })
+            .collect(),
+    }
+}
+
+pub fn events_from_proto(log: &proto::EventLog) -> Vec<Event> {
+    log.events.iter()
+        .map(|event| match &event.kind {
+            Some(proto::event::Kind::Login(login)) => Event::Login { user: login.user.clone() },
+            Some(proto::event::Kind::Logout(_)) => Event::Logout,
+            Some(proto::event::Kind::Heartbeat(timestamp)) => Event::Heartbeat(*timestamp),
+            None => panic!("protobuf event is missing its `kind`"),
+        })
+        .collect()
+}
+
+// `TestData` plus an inner struct and a list of small structs, so the cost of
+// nesting (length prefixes, sub-message tags, field names) shows up per format
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, BenchmarkData, Debug, Clone, PartialEq)]
+pub struct NestedTestData {
+    pub data: TestData,
+    pub address: Address,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, BenchmarkData, Debug, Clone, PartialEq)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub zip: u32,
+}
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, BenchmarkData, Debug, Clone, PartialEq)]
+pub struct Tag {
+    pub name: String,
+    pub weight: u32,
+}
+
+impl NestedTestData {
+    pub fn sample() -> Self {
+        NestedTestData {
+            data: TestData::new(),
+            address: Address {
+                street: "1 Ferris Lane".to_string(),
+                city: "Crabtown".to_string(),
+                zip: 12345,
+            },
+            tags: vec![
+                Tag { name: "serde".to_string(), weight: 1 },
+                Tag { name: "binary".to_string(), weight: 2 },
+                Tag { name: "bench".to_string(), weight: 3 },
+            ],
+        }
+    }
+}
+
+pub fn proto_nested(nested: &NestedTestData) -> proto::NestedTestData {
+    let mut message = proto::NestedTestData::new();
+    message.data = protobuf::MessageField::some(crate::proto_test_data(&nested.data));
+    message.address = protobuf::MessageField::some(proto::Address {
+        street: nested.address.street.clone(),
+        city: nested.address.city.clone(),
+        zip: nested.address.zip,
+        ..Default::default()
+    });
+    message.tags = nested.tags.iter()
+        .map(|tag| proto::Tag { name: tag.name.clone(), weight: tag.weight, ..Default::default() })
+        .collect();
+    message
+}
+
+// Borrows every string from `nested`, like `quick_proto_test_data`
+pub fn quick_proto_nested(nested: &NestedTestData) -> quick_proto::NestedTestData<'_> {
+    quick_proto::NestedTestData {
+        data: Some(crate::quick_proto_test_data(&nested.data)),
+        address: Some(quick_proto::Address {
+            street: Cow::Borrowed(&nested.address.street),
+            city: Cow::Borrowed(&nested.address.city),
+            zip: nested.address.zip,
+        }),
+        tags: nested.tags.iter()
+            .map(|tag| quick_proto::Tag { name: Cow::Borrowed(&tag.name), weight: tag.weight })
+            .collect(),
+    }
+}
+
+// nanoserde mirror of `NestedTestData`
+#[derive(SerJson, DeJson, SerBin, DeBin, Debug, Clone, PartialEq)]
+pub struct NanoNestedTestData {
+    pub data: NanoTestData,
+    pub address: NanoAddress,
+    pub tags: Vec<NanoTag>,
+}
+
+#[derive(SerJson, DeJson, SerBin, DeBin, Debug, Clone, PartialEq)]
+pub struct NanoAddress {
+    pub street: String,
+    pub city: String,
+    pub zip: u32,
+}
+
+#[derive(SerJson, DeJson, SerBin, DeBin, Debug, Clone, PartialEq)]
+pub struct NanoTag {
+    pub name: String,
+    pub weight: u32,
+}
+
+pub fn nano_nested(nested: &NestedTestData) -> NanoNestedTestData {
+    NanoNestedTestData {
+        data: crate::nano_test_data(&nested.data),
+        address: NanoAddress {
+            street: nested.address.street.clone(),
+            city: nested.address.city.clone(),
+            zip: nested.address.zip,
+        },
+        tags: nested.tags.iter()
+            .map(|tag| NanoTag { name: tag.name.clone(), weight: tag.weight })
+            .collect(),
+    }
+}
+
+// ASN.1 mirror of `NestedTestData`: nested SEQUENCEs with the tags as a SEQUENCE OF
+#[derive(rasn::AsnType, rasn::Encode, rasn::Decode, Debug, Clone, PartialEq)]
+pub struct DerNestedTestData {
+    pub data: DerTestData,
+    pub address: DerAddress,
+    pub tags: Vec<DerTag>,
+}
+
+#[derive(rasn::AsnType, rasn::Encode, rasn::Decode, Debug, Clone, PartialEq)]
+pub struct DerAddress {
+    pub street: rasn::types::Utf8String,
+    pub city: rasn::types::Utf8String,
+    pub zip: u32,
+}
+
+#[derive(rasn::AsnType, rasn::Encode, rasn::Decode, Debug, Clone, PartialEq)]
+pub struct DerTag {
+    pub name: rasn::types::Utf8String,
+    pub weight: u32,
+}
+
+pub fn der_nested(nested: &NestedTestData) -> DerNestedTestData {
+    DerNestedTestData {
+        data: crate::der_test_data(&nested.data),
+        address: DerAddress {
+            street: nested.address.street.clone(),
+            city: nested.address.city.clone(),
+            zip: nested.address.zip,
+        },
+        tags: nested.tags.iter()
+            .map(|tag| DerTag { name: tag.name.clone(), weight: tag.weight })
+            .collect(),
+    }
+}
+
+// Small header plus an opaque payload (an image, a compressed chunk, a signature)
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone)]
+#[alkahest(SerializeRef<AlkahestBlobData>)]
+pub struct BlobData {
+    pub id: u32,
+    pub payload: Vec<u8>,
+}
+
+// Seed of the generator that fills `BlobData::sample`
+const BLOB_SEED: u32 = 0x5EED_B10B;
+
+impl BlobData {
+    // A blob of `len` pseudo-random bytes from a fixed-seed LCG, so the payload
+    // is identical on every run but has no pattern a compressor could exploit
+    pub fn sample(len: usize) -> Self {
+        let mut state = BLOB_SEED;
+        BlobData {
+            id: 1,
+            payload: (0..len)
+                .map(|_| {
+                    // Numerical Recipes constants; the high byte is the most random
+                    state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                    (state >> 24) as u8
+                })
+                .collect(),
+        }
+    }
+}
+
+// `BlobData` with the payload as a base64 string, the usual way to put bytes in JSON
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Base64BlobData {
+    pub id: u32,
+    #[serde(with = "base64_payload")]
+    pub payload: Vec<u8>,
+}
+
+mod base64_payload {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(payload: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(payload))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = <&str>::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+pub fn base64_blob(blob: &BlobData) -> Base64BlobData {
+    Base64BlobData { id: blob.id, payload: blob.payload.clone() }
+}
+
+pub fn proto_blob(blob: &BlobData) -> proto::BlobData {
+    proto::BlobData { id: blob.id, payload: blob.payload.clone(), ..Default::default() }
+}
+
+pub fn quick_proto_blob(blob: &BlobData) -> quick_proto::BlobData<'_> {
+    quick_proto::BlobData { id: blob.id, payload: Cow::Borrowed(&blob.payload) }
+}
+
+// nanoserde mirror of `BlobData`
+#[derive(SerJson, DeJson, SerBin, DeBin, Debug, Clone)]
+pub struct NanoBlobData {
+    pub id: u32,
+    pub payload: Vec<u8>,
+}
+
+pub fn nano_blob(blob: &BlobData) -> NanoBlobData {
+    NanoBlobData { id: blob.id, payload: blob.payload.clone() }
+}
+
+// ASN.1 mirror of `BlobData`, with the payload as an OCTET STRING
+#[derive(rasn::AsnType, rasn::Encode, rasn::Decode, Debug, Clone)]
+pub struct DerBlobData {
+    pub id: u32,
+    pub payload: rasn::types::OctetString,
+}
+
+pub fn der_blob(blob: &BlobData) -> DerBlobData {
+    DerBlobData { id: blob.id, payload: blob.payload.clone().into() }
+}
+
+// alkahest schema for `BlobData`, with the payload as raw bytes rather than a
+// list of `u8`s
+#[alkahest(Formula)]
+pub struct AlkahestBlobData {
+    id: u32,
+    payload: alkahest::Bytes,
+}
+
+// Zero-copy read side of `AlkahestBlobData`: `payload` borrows from the buffer
+#[alkahest(Deserialize<'de, AlkahestBlobData>)]
+pub struct AlkahestBlobDataView<'de> {
+    pub id: u32,
+    pub payload: &'de [u8],
+}
+
+// Avro schema for `BlobData`, with the payload as `bytes`
+const AVRO_BLOB_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "BlobData",
+    "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "payload", "type": "bytes"}
+    ]
+}"#;
+
+pub fn avro_blob_schema() -> apache_avro::Schema {
+    apache_avro::Schema::parse_str(AVRO_BLOB_SCHEMA).unwrap()
+}
+
+pub fn avro_blob_record(schema: &apache_avro::Schema, blob: &BlobData) -> apache_avro::types::Value {
+    let mut record = apache_avro::types::Record::new(schema).unwrap();
+    record.put("id", i64::from(blob.id));
+    record.put("payload", apache_avro::types::Value::Bytes(blob.payload.clone()));
+    record.into()
+}
+
+// Entries in each of `MapData`'s maps
+pub const MAP_ENTRIES: usize = 100;
+
+// Metadata-style payload: an unordered map of string pairs next to an ordered
+// map of numeric readings, so rebuilding both kinds of map on deserialize shows up
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct MapData {
+    pub metadata: HashMap<String, String>,
+    pub readings: BTreeMap<u64, f64>,
+}
+
+impl MapData {
+    // `MAP_ENTRIES` entries in each map
+    pub fn sample() -> Self {
+        MapData {
+            metadata: (0..MAP_ENTRIES)
+                .map(|index| (format!("key-{:03}", index), format!("value-{:03}", index)))
+                .collect(),
+            readings: (0..MAP_ENTRIES as u64)
+                .map(|index| (1_700_000_000 + index, index as f64 * 0.25))
+                .collect(),
+        }
+    }
+}
+
+// protobuf maps are always `HashMap`s, so the readings lose their ordering
+pub fn proto_map_data(maps: &MapData) -> proto::MapData {
+    proto::MapData {
+        metadata: maps.metadata.clone(),
+        readings: maps.readings.iter().map(|(key, value)| (*key, *value)).collect(),
+        ..Default::default()
+    }
+}
+
+pub fn map_data_from_proto(message: &proto::MapData) -> MapData {
+    MapData {
+        metadata: message.metadata.clone(),
+        readings: message.readings.iter().map(|(key, value)| (*key, *value)).collect(),
+    }
+}
+
+// Borrows the metadata strings from `maps`, like `quick_proto_test_data`
+pub fn quick_proto_map_data(maps: &MapData) -> quick_proto::MapData<'_> {
+    quick_proto::MapData {
+        metadata: maps.metadata.iter()
+            .map(|(key, value)| (Cow::Borrowed(key.as_str()), Cow::Borrowed(value.as_str())))
+            .collect(),
+        readings: maps.readings.iter().map(|(key, value)| (*key, *value)).collect(),
+    }
+}
+
+// Pairs in `MapTestData::sample()`
+pub const MAP_ATTRIBUTES: usize = 20;
+
+// A record with a string-to-string attribute map, the shape of dynamic
+// configuration and event properties. Protobuf writes each pair as a
+// `map<string, string>` entry sub-message; Cap'n Proto and FlatBuffers, which
+// have no map type, as a list of key/value structs
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct MapTestData {
+    pub id: u32,
+    pub attributes: HashMap<String, String>,
+}
+
+impl MapTestData {
+    pub fn sample() -> Self {
+        MapTestData {
+            id: 1,
+            attributes: (0..MAP_ATTRIBUTES)
+                .map(|index| (format!("attribute-{:02}", index), format!("value-{:02}", index)))
+                .collect(),
+        }
+    }
+}
+
+pub fn proto_map_test_data(data: &MapTestData) -> proto::MapTestData {
+    proto::MapTestData {
+        id: data.id,
+        attributes: data.attributes.clone(),
+        ..Default::default()
+    }
+}
+
+pub fn map_test_data_from_proto(message: &proto::MapTestData) -> MapTestData {
+    MapTestData { id: message.id, attributes: message.attributes.clone() }
+}
+
+// Borrows the attribute strings from `data`
+pub fn quick_proto_map_test_data(data: &MapTestData) -> quick_proto::MapTestData<'_> {
+    quick_proto::MapTestData {
+        id: data.id,
+        attributes: data.attributes.iter()
+            .map(|(key, value)| (Cow::Borrowed(key.as_str()), Cow::Borrowed(value.as_str())))
+            .collect(),
+    }
+}
+
+pub fn prost_map_test_data(data: &MapTestData) -> prost_proto::MapTestData {
+    prost_proto::MapTestData { id: data.id, attributes: data.attributes.clone() }
+}
+
+pub fn map_test_data_from_prost(message: &prost_proto::MapTestData) -> MapTestData {
+    MapTestData { id: message.id, attributes: message.attributes.clone() }
+}
+
+// nanoserde mirror of `MapTestData`
+#[derive(SerJson, DeJson, SerBin, DeBin, Debug, Clone, PartialEq)]
+pub struct NanoMapTestData {
+    pub id: u32,
+    pub attributes: HashMap<String, String>,
+}
+
+pub fn nano_map_test_data(data: &MapTestData) -> NanoMapTestData {
+    NanoMapTestData { id: data.id, attributes: data.attributes.clone() }
+}
+
+// Avro schema for `MapTestData`, with the attributes as a `map` of strings
+const AVRO_MAP_TEST_DATA_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "MapTestData",
+    "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "attributes", "type": {"type": "map", "values": "string"}}
+    ]
+}"#;
+
+pub fn avro_map_test_data_schema() -> apache_avro::Schema {
+    apache_avro::Schema::parse_str(AVRO_MAP_TEST_DATA_SCHEMA).unwrap()
+}
+
+pub fn avro_map_test_data_record(schema: &apache_avro::Schema, data: &MapTestData) -> apache_avro::types::Value {
+    let mut record = apache_avro::types::Record::new(schema).unwrap();
+    record.put("id", i64::from(data.id));
+    record.put("attributes", apache_avro::types::Value::Map(
+        data.attributes.iter()
+            .map(|(key, value)| (key.clone(), apache_avro::types::Value::String(value.clone())))
+            .collect(),
+    ));
+    record.into()
+}
+
+// An API-patch-style record: twenty optional fields, of which `sparse()` sets
+// two and `full()` all twenty, to show what each format spends on `None`
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, Default, PartialEq)]
+pub struct SparseData {
+    pub id: Option<u32>,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub age: Option<u32>,
+    pub score: Option<f64>,
+    pub active: Option<bool>,
+    pub created_at: Option<u64>,
+    pub updated_at: Option<u64>,
+    pub parent_id: Option<u64>,
+    pub country: Option<String>,
+    pub city: Option<String>,
+    pub zip: Option<u32>,
+    pub phone: Option<String>,
+    pub verified: Option<bool>,
+    pub rank: Option<i32>,
+    pub balance: Option<i64>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub retries: Option<u32>,
+    pub deleted: Option<bool>,
+}
+
+impl SparseData {
+    pub fn sparse() -> Self {
+        SparseData {
+            id: Some(1),
+            name: Some("Rust".to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn full() -> Self {
+        SparseData {
+            id: Some(1),
+            name: Some("Rust".to_string()),
+            email: Some("ferris@example.com".to_string()),
+            age: Some(9),
+            score: Some(97.5),
+            active: Some(true),
+            created_at: Some(1_700_000_000),
+            updated_at: Some(1_700_000_600),
+            parent_id: Some(42),
+            country: Some("NL".to_string()),
+            city: Some("Crabtown".to_string()),
+            zip: Some(12345),
+            phone: Some("+31 20 555 0100".to_string()),
+            verified: Some(true),
+            rank: Some(-3),
+            balance: Some(-1_250),
+            locale: Some("en-US".to_string()),
+            timezone: Some("Europe/Amsterdam".to_string()),
+            retries: Some(2),
+            deleted: Some(false),
+        }
+    }
+}
+
+// Field-for-field copy into the proto3 `optional` fields, which are left off
+// the wire when `None`
+pub fn proto_sparse(data: &SparseData) -> proto::SparseData {
+    proto::SparseData {
+        id: data.id,
+        name: data.name.clone(),
+        email: data.email.clone(),
+        age: data.age,
+        score: data.score,
+        active: data.active,
+        created_at: data.created_at,
+        updated_at: data.updated_at,
+        parent_id: data.parent_id,
+        country: data.country.clone(),
+        city: data.city.clone(),
+        zip: data.zip,
+        phone: data.phone.clone(),
+        verified: data.verified,
+        rank: data.rank,
+        balance: data.balance,
+        locale: data.locale.clone(),
+        timezone: data.timezone.clone(),
+        retries: data.retries,
+        deleted: data.deleted,
+        ..Default::default()
+    }
+}
+
+pub fn sparse_from_proto(message: &proto::SparseData) -> SparseData {
+    SparseData {
+        id: message.id,
+        name: message.name.clone(),
+        email: message.email.clone(),
+        age: message.age,
+        score: message.score,
+        active: message.active,
+        created_at: message.created_at,
+        updated_at: message.updated_at,
+        parent_id: message.parent_id,
+        country: message.country.clone(),
+        city: message.city.clone(),
+        zip: message.zip,
+        phone: message.phone.clone(),
+        verified: message.verified,
+        rank: message.rank,
+        balance: message.balance,
+        locale: message.locale.clone(),
+        timezone: message.timezone.clone(),
+        retries: message.retries,
+        deleted: message.deleted,
+    }
+}
+
+// Borrows every string from `data`, like `quick_proto_test_data`
+pub fn quick_proto_sparse(data: &SparseData) -> quick_proto::SparseData<'_> {
+    quick_proto::SparseData {
+        id: data.id,
+        name: data.name.as_deref().map(Cow::Borrowed),
+        email: data.email.as_deref().map(Cow::Borrowed),
+        age: data.age,
+        score: data.score,
+        active: data.active,
+        created_at: data.created_at,
+        updated_at: data.updated_at,
+        parent_id: data.parent_id,
+        country: data.country.as_deref().map(Cow::Borrowed),
+        city: data.city.as_deref().map(Cow::Borrowed),
+        zip: data.zip,
+        phone: data.phone.as_deref().map(Cow::Borrowed),
+        verified: data.verified,
+        rank: data.rank,
+        balance: data.balance,
+        locale: data.locale.as_deref().map(Cow::Borrowed),
+        timezone: data.timezone.as_deref().map(Cow::Borrowed),
+        retries: data.retries,
+        deleted: data.deleted,
+    }
+}
+
+// Points in `MetricsData::sample()`
+pub const METRICS_POINTS: usize = 1_000;
+
+// Points in `MetricsData::float_precision_sample()`
+pub const FLOAT_PRECISION_POINTS: usize = 256;
+
+// A metrics series: millisecond timestamps next to float samples, so numeric
+// encoding (and float formatting/parsing for the text formats) dominates
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct MetricsData {
+    pub timestamps: Vec<u64>,
+    pub values: Vec<f64>,
+}
+
+impl MetricsData {
+    // `METRICS_POINTS` points one second apart; the values need all 17
+    // significant digits, so any precision lost on the way shows up
+    pub fn sample() -> Self {
+        MetricsData {
+            timestamps: (0..METRICS_POINTS as u64)
+                .map(|index| 1_700_000_000_000 + index * 1_000)
+                .collect(),
+            values: (0..METRICS_POINTS)
+                .map(|index| (index as f64 * 0.01).sin() * 100.0 + 1.0 / 3.0)
+                .collect(),
+        }
+    }
+
+    // `FLOAT_PRECISION_POINTS` finite values that are hard to print and parse
+    // back exactly: negative zero, subnormals, the extremes, classic decimal
+    // traps, then 17-digit values across the exponent range, each a few ulps
+    // from its neighbour
+    pub fn float_precision_sample() -> Self {
+        let edge_cases = [
+            0.0, -0.0, f64::from_bits(1), f64::from_bits(0x000f_ffff_ffff_ffff), f64::MIN_POSITIVE,
+            f64::MAX, f64::MIN, f64::EPSILON, 0.1, 0.1 + 0.2, 1.0 / 3.0, 1e23, 9_007_199_254_740_993.0,
+        ];
+        let spread = (0..FLOAT_PRECISION_POINTS - edge_cases.len()).map(|index| {
+            let exponent = -300 + (index * 600 / (FLOAT_PRECISION_POINTS - edge_cases.len())) as i32;
+            let value = 1.234_567_890_123_456_7 * 10f64.powi(exponent);
+            let value = f64::from_bits(value.to_bits() + (index % 3) as u64);
+            if index % 2 == 0 { value } else { -value }
+        });
+        MetricsData {
+            timestamps: (0..FLOAT_PRECISION_POINTS as u64).collect(),
+            values: edge_cases.into_iter().chain(spread).collect(),
+        }
+    }
+}
+
+pub fn proto_metrics(metrics: &MetricsData) -> proto::MetricsData {
+    proto::MetricsData {
+        timestamps: metrics.timestamps.clone(),
+        values: metrics.values.clone(),
+        ..Default::default()
+    }
+}
+
+pub fn metrics_from_proto(message: &proto::MetricsData) -> MetricsData {
+    MetricsData {
+        timestamps: message.timestamps.clone(),
+        values: message.values.clone(),
+    }
+}
+
+pub fn quick_proto_metrics(metrics: &MetricsData) -> quick_proto::MetricsData {
+    quick_proto::MetricsData {
+        timestamps: metrics.timestamps.clone(),
+        values: metrics.values.clone(),
+    }
+}
+
+// About 1.7 KB of multilingual text, one script per field: accented Latin,
+// CJK, right-to-left Arabic and Hebrew (with a right-to-left mark), emoji
+// including ZWJ sequences and flags, and characters JSON has to escape
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct UnicodeData {
+    pub id: u32,
+    pub latin: String,
+    pub cjk: String,
+    pub rtl: String,
+    pub emoji: String,
+    pub escapes: String,
+}
+
+impl UnicodeData {
+    pub fn sample() -> Self {
+        UnicodeData {
+            id: 1,
+            latin: "Größenmaßstäbe, naïve café, Ærøskøbing, façade, jalapeño. ".repeat(5),
+            cjk: "数据序列化的性能测试。データのシリアライズ性能。데이터 직렬화 성능 테스트. ".repeat(3),
+            rtl: "تسلسل البيانات وسرعة التحويل. \u{200F}סריאליזציה של נתונים ומהירות. ".repeat(3),
+            emoji: "🦀🚀✨ 👩‍💻 👨‍👩‍👧‍👦 🇳🇱 🏳️‍🌈 ".repeat(4),
+            escapes: "\"quoted\" C:\\path\\to\\file\ttab\nnewline\r\u{1}\u{8}\u{c}\u{1f} </tag> & ".repeat(8),
+        }
+    }
+}
+
+pub fn proto_unicode(data: &UnicodeData) -> proto::UnicodeData {
+    proto::UnicodeData {
+        id: data.id,
+        latin: data.latin.clone(),
+        cjk: data.cjk.clone(),
+        rtl: data.rtl.clone(),
+        emoji: data.emoji.clone(),
+        escapes: data.escapes.clone(),
+        ..Default::default()
+    }
+}
+
+pub fn unicode_from_proto(message: &proto::UnicodeData) -> UnicodeData {
+    UnicodeData {
+        id: message.id,
+        latin: message.latin.clone(),
+        cjk: message.cjk.clone(),
+        rtl: message.rtl.clone(),
+        emoji: message.emoji.clone(),
+        escapes: message.escapes.clone(),
+    }
+}
+
+// Borrows every string from `data`, like `quick_proto_test_data`
+pub fn quick_proto_unicode(data: &UnicodeData) -> quick_proto::UnicodeData<'_> {
+    quick_proto::UnicodeData {
+        id: data.id,
+        latin: Cow::Borrowed(&data.latin),
+        cjk: Cow::Borrowed(&data.cjk),
+        rtl: Cow::Borrowed(&data.rtl),
+        emoji: Cow::Borrowed(&data.emoji),
+        escapes: Cow::Borrowed(&data.escapes),
+    }
+}
+
+// A user account as a typical web backend stores it
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct FixtureUser {
+    pub id: u32,
+    pub username: String,
+    pub email: String,
+    pub full_name: String,
+    pub age: u32,
+    pub country: String,
+    pub city: String,
+    pub created_at: u64,
+    pub last_login_at: u64,
+    pub active: bool,
+    // quick-xml writes an empty list as no elements at all, so it reads back as missing
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// The fixture file's top level; a table rather than a bare array, so TOML and
+// XML can encode it too
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct FixtureData {
+    // Defaulted for the same reason as `FixtureUser::tags`
+    #[serde(default)]
+    pub users: Vec<FixtureUser>,
+}
+
+impl FixtureData {
+    // Read a JSON fixture file shaped like `benches/fixtures/users.json`
+    pub fn load(path: &str) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+        serde_json::from_str(&json).map_err(|err| err.to_string())
+    }
+}
+
+// Minimum length of `BigStringData::sample()`'s text, in bytes
+pub const BIG_STRING_BYTES: usize = 1024 * 1024;
+
+// One large text field, as in log shipping or document storage, where the cost
+// is copying the bytes and, for the text formats, scanning them for escapes
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct BigStringData {
+    pub text: String,
+}
+
+impl BigStringData {
+    // Access-log lines joined by newlines until the text reaches
+    // `BIG_STRING_BYTES`. Mostly ASCII, with the quotes, backslashes, tabs and
+    // newlines JSON has to escape; the text ends without whitespace, since XML
+    // readers may trim it
+    pub fn sample() -> Self {
+        let mut lines = Vec::new();
+        let mut len = 0;
+        let mut index = 0usize;
+        while len < BIG_STRING_BYTES {
+            let line = format!(
+                "2024-05-{:02}T{:02}:{:02}:{:02}Z\tINFO\t10.0.{}.{} \"GET /api/v1/items/{}?page={} HTTP/1.1\" {} {} \"Mozilla/5.0 (X11; Linux x86_64)\" path=C:\\srv\\www\\items\\{}.json",
+                index % 28 + 1, index % 24, index % 60, (index * 7) % 60,
+                index % 256, (index * 13) % 256,
+                index, index % 10,
+                if index.is_multiple_of(17) { 404 } else { 200 }, 512 + (index * 31) % 4096,
+                index,
+            );
+            len += line.len() + 1;
+            lines.push(line);
+            index += 1;
+        }
+        BigStringData { text: lines.join("\n") }
+    }
+}
+
+// Events in `TimestampData::sample()`
+pub const TIMESTAMP_EVENTS: usize = 32;
+
+// A UTC instant with nanosecond precision, limited to what fits in i64
+// nanoseconds since the epoch (years 1677 to 2262). Human-readable serde
+// formats get chrono's RFC 3339 string, the others, borsh and speedy get the
+// i64 nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timestamp(DateTime<Utc>);
+
+impl Timestamp {
+    pub fn from_nanos(nanos: i64) -> Self {
+        Timestamp(DateTime::from_timestamp_nanos(nanos))
+    }
+
+    // Cannot fail: every constructor checks the range
+    pub fn nanos(&self) -> i64 {
+        self.0.timestamp_nanos_opt().expect("timestamp outside the i64 nanosecond range")
+    }
+
+    // Seconds and nanoseconds of the second, as in google.protobuf.Timestamp
+    pub fn to_parts(self) -> (i64, i32) {
+        (self.0.timestamp(), self.0.timestamp_subsec_nanos() as i32)
+    }
+
+    // Fails when `nanos` is not a fraction of a second or the instant is
+    // outside the i64 nanosecond range
+    pub fn from_parts(seconds: i64, nanos: i32) -> Result<Self, String> {
+        if !(0..1_000_000_000).contains(&nanos) {
+            return Err(format!("{} nanoseconds is not a fraction of a second", nanos));
+        }
+        seconds.checked_mul(1_000_000_000)
+            .and_then(|whole| whole.checked_add(nanos as i64))
+            .map(Timestamp::from_nanos)
+            .ok_or_else(|| format!("{}s {}ns is outside the i64 nanosecond range", seconds, nanos))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            Serialize::serialize(&self.0, serializer)
+        } else {
+            serializer.serialize_i64(self.nanos())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let datetime = <DateTime<Utc> as Deserialize>::deserialize(deserializer)?;
+            match datetime.timestamp_nanos_opt() {
+                Some(_) => Ok(Timestamp(datetime)),
+                None => Err(serde::de::Error::custom(format!("{} is outside the i64 nanosecond range", datetime))),
+            }
+        } else {
+            <i64 as Deserialize>::deserialize(deserializer).map(Timestamp::from_nanos)
+        }
+    }
+}
+
+impl BorshSerialize for Timestamp {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.nanos(), writer)
+    }
+}
+
+impl BorshDeserialize for Timestamp {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        i64::deserialize_reader(reader).map(Timestamp::from_nanos)
+    }
+}
+
+impl<'a, C: Context> Readable<'a, C> for Timestamp {
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_i64().map(Timestamp::from_nanos)
+    }
+
+    fn minimum_bytes_needed() -> usize {
+        8
+    }
+}
+
+impl<C: Context> Writable<C> for Timestamp {
+    fn write_to<T: ?Sized + Writer<C>>(&self, writer: &mut T) -> Result<(), C::Error> {
+        writer.write_i64(self.nanos())
+    }
+
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(8)
+    }
+}
+
+// A record with creation and update times and a list of event times, where
+// the cost is formatting and parsing RFC 3339 strings in the text formats.
+// `updated_at` is unset until the record is first modified
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct TimestampData {
+    pub id: u32,
+    pub created_at: Timestamp,
+    pub updated_at: Option<Timestamp>,
+    pub events: Vec<Timestamp>,
+}
+
+impl TimestampData {
+    // Instants from 2024-05-01T00:00:00.123456789Z on, at irregular offsets,
+    // so every timestamp needs all nine fractional digits
+    pub fn sample() -> Self {
+        const START: i64 = 1_714_521_600_123_456_789;
+        TimestampData {
+            id: 1,
+            created_at: Timestamp::from_nanos(START),
+            updated_at: Some(Timestamp::from_nanos(START + 86_400_000_000_007)),
+            events: (0..TIMESTAMP_EVENTS as i64)
+                .map(|index| Timestamp::from_nanos(START + index * 3_600_987_654_321 + index * index * 1_013))
+                .collect(),
+        }
+    }
+}
+
+pub fn proto_timestamp_data(data: &TimestampData) -> proto::TimestampData {
+    let timestamp = |value: &Timestamp| {
+        let (seconds, nanos) = value.to_parts();
+        protobuf::well_known_types::timestamp::Timestamp { seconds, nanos, ..Default::default() }
+    };
+    proto::TimestampData {
+        id: data.id,
+        created_at: Some(timestamp(&data.created_at)).into(),
+        updated_at: data.updated_at.as_ref().map(timestamp).into(),
+        events: data.events.iter().map(timestamp).collect(),
+        ..Default::default()
+    }
+}
+
+// Fails on any timestamp `Timestamp::from_parts` rejects
+pub fn timestamp_data_from_proto(message: &proto::TimestampData) -> Result<TimestampData, String> {
+    let timestamp = |value: &protobuf::well_known_types::timestamp::Timestamp| Timestamp::from_parts(value.seconds, value.nanos);
+    Ok(TimestampData {
+        id: message.id,
+        created_at: timestamp(&message.created_at)?,
+        updated_at: message.updated_at.as_ref().map(timestamp).transpose()?,
+        events: message.events.iter().map(timestamp).collect::<Result<_, _>>()?,
+    })
+}
+
+pub fn quick_proto_timestamp_data(data: &TimestampData) -> quick_proto::TimestampData {
+    let timestamp = |value: &Timestamp| {
+        let (seconds, nanos) = value.to_parts();
+        google::protobuf::Timestamp { seconds, nanos }
+    };
+    quick_proto::TimestampData {
+        id: data.id,
+        created_at: Some(timestamp(&data.created_at)),
+        updated_at: data.updated_at.as_ref().map(timestamp),
+        events: data.events.iter().map(timestamp).collect(),
+    }
+}
+
+pub fn prost_timestamp_data(data: &TimestampData) -> prost_proto::TimestampData {
+    let timestamp = |value: &Timestamp| {
+        let (seconds, nanos) = value.to_parts();
+        prost_types::Timestamp { seconds, nanos }
+    };
+    prost_proto::TimestampData {
+        id: data.id,
+        created_at: Some(timestamp(&data.created_at)),
+        updated_at: data.updated_at.as_ref().map(timestamp),
+        events: data.events.iter().map(timestamp).collect(),
+    }
+}
+
+pub fn timestamp_data_from_prost(message: &prost_proto::TimestampData) -> Result<TimestampData, String> {
+    let timestamp = |value: &prost_types::Timestamp| Timestamp::from_parts(value.seconds, value.nanos);
+    Ok(TimestampData {
+        id: message.id,
+        created_at: message.created_at.as_ref().map_or(Ok(Timestamp::from_nanos(0)), timestamp)?,
+        updated_at: message.updated_at.as_ref().map(timestamp).transpose()?,
+        events: message.events.iter().map(timestamp).collect::<Result<_, _>>()?,
+    })
+}
+
+// A 128-bit token amount and a signed change to it, both well past 2^64 so a
+// format that truncates to 64 bits fails the roundtrip check
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct WideInts {
+    #[serde(with = "readable_decimal")]
+    pub amount: u128,
+    #[serde(with = "readable_decimal")]
+    pub delta: i128,
+}
+
+// Serde `with` module for 128-bit integers: a decimal string in human-readable
+// formats, since most JSON parsers outside Rust read numbers as f64 and lose
+// everything past 2^53, and the native integer in the others
+mod readable_decimal {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub fn serialize<T: Display + Serialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(value)
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr + Deserialize<'de>,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+}
+
+impl WideInts {
+    pub fn sample() -> Self {
+        WideInts {
+            amount: 123_456_789_012_345_678_901_234_567_890,
+            delta: -98_765_432_109_876_543_210_987,
+        }
+    }
+
+    // Parse the decimal strings the protobuf messages carry
+    pub fn from_decimal(amount: &str, delta: &str) -> Result<Self, std::num::ParseIntError> {
+        Ok(WideInts { amount: amount.parse()?, delta: delta.parse()? })
+    }
+}
+
+pub fn proto_wide_ints(data: &WideInts) -> proto::WideInts {
+    proto::WideInts {
+        amount: data.amount.to_string(),
+        delta: data.delta.to_string(),
+        ..Default::default()
+    }
+}
+
+pub fn quick_proto_wide_ints(data: &WideInts) -> quick_proto::WideInts<'static> {
+    quick_proto::WideInts {
+        amount: Cow::Owned(data.amount.to_string()),
+        delta: Cow::Owned(data.delta.to_string()),
+    }
+}
+
+pub fn prost_wide_ints(data: &WideInts) -> prost_proto::WideInts {
+    prost_proto::WideInts {
+        amount: data.amount.to_string(),
+        delta: data.delta.to_string(),
+    }
+}
+
+// Levels in `DeepNested::sample()`
+pub const DEEP_NESTING_DEPTH: u32 = 20;
+
+// A chain of structs each holding the next, so the per-level cost of entering
+// a struct (recursive calls, length prefixes, braces and indentation) adds up.
+// Borsh and BCS need no wrapper for the `Box`: borsh implements its traits for
+// `Box<T>` and BCS goes through serde's. BCS does reject anything nested more
+// than 500 containers deep
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct DeepNested {
+    pub level: u32,
+    pub child: Option<Box<DeepNested>>,
+}
+
+impl DeepNested {
+    // Levels 1 (outermost) to `DEEP_NESTING_DEPTH`
+    pub fn sample() -> Self {
+        (1..DEEP_NESTING_DEPTH).rev().fold(
+            DeepNested { level: DEEP_NESTING_DEPTH, child: None },
+            |child, level| DeepNested { level, child: Some(Box::new(child)) },
+        )
+    }
+}
+
+pub fn proto_deep(deep: &DeepNested) -> proto::DeepNested {
+    proto::DeepNested {
+        level: deep.level,
+        child: deep.child.as_deref().map(proto_deep).into(),
+        ..Default::default()
+    }
+}
+
+pub fn deep_from_proto(message: &proto::DeepNested) -> DeepNested {
+    DeepNested {
+        level: message.level,
+        child: message.child.as_ref().map(|child| Box::new(deep_from_proto(child))),
+    }
+}
+
+pub fn quick_proto_deep(deep: &DeepNested) -> quick_proto::DeepNested {
+    quick_proto::DeepNested {
+        level: deep.level,
+        child: deep.child.as_deref().map(|child| Box::new(quick_proto_deep(child))),
+    }
+}
+
+pub fn prost_deep(deep: &DeepNested) -> prost_proto::DeepNested {
+    prost_proto::DeepNested {
+        level: deep.level,
+        child: deep.child.as_deref().map(|child| Box::new(prost_deep(child))),
+    }
+}
+
+pub fn deep_from_prost(message: &prost_proto::DeepNested) -> DeepNested {
+    DeepNested {
+        level: message.level,
+        child: message.child.as_deref().map(|child| Box::new(deep_from_prost(child))),
+    }
+}
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct UserId(pub u64);
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct Email(pub String);
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct Version(pub u32);
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct Rgba(pub u8, pub u8, pub u8, pub u8);
+
+// A record built from newtypes and a 4-element tuple struct, the way typed IDs
+// and small value types usually look in application code
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct NewtypeData {
+    pub id: UserId,
+    pub email: Email,
+    pub version: Version,
+    pub color: Rgba,
+}
+
+// `NewtypeData` with the wrappers stripped: the same values as bare fields and
+// an array, so the difference in encoded size is what the wrappers cost
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct PlainNewtypeData {
+    pub id: u64,
+    pub email: String,
+    pub version: u32,
+    pub color: [u8; 4],
+}
+
+impl NewtypeData {
+    pub fn sample() -> Self {
+        NewtypeData {
+            id: UserId(4_200_000_017),
+            email: Email("ferris@example.com".to_string()),
+            version: Version(3),
+            color: Rgba(222, 165, 132, 255),
+        }
+    }
+
+    pub fn unwrapped(&self) -> PlainNewtypeData {
+        let Rgba(r, g, b, a) = self.color;
+        PlainNewtypeData {
+            id: self.id.0,
+            email: self.email.0.clone(),
+            version: self.version.0,
+            color: [r, g, b, a],
+        }
+    }
+}
+
+pub fn proto_newtype(data: &NewtypeData) -> proto::NewtypeData {
+    let Rgba(r, g, b, a) = data.color;
+    proto::NewtypeData {
+        id: data.id.0,
+        email: data.email.0.clone(),
+        version: data.version.0,
+        color: Some(proto::Rgba { r: r.into(), g: g.into(), b: b.into(), a: a.into(), ..Default::default() }).into(),
+        ..Default::default()
+    }
+}
+
+// Fails on a color channel past 255, which the `uint32` fields can carry
+pub fn newtype_from_proto(message: &proto::NewtypeData) -> Option<NewtypeData> {
+    let color = message.color.as_ref()?;
+    Some(NewtypeData {
+        id: UserId(message.id),
+        email: Email(message.email.clone()),
+        version: Version(message.version),
+        color: Rgba(color.r.try_into().ok()?, color.g.try_into().ok()?, color.b.try_into().ok()?, color.a.try_into().ok()?),
+    })
+}
+
+// Borrows the email from `data`, like `quick_proto_test_data`
+pub fn quick_proto_newtype(data: &NewtypeData) -> quick_proto::NewtypeData<'_> {
+    let Rgba(r, g, b, a) = data.color;
+    quick_proto::NewtypeData {
+        id: data.id.0,
+        email: Cow::Borrowed(&data.email.0),
+        version: data.version.0,
+        color: Some(quick_proto::Rgba { r: r.into(), g: g.into(), b: b.into(), a: a.into() }),
+    }
+}
+
+pub fn prost_newtype(data: &NewtypeData) -> prost_proto::NewtypeData {
+    let Rgba(r, g, b, a) = data.color;
+    prost_proto::NewtypeData {
+        id: data.id.0,
+        email: data.email.0.clone(),
+        version: data.version.0,
+        color: Some(prost_proto::Rgba { r: r.into(), g: g.into(), b: b.into(), a: a.into() }),
+    }
+}
+
+pub fn newtype_from_prost(message: &prost_proto::NewtypeData) -> Option<NewtypeData> {
+    let color = message.color.as_ref()?;
+    Some(NewtypeData {
+        id: UserId(message.id),
+        email: Email(message.email.clone()),
+        version: Version(message.version),
+        color: Rgba(color.r.try_into().ok()?, color.g.try_into().ok()?, color.b.try_into().ok()?, color.a.try_into().ok()?),
+    })
+}
+
+// An API-style record keyed by UUIDs. uuid's serde impl writes the hyphenated
+// 36-character string in human-readable formats and the 16 bytes through
+// `serialize_bytes` in the others; borsh and speedy write the 16 bytes as is
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct UuidTestData {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+}
+
+impl UuidTestData {
+    pub fn sample() -> Self {
+        UuidTestData {
+            id: Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8),
+            parent_id: Some(Uuid::from_u128(0x936d_a01f_9abd_4d9d_80c7_02af_85c8_22a8)),
+            name: "Rust".to_string(),
+        }
+    }
+}
+
+pub fn proto_uuid_data(data: &UuidTestData) -> proto::UuidTestData {
+    proto::UuidTestData {
+        id: data.id.as_bytes().to_vec(),
+        parent_id: data.parent_id.map_or_else(Vec::new, |parent| parent.as_bytes().to_vec()),
+        name: data.name.clone(),
+        ..Default::default()
+    }
+}
+
+// Fails unless each id is exactly 16 bytes; an empty `parent_id` is `None`
+pub fn uuid_data_from_proto(message: &proto::UuidTestData) -> Result<UuidTestData, uuid::Error> {
+    Ok(UuidTestData {
+        id: Uuid::from_slice(&message.id)?,
+        parent_id: if message.parent_id.is_empty() { None } else { Some(Uuid::from_slice(&message.parent_id)?) },
+        name: message.name.clone(),
+    })
+}
+
+// Borrows the ids and the name from `data`, like `quick_proto_test_data`
+pub fn quick_proto_uuid_data(data: &UuidTestData) -> quick_proto::UuidTestData<'_> {
+    quick_proto::UuidTestData {
+        id: Cow::Borrowed(data.id.as_bytes()),
+        parent_id: data.parent_id.as_ref().map_or(Cow::Borrowed(&[][..]), |parent| Cow::Borrowed(parent.as_bytes())),
+        name: Cow::Borrowed(&data.name),
+    }
+}
+
+pub fn prost_uuid_data(data: &UuidTestData) -> prost_proto::UuidTestData {
+    prost_proto::UuidTestData {
+        id: data.id.as_bytes().to_vec(),
+        parent_id: data.parent_id.map_or_else(Vec::new, |parent| parent.as_bytes().to_vec()),
+        name: data.name.clone(),
+    }
+}
+
+pub fn uuid_data_from_prost(message: &prost_proto::UuidTestData) -> Result<UuidTestData, uuid::Error> {
+    Ok(UuidTestData {
+        id: Uuid::from_slice(&message.id)?,
+        parent_id: if message.parent_id.is_empty() { None } else { Some(Uuid::from_slice(&message.parent_id)?) },
+        name: message.name.clone(),
+    })
+}
+
+// Commands in `EnumTestData::sample()`
+pub const ENUM_COMMANDS: usize = 32;
+
+// A status with unit, tuple and struct variants, which the binary formats
+// write as a discriminant followed by the fields and the text formats as the
+// variant name, wrapping the fields of the data-carrying ones. Protobuf maps it
+// onto the `Status.kind` oneof, the unit variants becoming empty messages
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub enum Status {
+    Pending,
+    Active(u64),
+    Suspended(u32, String),
+    Closed { reason: String, at: u64 },
+    Archived,
+}
+
+// Unit-variant enums, which the binary formats write as a bare discriminant,
+// the text formats as the variant name and protobuf as an `enum` varint. The
+// protobuf values follow the declaration order
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, Copy, PartialEq)]
+pub enum Command {
+    Start,
+    Stop,
+    Pause,
+    Resume,
+    Restart,
+}
+
+impl Status {
+    // One status of each variant
+    pub fn variants() -> Vec<Status> {
+        vec![
+            Status::Pending,
+            Status::Active(1_700_000_000),
+            Status::Suspended(30, "payment overdue".to_string()),
+            Status::Closed { reason: "account migrated".to_string(), at: 1_700_086_400 },
+            Status::Archived,
+        ]
+    }
+}
+
+impl Command {
+    const ALL: [Command; 5] = [Command::Start, Command::Stop, Command::Pause, Command::Resume, Command::Restart];
+
+    fn from_proto_value(value: i32) -> Option<Self> {
+        usize::try_from(value).ok().and_then(|index| Self::ALL.get(index).copied())
+    }
+}
+
+// A record with a data-carrying status and a list of unit-variant commands
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, Debug, Clone, PartialEq)]
+pub struct EnumTestData {
+    pub id: u32,
+    pub status: Status,
+    pub commands: Vec<Command>,
+}
+
+impl EnumTestData {
+    // Every command in turn, with the struct-variant status, the one whose
+    // encoding differs most between formats
+    pub fn sample() -> Self {
+        EnumTestData {
+            id: 1,
+            status: Status::Closed { reason: "account migrated".to_string(), at: 1_700_086_400 },
+            commands: (0..ENUM_COMMANDS).map(|index| Command::ALL[index % Command::ALL.len()]).collect(),
+        }
+    }
+}
+
+pub fn proto_enum_data(data: &EnumTestData) -> proto::EnumTestData {
+    let mut status = proto::Status::new();
+    match &data.status {
+        Status::Pending => status.set_pending(proto::Pending::new()),
+        Status::Active(since) => status.set_active(*since),
+        Status::Suspended(days, note) => {
+            let mut suspended = proto::Suspended::new();
+            suspended.days = *days;
+            suspended.note = note.clone();
+            status.set_suspended(suspended);
+        }
+        Status::Closed { reason, at } => {
+            let mut closed = proto::Closed::new();
+            closed.reason = reason.clone();
+            closed.at = *at;
+            status.set_closed(closed);
+        }
+        Status::Archived => status.set_archived(proto::Archived::new()),
+    }
+    proto::EnumTestData {
+        id: data.id,
+        status: protobuf::MessageField::some(status),
+        commands: data.commands.iter().map(|&command| protobuf::EnumOrUnknown::from_i32(command as i32)).collect(),
+        ..Default::default()
+    }
+}
+
+// Fails on a missing status or a command past the last variant, which protobuf
+// keeps as unknown
+pub fn enum_data_from_proto(message: &proto::EnumTestData) -> Option<EnumTestData> {
+    let status = match message.status.as_ref()?.kind.as_ref()? {
+        proto::status::Kind::Pending(_) => Status::Pending,
+        proto::status::Kind::Active(since) => Status::Active(*since),
+        proto::status::Kind::Suspended(suspended) => Status::Suspended(suspended.days, suspended.note.clone()),
+        proto::status::Kind::Closed(closed) => Status::Closed { reason: closed.reason.clone(), at: closed.at },
+        proto::status::Kind::Archived(_) => Status::Archived,
+    };
+    Some(EnumTestData {
+        id: message.id,
+        status,
+        commands: message.commands.iter().map(|command| Command::from_proto_value(command.value())).collect::<Option<_>>()?,
+    })
+}
+
+// Borrows the status strings from `data`
+pub fn quick_proto_enum_data(data: &EnumTestData) -> quick_proto::EnumTestData<'_> {
+    quick_proto::EnumTestData {
+        id: data.id,
+        status: Some(quick_proto::Status {
+            kind: match &data.status {
+                Status::Pending => quick_proto::mod_Status::OneOfkind::pending(quick_proto::Pending {}),
+                Status::Active(since) => quick_proto::mod_Status::OneOfkind::active(*since),
+                Status::Suspended(days, note) => quick_proto::mod_Status::OneOfkind::suspended(quick_proto::Suspended { days: *days, note: Cow::Borrowed(note) }),
+                Status::Closed { reason, at } => quick_proto::mod_Status::OneOfkind::closed(quick_proto::Closed { reason: Cow::Borrowed(reason), at: *at }),
+                Status::Archived => quick_proto::mod_Status::OneOfkind::archived(quick_proto::Archived {}),
+            },
+        }),
+        commands: data.commands.iter().map(|&command| quick_proto::Command::from(command as i32)).collect(),
+    }
+}
+
+pub fn prost_enum_data(data: &EnumTestData) -> prost_proto::EnumTestData {
+    let kind = match &data.status {
+        Status::Pending => prost_proto::status::Kind::Pending(prost_proto::Pending {}),
+        Status::Active(since) => prost_proto::status::Kind::Active(*since),
+        Status::Suspended(days, note) => prost_proto::status::Kind::Suspended(prost_proto::Suspended { days: *days, note: note.clone() }),
+        Status::Closed { reason, at } => prost_proto::status::Kind::Closed(prost_proto::Closed { reason: reason.clone(), at: *at }),
+        Status::Archived => prost_proto::status::Kind::Archived(prost_proto::Archived {}),
+    };
+    prost_proto::EnumTestData {
+        id: data.id,
+        status: Some(prost_proto::Status { kind: Some(kind) }),
+        commands: data.commands.iter().map(|&command| command as i32).collect(),
+    }
+}
+
+pub fn enum_data_from_prost(message: &prost_proto::EnumTestData) -> Option<EnumTestData> {
+    let status = match message.status.as_ref()?.kind.as_ref()? {
+        prost_proto::status::Kind::Pending(_) => Status::Pending,
+        prost_proto::status::Kind::Active(since) => Status::Active(*since),
+        prost_proto::status::Kind::Suspended(suspended) => Status::Suspended(suspended.days, suspended.note.clone()),
+        prost_proto::status::Kind::Closed(closed) => Status::Closed { reason: closed.reason.clone(), at: closed.at },
+        prost_proto::status::Kind::Archived(_) => Status::Archived,
+    };
+    Some(EnumTestData {
+        id: message.id,
+        status,
+        commands: message.commands.iter().map(|&command| Command::from_proto_value(command)).collect::<Option<_>>()?,
+    })
+}
@@ -0,0 +1,316 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TestData {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub active: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Login {
+    #[prost(string, tag = "1")]
+    pub user: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Logout {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Event {
+    #[prost(oneof = "event::Kind", tags = "1, 2, 3")]
+    pub kind: ::core::option::Option<event::Kind>,
+}
+/// Nested message and enum types in `Event`.
+pub mod event {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Login(super::Login),
+        #[prost(message, tag = "2")]
+        Logout(super::Logout),
+        #[prost(uint64, tag = "3")]
+        Heartbeat(u64),
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EventLog {
+    #[prost(message, repeated, tag = "1")]
+    pub events: ::prost::alloc::vec::Vec<Event>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TestDataBatch {
+    #[prost(message, repeated, tag = "1")]
+    pub items: ::prost::alloc::vec::Vec<TestData>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Address {
+    #[prost(string, tag = "1")]
+    pub street: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub city: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub zip: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Tag {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub weight: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NestedTestData {
+    #[prost(message, optional, tag = "1")]
+    pub data: ::core::option::Option<TestData>,
+    #[prost(message, optional, tag = "2")]
+    pub address: ::core::option::Option<Address>,
+    #[prost(message, repeated, tag = "3")]
+    pub tags: ::prost::alloc::vec::Vec<Tag>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BlobData {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub payload: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapData {
+    #[prost(map = "string, string", tag = "1")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(map = "uint64, double", tag = "2")]
+    pub readings: ::std::collections::HashMap<u64, f64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SparseData {
+    #[prost(uint32, optional, tag = "1")]
+    pub id: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub email: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "4")]
+    pub age: ::core::option::Option<u32>,
+    #[prost(double, optional, tag = "5")]
+    pub score: ::core::option::Option<f64>,
+    #[prost(bool, optional, tag = "6")]
+    pub active: ::core::option::Option<bool>,
+    #[prost(uint64, optional, tag = "7")]
+    pub created_at: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "8")]
+    pub updated_at: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "9")]
+    pub parent_id: ::core::option::Option<u64>,
+    #[prost(string, optional, tag = "10")]
+    pub country: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "11")]
+    pub city: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "12")]
+    pub zip: ::core::option::Option<u32>,
+    #[prost(string, optional, tag = "13")]
+    pub phone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag = "14")]
+    pub verified: ::core::option::Option<bool>,
+    #[prost(int32, optional, tag = "15")]
+    pub rank: ::core::option::Option<i32>,
+    #[prost(int64, optional, tag = "16")]
+    pub balance: ::core::option::Option<i64>,
+    #[prost(string, optional, tag = "17")]
+    pub locale: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "18")]
+    pub timezone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "19")]
+    pub retries: ::core::option::Option<u32>,
+    #[prost(bool, optional, tag = "20")]
+    pub deleted: ::core::option::Option<bool>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetricsData {
+    #[prost(uint64, repeated, tag = "1")]
+    pub timestamps: ::prost::alloc::vec::Vec<u64>,
+    #[prost(double, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<f64>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UnicodeData {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(string, tag = "2")]
+    pub latin: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub cjk: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub rtl: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub emoji: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub escapes: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BigString {
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TimestampData {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// Unset until the record is first modified
+    #[prost(message, optional, tag = "3")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, repeated, tag = "4")]
+    pub events: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+}
+/// Protobuf has no 128-bit integers, so both are decimal strings
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WideInts {
+    #[prost(string, tag = "1")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub delta: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeepNested {
+    #[prost(uint32, tag = "1")]
+    pub level: u32,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub child: ::core::option::Option<::prost::alloc::boxed::Box<DeepNested>>,
+}
+/// Protobuf has no newtypes: `UserId`, `Email` and `Version` are plain fields,
+/// and the `Rgba` tuple struct becomes a message with one field per element
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Rgba {
+    #[prost(uint32, tag = "1")]
+    pub r: u32,
+    #[prost(uint32, tag = "2")]
+    pub g: u32,
+    #[prost(uint32, tag = "3")]
+    pub b: u32,
+    #[prost(uint32, tag = "4")]
+    pub a: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct NewtypeData {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(string, tag = "2")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub version: u32,
+    #[prost(message, optional, tag = "4")]
+    pub color: ::core::option::Option<Rgba>,
+}
+/// UUIDs as their 16 raw bytes; an empty `parent_id` means no parent
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UuidTestData {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub parent_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub name: ::prost::alloc::string::String,
+}
+/// `Status` variants map onto the `Status.kind` oneof: `Suspended` and `Closed`
+/// carry their fields as messages, `active` is a bare uint64, and the unit
+/// variants are empty messages, like `Logout`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Pending {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Suspended {
+    #[prost(uint32, tag = "1")]
+    pub days: u32,
+    #[prost(string, tag = "2")]
+    pub note: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Closed {
+    #[prost(string, tag = "1")]
+    pub reason: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub at: u64,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Archived {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Status {
+    #[prost(oneof = "status::Kind", tags = "1, 2, 3, 4, 5")]
+    pub kind: ::core::option::Option<status::Kind>,
+}
+/// Nested message and enum types in `Status`.
+pub mod status {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "1")]
+        Pending(super::Pending),
+        #[prost(uint64, tag = "2")]
+        Active(u64),
+        #[prost(message, tag = "3")]
+        Suspended(super::Suspended),
+        #[prost(message, tag = "4")]
+        Closed(super::Closed),
+        #[prost(message, tag = "5")]
+        Archived(super::Archived),
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct EnumTestData {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(message, optional, tag = "2")]
+    pub status: ::core::option::Option<Status>,
+    #[prost(enumeration = "Command", repeated, tag = "3")]
+    pub commands: ::prost::alloc::vec::Vec<i32>,
+}
+/// Each entry is written as a length-delimited key/value sub-message
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MapTestData {
+    #[prost(uint32, tag = "1")]
+    pub id: u32,
+    #[prost(map = "string, string", tag = "2")]
+    pub attributes: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+/// A unit-variant enum: one varint per value on the wire, with the zero value
+/// as the default that proto3 leaves out
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Command {
+    Start = 0,
+    Stop = 1,
+    Pause = 2,
+    Resume = 3,
+    Restart = 4,
+}
+impl Command {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Start => "COMMAND_START",
+            Self::Stop => "COMMAND_STOP",
+            Self::Pause => "COMMAND_PAUSE",
+            Self::Resume => "COMMAND_RESUME",
+            Self::Restart => "COMMAND_RESTART",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COMMAND_START" => Some(Self::Start),
+            "COMMAND_STOP" => Some(Self::Stop),
+            "COMMAND_PAUSE" => Some(Self::Pause),
+            "COMMAND_RESUME" => Some(Self::Resume),
+            "COMMAND_RESTART" => Some(Self::Restart),
+            _ => None,
+        }
+    }
+}
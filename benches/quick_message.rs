@@ -0,0 +1,1181 @@
+// Automatically generated rust module for 'message.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+type KVMap<K, V> = HashMap<K, V>;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+use super::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Command {
+    COMMAND_START = 0,
+    COMMAND_STOP = 1,
+    COMMAND_PAUSE = 2,
+    COMMAND_RESUME = 3,
+    COMMAND_RESTART = 4,
+}
+
+impl Default for Command {
+    fn default() -> Self {
+        Command::COMMAND_START
+    }
+}
+
+impl From<i32> for Command {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => Command::COMMAND_START,
+            1 => Command::COMMAND_STOP,
+            2 => Command::COMMAND_PAUSE,
+            3 => Command::COMMAND_RESUME,
+            4 => Command::COMMAND_RESTART,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Command {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "COMMAND_START" => Command::COMMAND_START,
+            "COMMAND_STOP" => Command::COMMAND_STOP,
+            "COMMAND_PAUSE" => Command::COMMAND_PAUSE,
+            "COMMAND_RESUME" => Command::COMMAND_RESUME,
+            "COMMAND_RESTART" => Command::COMMAND_RESTART,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TestData<'a> {
+    pub id: u32,
+    pub name: Cow<'a, str>,
+    pub active: bool,
+}
+
+impl<'a> MessageRead<'a> for TestData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint32(bytes)?,
+                Ok(18) => msg.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(24) => msg.active = r.read_bool(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for TestData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + if self.name == "" { 0 } else { 1 + sizeof_len((&self.name).len()) }
+        + if self.active == false { 0 } else { 1 + sizeof_varint(*(&self.active) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.id))?; }
+        if self.name != "" { w.write_with_tag(18, |w| w.write_string(&**&self.name))?; }
+        if self.active != false { w.write_with_tag(24, |w| w.write_bool(*&self.active))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Login<'a> {
+    pub user: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for Login<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.user = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Login<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.user == "" { 0 } else { 1 + sizeof_len((&self.user).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.user != "" { w.write_with_tag(10, |w| w.write_string(&**&self.user))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Logout { }
+
+impl<'a> MessageRead<'a> for Logout {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+}
+
+impl MessageWrite for Logout { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Event<'a> {
+    pub kind: mod_Event::OneOfkind<'a>,
+}
+
+impl<'a> MessageRead<'a> for Event<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.kind = mod_Event::OneOfkind::login(r.read_message::<Login>(bytes)?),
+                Ok(18) => msg.kind = mod_Event::OneOfkind::logout(r.read_message::<Logout>(bytes)?),
+                Ok(24) => msg.kind = mod_Event::OneOfkind::heartbeat(r.read_uint64(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Event<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + match self.kind {
+            mod_Event::OneOfkind::login(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_Event::OneOfkind::logout(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_Event::OneOfkind::heartbeat(ref m) => 1 + sizeof_varint(*(m) as u64),
+            mod_Event::OneOfkind::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        match self.kind {            mod_Event::OneOfkind::login(ref m) => { w.write_with_tag(10, |w| w.write_message(m))? },
+            mod_Event::OneOfkind::logout(ref m) => { w.write_with_tag(18, |w| w.write_message(m))? },
+            mod_Event::OneOfkind::heartbeat(ref m) => { w.write_with_tag(24, |w| w.write_uint64(*m))? },
+            mod_Event::OneOfkind::None => {},
+    }        Ok(())
+    }
+}
+
+pub mod mod_Event {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfkind<'a> {
+    login(Login<'a>),
+    logout(Logout),
+    heartbeat(u64),
+    None,
+}
+
+impl<'a> Default for OneOfkind<'a> {
+    fn default() -> Self {
+        OneOfkind::None
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EventLog<'a> {
+    pub events: Vec<Event<'a>>,
+}
+
+impl<'a> MessageRead<'a> for EventLog<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.events.push(r.read_message::<Event>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for EventLog<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.events.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.events { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TestDataBatch<'a> {
+    pub items: Vec<TestData<'a>>,
+}
+
+impl<'a> MessageRead<'a> for TestDataBatch<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.items.push(r.read_message::<TestData>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for TestDataBatch<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.items.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.items { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Address<'a> {
+    pub street: Cow<'a, str>,
+    pub city: Cow<'a, str>,
+    pub zip: u32,
+}
+
+impl<'a> MessageRead<'a> for Address<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.street = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => msg.city = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(24) => msg.zip = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Address<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.street == "" { 0 } else { 1 + sizeof_len((&self.street).len()) }
+        + if self.city == "" { 0 } else { 1 + sizeof_len((&self.city).len()) }
+        + if self.zip == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.zip) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.street != "" { w.write_with_tag(10, |w| w.write_string(&**&self.street))?; }
+        if self.city != "" { w.write_with_tag(18, |w| w.write_string(&**&self.city))?; }
+        if self.zip != 0u32 { w.write_with_tag(24, |w| w.write_uint32(*&self.zip))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Tag<'a> {
+    pub name: Cow<'a, str>,
+    pub weight: u32,
+}
+
+impl<'a> MessageRead<'a> for Tag<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => msg.weight = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Tag<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.name == "" { 0 } else { 1 + sizeof_len((&self.name).len()) }
+        + if self.weight == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.weight) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.name != "" { w.write_with_tag(10, |w| w.write_string(&**&self.name))?; }
+        if self.weight != 0u32 { w.write_with_tag(16, |w| w.write_uint32(*&self.weight))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NestedTestData<'a> {
+    pub data: Option<TestData<'a>>,
+    pub address: Option<Address<'a>>,
+    pub tags: Vec<Tag<'a>>,
+}
+
+impl<'a> MessageRead<'a> for NestedTestData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.data = Some(r.read_message::<TestData>(bytes)?),
+                Ok(18) => msg.address = Some(r.read_message::<Address>(bytes)?),
+                Ok(26) => msg.tags.push(r.read_message::<Tag>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for NestedTestData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.data.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.address.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.tags.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.data { w.write_with_tag(10, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.address { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.tags { w.write_with_tag(26, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BlobData<'a> {
+    pub id: u32,
+    pub payload: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for BlobData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint32(bytes)?,
+                Ok(18) => msg.payload = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for BlobData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + if self.payload == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.payload).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.id))?; }
+        if self.payload != Cow::Borrowed(b"") { w.write_with_tag(18, |w| w.write_bytes(&**&self.payload))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MapData<'a> {
+    pub metadata: KVMap<Cow<'a, str>, Cow<'a, str>>,
+    pub readings: KVMap<u64, f64>,
+}
+
+impl<'a> MessageRead<'a> for MapData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => {
+                    let (key, value) = r.read_map(bytes, |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?), |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?))?;
+                    msg.metadata.insert(key, value);
+                }
+                Ok(18) => {
+                    let (key, value) = r.read_map(bytes, |r, bytes| Ok(r.read_uint64(bytes)?), |r, bytes| Ok(r.read_double(bytes)?))?;
+                    msg.readings.insert(key, value);
+                }
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for MapData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.metadata.iter().map(|(k, v)| 1 + sizeof_len(2 + sizeof_len((k).len()) + sizeof_len((v).len()))).sum::<usize>()
+        + self.readings.iter().map(|(k, _)| 1 + sizeof_len(1 + sizeof_varint(*(k) as u64) + 1 + 8)).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for (k, v) in self.metadata.iter() { w.write_with_tag(10, |w| w.write_map(2 + sizeof_len((k).len()) + sizeof_len((v).len()), 10, |w| w.write_string(&**k), 18, |w| w.write_string(&**v)))?; }
+        for (k, v) in self.readings.iter() { w.write_with_tag(18, |w| w.write_map(1 + sizeof_varint(*(k) as u64) + 1 + 8, 8, |w| w.write_uint64(*k), 17, |w| w.write_double(*v)))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SparseData<'a> {
+    pub id: Option<u32>,
+    pub name: Option<Cow<'a, str>>,
+    pub email: Option<Cow<'a, str>>,
+    pub age: Option<u32>,
+    pub score: Option<f64>,
+    pub active: Option<bool>,
+    pub created_at: Option<u64>,
+    pub updated_at: Option<u64>,
+    pub parent_id: Option<u64>,
+    pub country: Option<Cow<'a, str>>,
+    pub city: Option<Cow<'a, str>>,
+    pub zip: Option<u32>,
+    pub phone: Option<Cow<'a, str>>,
+    pub verified: Option<bool>,
+    pub rank: Option<i32>,
+    pub balance: Option<i64>,
+    pub locale: Option<Cow<'a, str>>,
+    pub timezone: Option<Cow<'a, str>>,
+    pub retries: Option<u32>,
+    pub deleted: Option<bool>,
+}
+
+impl<'a> MessageRead<'a> for SparseData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = Some(r.read_uint32(bytes)?),
+                Ok(18) => msg.name = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(26) => msg.email = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(32) => msg.age = Some(r.read_uint32(bytes)?),
+                Ok(41) => msg.score = Some(r.read_double(bytes)?),
+                Ok(48) => msg.active = Some(r.read_bool(bytes)?),
+                Ok(56) => msg.created_at = Some(r.read_uint64(bytes)?),
+                Ok(64) => msg.updated_at = Some(r.read_uint64(bytes)?),
+                Ok(72) => msg.parent_id = Some(r.read_uint64(bytes)?),
+                Ok(82) => msg.country = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(90) => msg.city = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(96) => msg.zip = Some(r.read_uint32(bytes)?),
+                Ok(106) => msg.phone = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(112) => msg.verified = Some(r.read_bool(bytes)?),
+                Ok(120) => msg.rank = Some(r.read_int32(bytes)?),
+                Ok(128) => msg.balance = Some(r.read_int64(bytes)?),
+                Ok(138) => msg.locale = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(146) => msg.timezone = Some(r.read_string(bytes).map(Cow::Borrowed)?),
+                Ok(152) => msg.retries = Some(r.read_uint32(bytes)?),
+                Ok(160) => msg.deleted = Some(r.read_bool(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for SparseData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.id.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.name.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.email.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.age.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.score.as_ref().map_or(0, |_| 1 + 8)
+        + self.active.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.created_at.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.updated_at.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.parent_id.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.country.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.city.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.zip.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.phone.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.verified.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.rank.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.balance.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.locale.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.timezone.as_ref().map_or(0, |m| 1 + sizeof_len((m).len()))
+        + self.retries.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.deleted.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.id { w.write_with_tag(8, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.name { w.write_with_tag(18, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.email { w.write_with_tag(26, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.age { w.write_with_tag(32, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.score { w.write_with_tag(41, |w| w.write_double(*s))?; }
+        if let Some(ref s) = self.active { w.write_with_tag(48, |w| w.write_bool(*s))?; }
+        if let Some(ref s) = self.created_at { w.write_with_tag(56, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.updated_at { w.write_with_tag(64, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.parent_id { w.write_with_tag(72, |w| w.write_uint64(*s))?; }
+        if let Some(ref s) = self.country { w.write_with_tag(82, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.city { w.write_with_tag(90, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.zip { w.write_with_tag(96, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.phone { w.write_with_tag(106, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.verified { w.write_with_tag(112, |w| w.write_bool(*s))?; }
+        if let Some(ref s) = self.rank { w.write_with_tag(120, |w| w.write_int32(*s))?; }
+        if let Some(ref s) = self.balance { w.write_with_tag(128, |w| w.write_int64(*s))?; }
+        if let Some(ref s) = self.locale { w.write_with_tag(138, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.timezone { w.write_with_tag(146, |w| w.write_string(&**s))?; }
+        if let Some(ref s) = self.retries { w.write_with_tag(152, |w| w.write_uint32(*s))?; }
+        if let Some(ref s) = self.deleted { w.write_with_tag(160, |w| w.write_bool(*s))?; }
+        Ok(())
+    }
+}
+
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MetricsData {
+    pub timestamps: Vec<u64>,
+    pub values: Vec<f64>,
+}
+
+impl<'a> MessageRead<'a> for MetricsData {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.timestamps = r.read_packed(bytes, |r, bytes| Ok(r.read_uint64(bytes)?))?,
+                Ok(18) => msg.values = r.read_packed(bytes, |r, bytes| Ok(r.read_double(bytes)?))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for MetricsData {
+    fn get_size(&self) -> usize {
+        0
+        + if self.timestamps.is_empty() { 0 } else { 1 + sizeof_len(self.timestamps.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+        + if self.values.is_empty() { 0 } else { 1 + sizeof_len(self.values.len() * 8) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_packed_with_tag(10, &self.timestamps, |w, m| w.write_uint64(*m), &|m| sizeof_varint(*(m) as u64))?;
+        w.write_packed_with_tag(18, &self.values, |w, m| w.write_double(*m), &|_| 8)?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct UnicodeData<'a> {
+    pub id: u32,
+    pub latin: Cow<'a, str>,
+    pub cjk: Cow<'a, str>,
+    pub rtl: Cow<'a, str>,
+    pub emoji: Cow<'a, str>,
+    pub escapes: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for UnicodeData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint32(bytes)?,
+                Ok(18) => msg.latin = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(26) => msg.cjk = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(34) => msg.rtl = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(42) => msg.emoji = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(50) => msg.escapes = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for UnicodeData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + if self.latin == "" { 0 } else { 1 + sizeof_len((&self.latin).len()) }
+        + if self.cjk == "" { 0 } else { 1 + sizeof_len((&self.cjk).len()) }
+        + if self.rtl == "" { 0 } else { 1 + sizeof_len((&self.rtl).len()) }
+        + if self.emoji == "" { 0 } else { 1 + sizeof_len((&self.emoji).len()) }
+        + if self.escapes == "" { 0 } else { 1 + sizeof_len((&self.escapes).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.id))?; }
+        if self.latin != "" { w.write_with_tag(18, |w| w.write_string(&**&self.latin))?; }
+        if self.cjk != "" { w.write_with_tag(26, |w| w.write_string(&**&self.cjk))?; }
+        if self.rtl != "" { w.write_with_tag(34, |w| w.write_string(&**&self.rtl))?; }
+        if self.emoji != "" { w.write_with_tag(42, |w| w.write_string(&**&self.emoji))?; }
+        if self.escapes != "" { w.write_with_tag(50, |w| w.write_string(&**&self.escapes))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct BigString<'a> {
+    pub text: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for BigString<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.text = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for BigString<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.text == "" { 0 } else { 1 + sizeof_len((&self.text).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.text != "" { w.write_with_tag(10, |w| w.write_string(&**&self.text))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct TimestampData {
+    pub id: u32,
+    pub created_at: Option<google::protobuf::Timestamp>,
+    pub updated_at: Option<google::protobuf::Timestamp>,
+    pub events: Vec<google::protobuf::Timestamp>,
+}
+
+impl<'a> MessageRead<'a> for TimestampData {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint32(bytes)?,
+                Ok(18) => msg.created_at = Some(r.read_message::<google::protobuf::Timestamp>(bytes)?),
+                Ok(26) => msg.updated_at = Some(r.read_message::<google::protobuf::Timestamp>(bytes)?),
+                Ok(34) => msg.events.push(r.read_message::<google::protobuf::Timestamp>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for TimestampData {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + self.created_at.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.updated_at.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.events.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.id))?; }
+        if let Some(ref s) = self.created_at { w.write_with_tag(18, |w| w.write_message(s))?; }
+        if let Some(ref s) = self.updated_at { w.write_with_tag(26, |w| w.write_message(s))?; }
+        for s in &self.events { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct WideInts<'a> {
+    pub amount: Cow<'a, str>,
+    pub delta: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for WideInts<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.amount = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(18) => msg.delta = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for WideInts<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.amount == "" { 0 } else { 1 + sizeof_len((&self.amount).len()) }
+        + if self.delta == "" { 0 } else { 1 + sizeof_len((&self.delta).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.amount != "" { w.write_with_tag(10, |w| w.write_string(&**&self.amount))?; }
+        if self.delta != "" { w.write_with_tag(18, |w| w.write_string(&**&self.delta))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct DeepNested {
+    pub level: u32,
+    pub child: Option<Box<DeepNested>>,
+}
+
+impl<'a> MessageRead<'a> for DeepNested {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.level = r.read_uint32(bytes)?,
+                Ok(18) => msg.child = Some(Box::new(r.read_message::<DeepNested>(bytes)?)),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for DeepNested {
+    fn get_size(&self) -> usize {
+        0
+        + if self.level == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.level) as u64) }
+        + self.child.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.level != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.level))?; }
+        if let Some(ref s) = self.child { w.write_with_tag(18, |w| w.write_message(&**s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Rgba {
+    pub r: u32,
+    pub g: u32,
+    pub b: u32,
+    pub a: u32,
+}
+
+impl<'a> MessageRead<'a> for Rgba {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.r = r.read_uint32(bytes)?,
+                Ok(16) => msg.g = r.read_uint32(bytes)?,
+                Ok(24) => msg.b = r.read_uint32(bytes)?,
+                Ok(32) => msg.a = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for Rgba {
+    fn get_size(&self) -> usize {
+        0
+        + if self.r == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.r) as u64) }
+        + if self.g == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.g) as u64) }
+        + if self.b == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.b) as u64) }
+        + if self.a == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.a) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.r != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.r))?; }
+        if self.g != 0u32 { w.write_with_tag(16, |w| w.write_uint32(*&self.g))?; }
+        if self.b != 0u32 { w.write_with_tag(24, |w| w.write_uint32(*&self.b))?; }
+        if self.a != 0u32 { w.write_with_tag(32, |w| w.write_uint32(*&self.a))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct NewtypeData<'a> {
+    pub id: u64,
+    pub email: Cow<'a, str>,
+    pub version: u32,
+    pub color: Option<Rgba>,
+}
+
+impl<'a> MessageRead<'a> for NewtypeData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint64(bytes)?,
+                Ok(18) => msg.email = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(24) => msg.version = r.read_uint32(bytes)?,
+                Ok(34) => msg.color = Some(r.read_message::<Rgba>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for NewtypeData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + if self.email == "" { 0 } else { 1 + sizeof_len((&self.email).len()) }
+        + if self.version == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.version) as u64) }
+        + self.color.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u64 { w.write_with_tag(8, |w| w.write_uint64(*&self.id))?; }
+        if self.email != "" { w.write_with_tag(18, |w| w.write_string(&**&self.email))?; }
+        if self.version != 0u32 { w.write_with_tag(24, |w| w.write_uint32(*&self.version))?; }
+        if let Some(ref s) = self.color { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct UuidTestData<'a> {
+    pub id: Cow<'a, [u8]>,
+    pub parent_id: Cow<'a, [u8]>,
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for UuidTestData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.id = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(18) => msg.parent_id = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(26) => msg.name = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for UuidTestData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.id).len()) }
+        + if self.parent_id == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.parent_id).len()) }
+        + if self.name == "" { 0 } else { 1 + sizeof_len((&self.name).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != Cow::Borrowed(b"") { w.write_with_tag(10, |w| w.write_bytes(&**&self.id))?; }
+        if self.parent_id != Cow::Borrowed(b"") { w.write_with_tag(18, |w| w.write_bytes(&**&self.parent_id))?; }
+        if self.name != "" { w.write_with_tag(26, |w| w.write_string(&**&self.name))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Pending { }
+
+impl<'a> MessageRead<'a> for Pending {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+}
+
+impl MessageWrite for Pending { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Suspended<'a> {
+    pub days: u32,
+    pub note: Cow<'a, str>,
+}
+
+impl<'a> MessageRead<'a> for Suspended<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.days = r.read_uint32(bytes)?,
+                Ok(18) => msg.note = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Suspended<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.days == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.days) as u64) }
+        + if self.note == "" { 0 } else { 1 + sizeof_len((&self.note).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.days != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.days))?; }
+        if self.note != "" { w.write_with_tag(18, |w| w.write_string(&**&self.note))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Closed<'a> {
+    pub reason: Cow<'a, str>,
+    pub at: u64,
+}
+
+impl<'a> MessageRead<'a> for Closed<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.reason = r.read_string(bytes).map(Cow::Borrowed)?,
+                Ok(16) => msg.at = r.read_uint64(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Closed<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.reason == "" { 0 } else { 1 + sizeof_len((&self.reason).len()) }
+        + if self.at == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.at) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.reason != "" { w.write_with_tag(10, |w| w.write_string(&**&self.reason))?; }
+        if self.at != 0u64 { w.write_with_tag(16, |w| w.write_uint64(*&self.at))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Archived { }
+
+impl<'a> MessageRead<'a> for Archived {
+    fn from_reader(r: &mut BytesReader, _: &[u8]) -> Result<Self> {
+        r.read_to_end();
+        Ok(Self::default())
+    }
+}
+
+impl MessageWrite for Archived { }
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Status<'a> {
+    pub kind: mod_Status::OneOfkind<'a>,
+}
+
+impl<'a> MessageRead<'a> for Status<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.kind = mod_Status::OneOfkind::pending(r.read_message::<Pending>(bytes)?),
+                Ok(16) => msg.kind = mod_Status::OneOfkind::active(r.read_uint64(bytes)?),
+                Ok(26) => msg.kind = mod_Status::OneOfkind::suspended(r.read_message::<Suspended>(bytes)?),
+                Ok(34) => msg.kind = mod_Status::OneOfkind::closed(r.read_message::<Closed>(bytes)?),
+                Ok(42) => msg.kind = mod_Status::OneOfkind::archived(r.read_message::<Archived>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Status<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + match self.kind {
+            mod_Status::OneOfkind::pending(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_Status::OneOfkind::active(ref m) => 1 + sizeof_varint(*(m) as u64),
+            mod_Status::OneOfkind::suspended(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_Status::OneOfkind::closed(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_Status::OneOfkind::archived(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_Status::OneOfkind::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        match self.kind {            mod_Status::OneOfkind::pending(ref m) => { w.write_with_tag(10, |w| w.write_message(m))? },
+            mod_Status::OneOfkind::active(ref m) => { w.write_with_tag(16, |w| w.write_uint64(*m))? },
+            mod_Status::OneOfkind::suspended(ref m) => { w.write_with_tag(26, |w| w.write_message(m))? },
+            mod_Status::OneOfkind::closed(ref m) => { w.write_with_tag(34, |w| w.write_message(m))? },
+            mod_Status::OneOfkind::archived(ref m) => { w.write_with_tag(42, |w| w.write_message(m))? },
+            mod_Status::OneOfkind::None => {},
+    }        Ok(())
+    }
+}
+
+pub mod mod_Status {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfkind<'a> {
+    pending(Pending),
+    active(u64),
+    suspended(Suspended<'a>),
+    closed(Closed<'a>),
+    archived(Archived),
+    None,
+}
+
+impl<'a> Default for OneOfkind<'a> {
+    fn default() -> Self {
+        OneOfkind::None
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct EnumTestData<'a> {
+    pub id: u32,
+    pub status: Option<Status<'a>>,
+    pub commands: Vec<Command>,
+}
+
+impl<'a> MessageRead<'a> for EnumTestData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint32(bytes)?,
+                Ok(18) => msg.status = Some(r.read_message::<Status>(bytes)?),
+                Ok(26) => msg.commands = r.read_packed(bytes, |r, bytes| Ok(r.read_enum(bytes)?))?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for EnumTestData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + self.status.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + if self.commands.is_empty() { 0 } else { 1 + sizeof_len(self.commands.iter().map(|s| sizeof_varint(*(s) as u64)).sum::<usize>()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.id))?; }
+        if let Some(ref s) = self.status { w.write_with_tag(18, |w| w.write_message(s))?; }
+        w.write_packed_with_tag(26, &self.commands, |w, m| w.write_enum(*m as i32), &|m| sizeof_varint(*(m) as u64))?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct MapTestData<'a> {
+    pub id: u32,
+    pub attributes: KVMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> MessageRead<'a> for MapTestData<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.id = r.read_uint32(bytes)?,
+                Ok(18) => {
+                    let (key, value) = r.read_map(bytes, |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?), |r, bytes| Ok(r.read_string(bytes).map(Cow::Borrowed)?))?;
+                    msg.attributes.insert(key, value);
+                }
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for MapTestData<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.id == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.id) as u64) }
+        + self.attributes.iter().map(|(k, v)| 1 + sizeof_len(2 + sizeof_len((k).len()) + sizeof_len((v).len()))).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.id != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.id))?; }
+        for (k, v) in self.attributes.iter() { w.write_with_tag(18, |w| w.write_map(2 + sizeof_len((k).len()) + sizeof_len((v).len()), 10, |w| w.write_string(&**k), 18, |w| w.write_string(&**v)))?; }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+// The JSON results file: when and with which compiler the run was made, and
+// the results nested by format, then payload size. Shared by path with
+// tests/results_file.rs, since the package has no library target
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// Structure to store benchmark results
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BenchmarkResults {
+    pub serialize_time_ns: f64,
+    pub serialize_std_dev_ns: Option<f64>,
+    pub serialize_ops_per_sec: u64,
+    pub deserialize_time_ns: f64,
+    pub deserialize_std_dev_ns: Option<f64>,
+    pub deserialize_ops_per_sec: u64,
+    // Serialized size of the payload; 0 when no size was recorded. Defaulted
+    // so baselines written before it existed still load
+    #[serde(default)]
+    pub payload_bytes: usize,
+    // Mean process CPU time per call, measured only in BENCH_MODE=simple
+    // with the `cpu-time` feature
+    pub serialize_cpu_ns: Option<f64>,
+    pub deserialize_cpu_ns: Option<f64>,
+    // `payload_bytes` per second of each direction, in MB/s (10^6 bytes);
+    // `None` without a recorded size
+    pub serialize_mb_per_sec: Option<f64>,
+    pub deserialize_mb_per_sec: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RunMetadata {
+    // RFC 3339, UTC, to the second
+    pub timestamp: String,
+    // `rustc --version` of the compiler on PATH (or $RUSTC), "unknown" if it can't be run
+    pub rustc_version: String,
+}
+
+impl RunMetadata {
+    pub fn current() -> Self {
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let rustc_version = Command::new(rustc)
+            .arg("--version")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map_or_else(|| "unknown".to_string(), |version| version.trim().to_string());
+        RunMetadata {
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            rustc_version,
+        }
+    }
+}
+
+// The metadata sits under its own key, so diffing the `results` of two runs
+// stays clean; BTreeMaps keep the keys sorted
+#[derive(Serialize, Deserialize)]
+pub struct ResultsFile {
+    pub metadata: RunMetadata,
+    pub results: BTreeMap<String, BTreeMap<String, BenchmarkResults>>,
+}
+
+impl ResultsFile {
+    // Write as pretty JSON, creating the parent directories if needed
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|err| err.to_string())?;
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        fs::write(path, json).map_err(|err| err.to_string())
+    }
+
+    pub fn read(path: &str) -> Result<Self, String> {
+        let json = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        serde_json::from_str(&json).map_err(|err| err.to_string())
+    }
+}
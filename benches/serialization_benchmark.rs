@@ -1,345 +1,7518 @@
-use bincode::{serialize, deserialize};
+use bincode::{serialize, deserialize, Options as _};
 use bcs::{to_bytes, from_bytes};
-use protobuf::Message;
+use protobuf::{CodedOutputStream, Message};
 use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
 use serde_json::{to_string, from_str};
 use borsh::{BorshSerialize, BorshDeserialize};
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use nanoserde::{DeBin, DeJson, SerBin, SerJson};
+use serde_pickle::{DeOptions, SerOptions};
+use speedy::{Endianness, Readable, Writable};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
 mod message;
+mod message_capnp;
+mod message_generated;
+mod payloads;
+mod results_file;
+mod serializers;
+mod test_data;
+// Generated from the same .proto; only `TestData` is benchmarked with it
+#[allow(dead_code)]
+mod quick_message;
+// Generated from the same .proto; only `TestData` and `BigString` are benchmarked with it
+#[allow(dead_code)]
+mod prost_message;
+// quick-protobuf's module for google/protobuf/timestamp.proto, under the
+// package path quick_message.rs refers to it by
+mod quick_timestamp;
+mod google {
+    pub mod protobuf {
+        pub use crate::quick_timestamp::Timestamp;
+    }
+}
 mod trallocator;
 use message as proto;
-use std::alloc::System;
-use std::collections::HashMap;
-use std::sync::{Mutex, Arc};
+use message_generated as fbs;
+use prost_message as prost_proto;
+use quick_message as quick_proto;
+use quick_protobuf::{BytesReader, MessageRead, MessageWrite, Writer};
+use serializers::Serializer;
+// Imported anonymously: its name clashes with rust-protobuf's `Message`
+use prost::Message as _;
+use flatbuffers::FlatBufferBuilder;
+use payloads::{
+    avro_blob_record, avro_blob_schema, avro_map_test_data_record, avro_map_test_data_schema, AlkahestBlobData,
+    AlkahestBlobDataView, Base64BlobData, BigStringData, BlobData, Command, DeepNested, DerBlobData, DerNestedTestData,
+    EnumTestData, Event, FixtureData, MapData, MapTestData, MetricsData, NanoBlobData, NanoMapTestData,
+    NanoNestedTestData, NestedTestData, NewtypeData, SparseData, Status, TimestampData, UnicodeData, UuidTestData,
+    WideInts,
+};
+use std::borrow::Cow;
+use results_file::{BenchmarkResults, ResultsFile, RunMetadata};
+use test_data::{
+    avro_record, avro_schema, build_capnp_message, build_flatbuffer, der_test_data, nano_test_data, prost_test_data,
+    proto_test_data, quick_proto_test_data, serialize_alkahest, serialize_capnp, serialize_prost, serialize_quick_protobuf,
+    AlkahestTestData, AlkahestTestDataView, DerTestData, NanoTestData, TestData, SAVEFILE_VERSION,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::time::Instant;
+use std::sync::{Mutex, Arc, OnceLock};
 use once_cell::sync::Lazy;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::io::{Cursor, Read, Write};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[cfg(all(feature = "jemalloc", feature = "mimalloc"))]
+compile_error!("the `jemalloc` and `mimalloc` features are mutually exclusive, enable at most one");
+
+#[cfg(not(any(feature = "jemalloc", feature = "mimalloc")))]
+#[global_allocator]
+static GLOBAL: trallocator::Trallocator<std::alloc::System>
+    = trallocator::Trallocator::new(std::alloc::System);
+
+// With the `jemalloc` or `mimalloc` feature every benchmark runs on that
+// allocator instead, still counted by `Trallocator`
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+#[global_allocator]
+static GLOBAL: trallocator::Trallocator<tikv_jemallocator::Jemalloc>
+    = trallocator::Trallocator::new(tikv_jemallocator::Jemalloc);
 
+#[cfg(all(feature = "mimalloc", not(feature = "jemalloc")))]
 #[global_allocator]
-static GLOBAL: trallocator::Trallocator<System> 
-    = trallocator::Trallocator::new(System);
+static GLOBAL: trallocator::Trallocator<mimalloc::MiMalloc>
+    = trallocator::Trallocator::new(mimalloc::MiMalloc);
+
+// Name of the global allocator, shown in the results table headers so runs
+// under different allocators can't be mixed up
+const ALLOCATOR: &str = if cfg!(feature = "jemalloc") {
+    "jemalloc"
+} else if cfg!(feature = "mimalloc") {
+    "mimalloc"
+} else {
+    "system"
+};
+
+// Benchmark results keyed by (format, payload size)
+type ResultsMap = HashMap<(String, &'static str), BenchmarkResults>;
 
 // Global storage for benchmark results with thread-safe access
-static BENCHMARK_RESULTS: Lazy<Arc<Mutex<HashMap<String, BenchmarkResults>>>> = 
+static BENCHMARK_RESULTS: Lazy<Arc<Mutex<ResultsMap>>> = 
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Global storage for compression results, keyed by "<format>_<codec>"
+static COMPRESSION_RESULTS: Lazy<Arc<Mutex<HashMap<String, CompressionResults>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Global storage for enum payload results, keyed by "<format>_enum"
+static ENUM_RESULTS: Lazy<Arc<Mutex<HashMap<String, PayloadResults>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Global storage for big string payload results, keyed by "<format>_big_string"
+static BIG_STRING_RESULTS: Lazy<Arc<Mutex<HashMap<String, PayloadResults>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Results of payloads run in several variants, keyed by (group, variant),
+// e.g. ("<format>_blob", blob size)
+type VariantResultsMap = HashMap<(String, &'static str), PayloadResults>;
+
+// Global storage for blob payload results
+static BLOB_RESULTS: Lazy<Arc<Mutex<VariantResultsMap>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Global storage for collection results, keyed by ("<format>_collection", length)
+static COLLECTION_RESULTS: Lazy<Arc<Mutex<VariantResultsMap>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Global storage for sparse payload results, keyed by ("<format>_sparse", "sparse" or "full")
+static SPARSE_RESULTS: Lazy<Arc<Mutex<VariantResultsMap>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Global storage for batch throughput results, keyed by "<format>_batch"
+static THROUGHPUT_RESULTS: Lazy<Arc<Mutex<HashMap<String, ThroughputResults>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+// Serialized size of each benchmark group's payload, recorded while benchmarking
+static PAYLOAD_SIZES: Lazy<Arc<Mutex<HashMap<String, usize>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Formats that failed `roundtrip_float_precision_check`, with the number of
+// values that did not come back bit for bit
+static LOSSY_FLOAT_FORMATS: Lazy<Arc<Mutex<HashMap<&'static str, usize>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Net bytes allocated while each benchmark group ran (serialize plus
+// deserialize), as measured by its `MemoryTracker`
+static MEMORY_USAGE: Lazy<Arc<Mutex<HashMap<String, i64>>>> =
+    Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
+
+// Codecs applied on top of each format's serialized bytes
+type CodecFn = fn(&[u8]) -> Vec<u8>;
+const COMPRESSION_CODECS: [(&str, CodecFn, CodecFn); 3] = [
+    ("zstd", zstd_compress, zstd_decompress),
+    ("gzip", gzip_compress, gzip_decompress),
+    ("lz4", lz4_compress, lz4_decompress),
+];
+
+// zstd level used by the compression benchmarks
+const ZSTD_LEVEL: i32 = 3;
+
+// Every registered format: the identifier accepted by BENCH_FORMATS, its
+// benchmark function and the criterion groups that function produces
+type BenchmarkFn = fn(&mut Criterion);
+type FormatEntry = (&'static str, BenchmarkFn, &'static [&'static str]);
+const FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode, &["bincode"]),
+    ("bitcode", benchmark_bitcode, &["bitcode"]),
+    ("bcs", benchmark_bcs, &["bcs"]),
+    ("protobuf", benchmark_protobuf, &["protobuf"]),
+    ("quick_protobuf", benchmark_quick_protobuf, &["quick_protobuf"]),
+    ("prost", benchmark_prost, &["prost"]),
+    ("capnp", benchmark_capnp, &["capnp"]),
+    ("serde_json", benchmark_serde_json, &["serde_json"]),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", benchmark_simd_json, &["simd_json"]),
+    ("sonic_rs", benchmark_sonic_rs, &["sonic_rs"]),
+    ("serde_yaml", benchmark_serde_yaml, &["serde_yaml"]),
+    ("ron", benchmark_ron, &["ron"]),
+    ("toml", benchmark_toml, &["toml"]),
+    ("xml", benchmark_xml, &["xml"]),
+    ("borsh", benchmark_borsh, &["borsh"]),
+    ("musli", benchmark_musli, &["musli_storage", "musli_wire"]),
+    ("nanoserde", benchmark_nanoserde, &["nanoserde_json", "nanoserde_bin"]),
+    ("flatbuffers", benchmark_flatbuffers, &["flatbuffers"]),
+    ("flexbuffers", benchmark_flexbuffers, &["flexbuffers"]),
+    ("xdr", benchmark_xdr, &["xdr"]),
+    ("savefile", benchmark_savefile, &["savefile"]),
+    ("pickle", benchmark_pickle, &["pickle"]),
+    ("der", benchmark_der, &["der"]),
+    ("alkahest", benchmark_alkahest, &["alkahest"]),
+    ("speedy", benchmark_speedy, &["speedy"]),
+    ("avro", benchmark_avro, &["avro"]),
+    ("bincode_zero_alloc", benchmark_bincode_zero_alloc, &["bincode_zero_alloc"]),
+    ("bcs_zero_alloc", benchmark_bcs_zero_alloc, &["bcs_zero_alloc"]),
+    ("protobuf_zero_alloc", benchmark_protobuf_zero_alloc, &["protobuf_zero_alloc"]),
+    ("borsh_zero_alloc", benchmark_borsh_zero_alloc, &["borsh_zero_alloc"]),
+    ("musli_storage_zero_alloc", benchmark_musli_storage_zero_alloc, &["musli_storage_zero_alloc"]),
+    ("musli_wire_zero_alloc", benchmark_musli_wire_zero_alloc, &["musli_wire_zero_alloc"]),
+    ("bincode_reuse", benchmark_bincode_reuse, &["bincode_reuse"]),
+    ("bcs_reuse", benchmark_bcs_reuse, &["bcs_reuse"]),
+    ("borsh_reuse", benchmark_borsh_reuse, &["borsh_reuse"]),
+    ("serde_json_writer", benchmark_serde_json_writer, &["serde_json_writer"]),
+    ("serde_json_borrowed", benchmark_serde_json_borrowed, &["serde_json_borrowed"]),
+    ("bincode_borrowed", benchmark_bincode_borrowed, &["bincode_borrowed"]),
+    ("bincode_limited", benchmark_bincode_limited, &["bincode_limited"]),
+    ("json_value", benchmark_json_value, &["json_value"]),
+    ("flexbuffers_value", benchmark_flexbuffers_value, &["flexbuffers_value"]),
+    ("bincode_compressed", benchmark_bincode_compressed, &["bincode_compressed"]),
+    ("serde_json_compressed", benchmark_json_compressed, &["serde_json_compressed"]),
+];
+
+// JSON libraries that produce the same text for `TestData`; the results table
+// lists them together under a `json_variants` heading
+const JSON_VARIANTS: [&str; 3] = ["serde_json", "simd_json", "sonic_rs"];
+
+// gzip level used by the compression and compressed serialize/deserialize benchmarks
+const GZIP_LEVEL: u32 = 6;
+
+// Enum payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`
+const ENUM_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_enum, &["protobuf_enum"]),
+    ("quick_protobuf", benchmark_quick_protobuf_enum, &["quick_protobuf_enum"]),
+    ("borsh", benchmark_borsh_enum, &["borsh_enum"]),
+    ("musli", benchmark_musli_enum, &["musli_storage_enum", "musli_wire_enum"]),
+    ("savefile", benchmark_savefile_enum, &["savefile_enum"]),
+    ("speedy", benchmark_speedy_enum, &["speedy_enum"]),
+];
+
+// Nested payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`; their rows land in the main results table with
+// a `_nested` suffix
+const NESTED_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_nested, &["protobuf_nested"]),
+    ("quick_protobuf", benchmark_quick_protobuf_nested, &["quick_protobuf_nested"]),
+    ("borsh", benchmark_borsh_nested, &["borsh_nested"]),
+    ("musli", benchmark_musli_nested, &["musli_storage_nested", "musli_wire_nested"]),
+    ("nanoserde", benchmark_nanoserde_nested, &["nanoserde_json_nested", "nanoserde_bin_nested"]),
+    ("savefile", benchmark_savefile_nested, &["savefile_nested"]),
+    ("der", benchmark_der_nested, &["der_nested"]),
+    ("speedy", benchmark_speedy_nested, &["speedy_nested"]),
+];
+
+// Map payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`; their rows land in the main results table with
+// a `_map` suffix
+const MAP_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_map, &["protobuf_map"]),
+    ("quick_protobuf", benchmark_quick_protobuf_map, &["quick_protobuf_map"]),
+    ("borsh", benchmark_borsh_map, &["borsh_map"]),
+    ("musli", benchmark_musli_map, &["musli_storage_map", "musli_wire_map"]),
+    ("savefile", benchmark_savefile_map, &["savefile_map"]),
+    ("speedy", benchmark_speedy_map, &["speedy_map"]),
+];
+
+// String map payload benchmarks of the formats without a `Serializer`, keyed by
+// the same identifiers as `FORMATS`; their rows land in the main results table
+// with a `_string_map` suffix
+const STRING_MAP_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_string_map, &["protobuf_string_map"]),
+    ("quick_protobuf", benchmark_quick_protobuf_string_map, &["quick_protobuf_string_map"]),
+    ("prost", benchmark_prost_string_map, &["prost_string_map"]),
+    ("capnp", benchmark_capnp_string_map, &["capnp_string_map"]),
+    ("borsh", benchmark_borsh_string_map, &["borsh_string_map"]),
+    ("flatbuffers", benchmark_flatbuffers_string_map, &["flatbuffers_string_map"]),
+    ("avro", benchmark_avro_string_map, &["avro_string_map"]),
+    ("musli", benchmark_musli_string_map, &["musli_storage_string_map", "musli_wire_string_map"]),
+    ("nanoserde", benchmark_nanoserde_string_map, &["nanoserde_json_string_map", "nanoserde_bin_string_map"]),
+    ("savefile", benchmark_savefile_string_map, &["savefile_string_map"]),
+    ("speedy", benchmark_speedy_string_map, &["speedy_string_map"]),
+];
+
+// Metrics payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`; their rows land in the main results table with
+// a `_metrics` suffix. serde_json also runs `serde_json_metrics_values` on the
+// floats alone, isolating float formatting and parsing from the integer timestamps
+const METRICS_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_metrics, &["protobuf_metrics"]),
+    ("quick_protobuf", benchmark_quick_protobuf_metrics, &["quick_protobuf_metrics"]),
+    ("serde_json", benchmark_serde_json_metrics_values, &["serde_json_metrics_values"]),
+    ("borsh", benchmark_borsh_metrics, &["borsh_metrics"]),
+    ("musli", benchmark_musli_metrics, &["musli_storage_metrics", "musli_wire_metrics"]),
+    ("savefile", benchmark_savefile_metrics, &["savefile_metrics"]),
+    ("speedy", benchmark_speedy_metrics, &["speedy_metrics"]),
+];
+
+// Unicode payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`; their rows land in the main results table with
+// a `_unicode` suffix
+const UNICODE_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_unicode, &["protobuf_unicode"]),
+    ("quick_protobuf", benchmark_quick_protobuf_unicode, &["quick_protobuf_unicode"]),
+    ("borsh", benchmark_borsh_unicode, &["borsh_unicode"]),
+    ("musli", benchmark_musli_unicode, &["musli_storage_unicode", "musli_wire_unicode"]),
+    ("savefile", benchmark_savefile_unicode, &["savefile_unicode"]),
+    ("speedy", benchmark_speedy_unicode, &["speedy_unicode"]),
+];
+
+// Timestamp payload benchmarks of the formats without a `Serializer`, keyed by
+// the same identifiers as `FORMATS`; their rows land in the main results table
+// with a `_timestamp` suffix. Musli
+// and Savefile are missing: `Timestamp` would need hand-written impls of their
+// internal traits
+const TIMESTAMP_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_timestamp, &["protobuf_timestamp"]),
+    ("quick_protobuf", benchmark_quick_protobuf_timestamp, &["quick_protobuf_timestamp"]),
+    ("prost", benchmark_prost_timestamp, &["prost_timestamp"]),
+    ("borsh", benchmark_borsh_timestamp, &["borsh_timestamp"]),
+    ("speedy", benchmark_speedy_timestamp, &["speedy_timestamp"]),
+];
+
+// 128-bit integer payload benchmarks of the formats without a `Serializer`,
+// keyed by the same identifiers as `FORMATS`; their rows land in the main
+// results table with a `_wide_ints` suffix
+const WIDE_INT_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_wide_ints, &["protobuf_wide_ints"]),
+    ("quick_protobuf", benchmark_quick_protobuf_wide_ints, &["quick_protobuf_wide_ints"]),
+    ("prost", benchmark_prost_wide_ints, &["prost_wide_ints"]),
+    ("borsh", benchmark_borsh_wide_ints, &["borsh_wide_ints"]),
+    ("musli", benchmark_musli_wide_ints, &["musli_storage_wide_ints", "musli_wire_wide_ints"]),
+    ("savefile", benchmark_savefile_wide_ints, &["savefile_wide_ints"]),
+    ("speedy", benchmark_speedy_wide_ints, &["speedy_wide_ints"]),
+];
+
+// Deep nesting payload benchmarks of the formats without a `Serializer`, keyed
+// by the same identifiers as `FORMATS`; their rows land in the main results
+// table with a `_deep` suffix
+const DEEP_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_deep, &["protobuf_deep"]),
+    ("quick_protobuf", benchmark_quick_protobuf_deep, &["quick_protobuf_deep"]),
+    ("prost", benchmark_prost_deep, &["prost_deep"]),
+    ("borsh", benchmark_borsh_deep, &["borsh_deep"]),
+    ("musli", benchmark_musli_deep, &["musli_storage_deep", "musli_wire_deep"]),
+    ("savefile", benchmark_savefile_deep, &["savefile_deep"]),
+    ("speedy", benchmark_speedy_deep, &["speedy_deep"]),
+];
+
+// Newtype and tuple struct payload benchmarks of the formats without a
+// `Serializer`, keyed by the same identifiers as `FORMATS`; their rows land in
+// the main results table with a `_newtype` suffix
+const NEWTYPE_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_newtype, &["protobuf_newtype"]),
+    ("quick_protobuf", benchmark_quick_protobuf_newtype, &["quick_protobuf_newtype"]),
+    ("prost", benchmark_prost_newtype, &["prost_newtype"]),
+    ("borsh", benchmark_borsh_newtype, &["borsh_newtype"]),
+    ("musli", benchmark_musli_newtype, &["musli_storage_newtype", "musli_wire_newtype"]),
+    ("savefile", benchmark_savefile_newtype, &["savefile_newtype"]),
+    ("speedy", benchmark_speedy_newtype, &["speedy_newtype"]),
+];
+
+// UUID payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`; their rows land in the main results table with
+// a `_uuid` suffix
+const UUID_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_uuid, &["protobuf_uuid"]),
+    ("quick_protobuf", benchmark_quick_protobuf_uuid, &["quick_protobuf_uuid"]),
+    ("prost", benchmark_prost_uuid, &["prost_uuid"]),
+    ("borsh", benchmark_borsh_uuid, &["borsh_uuid"]),
+    ("speedy", benchmark_speedy_uuid, &["speedy_uuid"]),
+];
+
+// Enum data payload benchmarks of the formats without a `Serializer`,
+// keyed by the same identifiers as `FORMATS`; their rows land in the main
+// results table with a `_enum_data` suffix
+const ENUM_DATA_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_enum_data, &["protobuf_enum_data"]),
+    ("quick_protobuf", benchmark_quick_protobuf_enum_data, &["quick_protobuf_enum_data"]),
+    ("prost", benchmark_prost_enum_data, &["prost_enum_data"]),
+    ("borsh", benchmark_borsh_enum_data, &["borsh_enum_data"]),
+    ("musli", benchmark_musli_enum_data, &["musli_storage_enum_data", "musli_wire_enum_data"]),
+    ("savefile", benchmark_savefile_enum_data, &["savefile_enum_data"]),
+    ("speedy", benchmark_speedy_enum_data, &["speedy_enum_data"]),
+    ("capnp", benchmark_capnp_enum_data, &["capnp_enum_data"]),
+    ("flatbuffers", benchmark_flatbuffers_enum_data, &["flatbuffers_enum_data"]),
+];
+
+// Formats expected to encode a newtype exactly like the value it wraps and a
+// tuple struct exactly like an array of its elements, checked by
+// `print_newtype_size_report`
+const NEWTYPE_TRANSPARENT_FORMATS: &[&str] = &["bincode", "bcs", "serde_json", "simd_json", "sonic_rs", "serde_yaml", "toml", "borsh"];
+
+// Fixture payload benchmarks of the formats without a `Serializer`, keyed by the
+// same identifiers as `FORMATS`; their rows land in the main results table with
+// a `_fixture` suffix
+const FIXTURE_FORMATS: &[FormatEntry] = &[
+    ("borsh", benchmark_borsh_fixture, &["borsh_fixture"]),
+    ("musli", benchmark_musli_fixture, &["musli_storage_fixture", "musli_wire_fixture"]),
+    ("savefile", benchmark_savefile_fixture, &["savefile_fixture"]),
+    ("speedy", benchmark_speedy_fixture, &["speedy_fixture"]),
+];
+
+// Big string payload benchmarks of the formats without a `Serializer`, keyed by
+// the same identifiers as `FORMATS`
+const BIG_STRING_FORMATS: &[FormatEntry] = &[
+    ("protobuf", benchmark_protobuf_big_string, &["protobuf_big_string"]),
+    ("quick_protobuf", benchmark_quick_protobuf_big_string, &["quick_protobuf_big_string"]),
+    ("prost", benchmark_prost_big_string, &["prost_big_string"]),
+    ("borsh", benchmark_borsh_big_string, &["borsh_big_string"]),
+    ("musli", benchmark_musli_big_string, &["musli_storage_big_string", "musli_wire_big_string"]),
+    ("savefile", benchmark_savefile_big_string, &["savefile_big_string"]),
+    ("speedy", benchmark_speedy_big_string, &["speedy_big_string"]),
+];
+
+// Blob payload benchmarks, keyed by the same identifiers as `FORMATS`
+const BLOB_FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode_blob, &["bincode_blob"]),
+    ("bcs", benchmark_bcs_blob, &["bcs_blob"]),
+    ("protobuf", benchmark_protobuf_blob, &["protobuf_blob"]),
+    ("quick_protobuf", benchmark_quick_protobuf_blob, &["quick_protobuf_blob"]),
+    ("serde_json", benchmark_serde_json_blob, &["serde_json_blob", "serde_json_base64_blob"]),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", benchmark_simd_json_blob, &["simd_json_blob"]),
+    ("sonic_rs", benchmark_sonic_rs_blob, &["sonic_rs_blob"]),
+    ("serde_yaml", benchmark_serde_yaml_blob, &["serde_yaml_blob"]),
+    ("ron", benchmark_ron_blob, &["ron_blob"]),
+    ("borsh", benchmark_borsh_blob, &["borsh_blob"]),
+    ("musli", benchmark_musli_blob, &["musli_storage_blob", "musli_wire_blob"]),
+    ("nanoserde", benchmark_nanoserde_blob, &["nanoserde_json_blob", "nanoserde_bin_blob"]),
+    ("flexbuffers", benchmark_flexbuffers_blob, &["flexbuffers_blob"]),
+    ("xdr", benchmark_xdr_blob, &["xdr_blob"]),
+    ("savefile", benchmark_savefile_blob, &["savefile_blob"]),
+    ("pickle", benchmark_pickle_blob, &["pickle_blob"]),
+    ("der", benchmark_der_blob, &["der_blob"]),
+    ("speedy", benchmark_speedy_blob, &["speedy_blob"]),
+    ("capnp", benchmark_capnp_blob, &["capnp_blob"]),
+    ("flatbuffers", benchmark_flatbuffers_blob, &["flatbuffers_blob"]),
+    ("alkahest", benchmark_alkahest_blob, &["alkahest_blob"]),
+    ("avro", benchmark_avro_blob, &["avro_blob"]),
+];
+
+// Blob payload sizes in table order: (label, payload bytes)
+const BLOB_SIZES: [(&str, usize); 2] = [("1kb", 1024), ("64kb", 64 * 1024)];
+
+// Sparse payload benchmarks, keyed by the same identifiers as `FORMATS`
+const SPARSE_FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode_sparse, &["bincode_sparse"]),
+    ("protobuf", benchmark_protobuf_sparse, &["protobuf_sparse"]),
+    ("quick_protobuf", benchmark_quick_protobuf_sparse, &["quick_protobuf_sparse"]),
+    ("serde_json", benchmark_serde_json_sparse, &["serde_json_sparse"]),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", benchmark_simd_json_sparse, &["simd_json_sparse"]),
+    ("sonic_rs", benchmark_sonic_rs_sparse, &["sonic_rs_sparse"]),
+    ("serde_yaml", benchmark_serde_yaml_sparse, &["serde_yaml_sparse"]),
+    ("ron", benchmark_ron_sparse, &["ron_sparse"]),
+    ("toml", benchmark_toml_sparse, &["toml_sparse"]),
+    ("borsh", benchmark_borsh_sparse, &["borsh_sparse"]),
+    ("musli", benchmark_musli_sparse, &["musli_storage_sparse", "musli_wire_sparse"]),
+    ("flexbuffers", benchmark_flexbuffers_sparse, &["flexbuffers_sparse"]),
+    ("xdr", benchmark_xdr_sparse, &["xdr_sparse"]),
+    ("savefile", benchmark_savefile_sparse, &["savefile_sparse"]),
+    ("pickle", benchmark_pickle_sparse, &["pickle_sparse"]),
+    ("speedy", benchmark_speedy_sparse, &["speedy_sparse"]),
+];
+
+// Sparse payload variants in table order: two of twenty fields set, then all of them
+const SPARSE_VARIANTS: [&str; 2] = ["sparse", "full"];
+
+// Deserialization of corrupted standard payloads, keyed by the same
+// identifiers as `FORMATS`. Only formats that need every byte of the input
+// and validate what they read are listed, so both corruptions must fail
+const ERROR_FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode_error, &["bincode_error"]),
+    ("bcs", benchmark_bcs_error, &["bcs_error"]),
+    ("protobuf", benchmark_protobuf_error, &["protobuf_error"]),
+    ("prost", benchmark_prost_error, &["prost_error"]),
+    ("serde_json", benchmark_serde_json_error, &["serde_json_error"]),
+    ("sonic_rs", benchmark_sonic_rs_error, &["sonic_rs_error"]),
+    ("borsh", benchmark_borsh_error, &["borsh_error"]),
+    ("speedy", benchmark_speedy_error, &["speedy_error"]),
+];
+
+// Corruptions in table order: the last byte cut off, and every bit inverted
+const ERROR_VARIANTS: [&str; 2] = ["truncated", "inverted"];
+
+// Batch (`Vec<TestData>`) throughput benchmarks, keyed by the same identifiers as `FORMATS`
+const THROUGHPUT_FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode_batch, &["bincode_batch"]),
+    ("bcs", benchmark_bcs_batch, &["bcs_batch"]),
+    ("protobuf", benchmark_protobuf_batch, &["protobuf_batch"]),
+    ("serde_json", benchmark_serde_json_batch, &["serde_json_batch"]),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", benchmark_simd_json_batch, &["simd_json_batch"]),
+    ("sonic_rs", benchmark_sonic_rs_batch, &["sonic_rs_batch"]),
+    ("serde_yaml", benchmark_serde_yaml_batch, &["serde_yaml_batch"]),
+    ("ron", benchmark_ron_batch, &["ron_batch"]),
+    ("borsh", benchmark_borsh_batch, &["borsh_batch"]),
+    ("musli", benchmark_musli_batch, &["musli_storage_batch", "musli_wire_batch"]),
+    ("nanoserde", benchmark_nanoserde_batch, &["nanoserde_json_batch", "nanoserde_bin_batch"]),
+    ("flatbuffers", benchmark_flatbuffers_batch, &["flatbuffers_batch"]),
+    ("flexbuffers", benchmark_flexbuffers_batch, &["flexbuffers_batch"]),
+    ("xdr", benchmark_xdr_batch, &["xdr_batch"]),
+    ("savefile", benchmark_savefile_batch, &["savefile_batch"]),
+    ("pickle", benchmark_pickle_batch, &["pickle_batch"]),
+    ("der", benchmark_der_batch, &["der_batch"]),
+    ("alkahest", benchmark_alkahest_batch, &["alkahest_batch"]),
+    ("speedy", benchmark_speedy_batch, &["speedy_batch"]),
+    ("avro", benchmark_avro_batch, &["avro_batch"]),
+];
+
+// Medium and large payload benchmarks, keyed by the same identifiers as `FORMATS`;
+// the single-value groups above are the "small" payload
+const SIZED_FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode_sizes, &["bincode_sizes"]),
+    ("bcs", benchmark_bcs_sizes, &["bcs_sizes"]),
+    ("protobuf", benchmark_protobuf_sizes, &["protobuf_sizes"]),
+    ("serde_json", benchmark_serde_json_sizes, &["serde_json_sizes"]),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", benchmark_simd_json_sizes, &["simd_json_sizes"]),
+    ("sonic_rs", benchmark_sonic_rs_sizes, &["sonic_rs_sizes"]),
+    ("serde_yaml", benchmark_serde_yaml_sizes, &["serde_yaml_sizes"]),
+    ("ron", benchmark_ron_sizes, &["ron_sizes"]),
+    ("borsh", benchmark_borsh_sizes, &["borsh_sizes"]),
+    ("musli", benchmark_musli_sizes, &["musli_storage_sizes", "musli_wire_sizes"]),
+    ("nanoserde", benchmark_nanoserde_sizes, &["nanoserde_json_sizes", "nanoserde_bin_sizes"]),
+    ("flatbuffers", benchmark_flatbuffers_sizes, &["flatbuffers_sizes"]),
+    ("flexbuffers", benchmark_flexbuffers_sizes, &["flexbuffers_sizes"]),
+    ("xdr", benchmark_xdr_sizes, &["xdr_sizes"]),
+    ("savefile", benchmark_savefile_sizes, &["savefile_sizes"]),
+    ("pickle", benchmark_pickle_sizes, &["pickle_sizes"]),
+    ("der", benchmark_der_sizes, &["der_sizes"]),
+    ("alkahest", benchmark_alkahest_sizes, &["alkahest_sizes"]),
+    ("speedy", benchmark_speedy_sizes, &["speedy_sizes"]),
+    ("avro", benchmark_avro_sizes, &["avro_sizes"]),
+];
+
+// `Vec<TestData>` collection benchmarks, keyed by the same identifiers as `FORMATS`
+const COLLECTION_FORMATS: &[FormatEntry] = &[
+    ("bincode", benchmark_bincode_collection, &["bincode_collection"]),
+    ("bcs", benchmark_bcs_collection, &["bcs_collection"]),
+    ("protobuf", benchmark_protobuf_collection, &["protobuf_collection"]),
+    ("serde_json", benchmark_serde_json_collection, &["serde_json_collection"]),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", benchmark_simd_json_collection, &["simd_json_collection"]),
+    ("sonic_rs", benchmark_sonic_rs_collection, &["sonic_rs_collection"]),
+    ("serde_yaml", benchmark_serde_yaml_collection, &["serde_yaml_collection"]),
+    ("ron", benchmark_ron_collection, &["ron_collection"]),
+    ("borsh", benchmark_borsh_collection, &["borsh_collection"]),
+    ("musli", benchmark_musli_collection, &["musli_storage_collection", "musli_wire_collection"]),
+    ("nanoserde", benchmark_nanoserde_collection, &["nanoserde_json_collection", "nanoserde_bin_collection"]),
+    ("flatbuffers", benchmark_flatbuffers_collection, &["flatbuffers_collection"]),
+    ("flexbuffers", benchmark_flexbuffers_collection, &["flexbuffers_collection"]),
+    ("xdr", benchmark_xdr_collection, &["xdr_collection"]),
+    ("savefile", benchmark_savefile_collection, &["savefile_collection"]),
+    ("pickle", benchmark_pickle_collection, &["pickle_collection"]),
+    ("der", benchmark_der_collection, &["der_collection"]),
+    ("alkahest", benchmark_alkahest_collection, &["alkahest_collection"]),
+    ("speedy", benchmark_speedy_collection, &["speedy_collection"]),
+    ("avro", benchmark_avro_collection, &["avro_collection"]),
+];
+
+// Collection lengths in table order: (label, records)
+const COLLECTION_LENGTHS: [(&str, usize); 5] = [("1", 1), ("10", 10), ("100", 100), ("1000", 1_000), ("10000", 10_000)];
+
+// Column headers of the main results table
+const RESULT_COLUMNS: [&str; 13] = [
+    "Format",
+    "Serialization Time (ns)",
+    "Serialize ± (ns)",
+    "Serialization Ops/sec",
+    "Serialize MB/s",
+    "Serialize CPU (ns)",
+    "Deserialization Time (ns)",
+    "Deserialize ± (ns)",
+    "Deserialization Ops/sec",
+    "Deserialize MB/s",
+    "Deserialize CPU (ns)",
+    "Size (bytes)",
+    "Score",
+];
+
+// Indices of the CPU time columns in `RESULT_COLUMNS`, left out of tables
+// where no row has a CPU time
+const CPU_COLUMNS: [usize; 2] = [5, 10];
+
+// Weights of serialize time, deserialize time and size in the results table's
+// score unless BENCH_SCORE_WEIGHTS overrides them
+const DEFAULT_SCORE_WEIGHTS: [f64; 3] = [1.0, 1.0, 1.0];
+
+// Payload sizes in table order: "small" is a single `TestData`, "medium"
+// (~1 KB) and "large" (~1 MB) are `Vec<TestData>` of these many records
+const PAYLOAD_SIZE_TIERS: [&str; 3] = ["small", "medium", "large"];
+const MEDIUM_PAYLOAD_RECORDS: usize = 50;
+const LARGE_PAYLOAD_RECORDS: usize = 40_000;
+
+// Fixture used when BENCH_FIXTURE is unset
+const DEFAULT_FIXTURE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/fixtures/users.json");
+
+// Slowdown, in percent, beyond which a time counts as a regression against
+// the BENCH_BASELINE results unless SERIALIZER_REGRESSION_THRESHOLD overrides it
+const DEFAULT_REGRESSION_THRESHOLD: f64 = 5.0;
+
+// Number of `TestData` values per batch unless BENCH_BATCH_SIZE overrides it
+const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+// Timed calls per closure in BENCH_MODE=simple unless BENCH_ITERS overrides it;
+// a tenth as many untimed calls run first as warmup
+const DEFAULT_SIMPLE_ITERS: usize = 100_000;
+
+// Size of the caller-provided stack buffer used by the zero-allocation benchmarks
+const ZERO_ALLOC_BUFFER_SIZE: usize = 4096;
+
+// Serialize calls sampled when counting allocations per call for the reuse benchmarks
+const REUSE_ALLOC_SAMPLES: u64 = 1000;
+
+// Initial capacity of the scratch `Vec` the reuse benchmarks serialize into
+const REUSE_BUFFER_CAPACITY: usize = 256;
+
+// Most bytes the `_limited` group will read from one input, as a service
+// decoding untrusted input would cap it; far above the standard payload
+const UNTRUSTED_INPUT_LIMIT: u64 = 64 * 1024;
+
+// Name lengths, in characters, of random `TestData` unless BENCH_STRING_LEN overrides them
+const DEFAULT_RANDOM_NAME_CHARS: RangeInclusive<usize> = 1..=32;
+
 // Custom memory tracker
 #[derive(Default)]
 struct MemoryTracker {
-    initial: u64,
-    after_serialize: u64,
-    after_deserialize: u64,
+    serialize: i64,
+    deserialize: i64,
+    // Bytes released and `dealloc` calls made during each phase; the net
+    // counts above hide memory that was allocated and freed again
+    serialize_dealloc_bytes: u64,
+    serialize_dealloc_count: u64,
+    deserialize_dealloc_bytes: u64,
+    deserialize_dealloc_count: u64,
 }
 
 impl MemoryTracker {
     fn new() -> Self {
         MemoryTracker {
-            initial: 0,
-            after_serialize: 0,
-            after_deserialize: 0,
+            serialize: 0,
+            deserialize: 0,
+            serialize_dealloc_bytes: 0,
+            serialize_dealloc_count: 0,
+            deserialize_dealloc_bytes: 0,
+            deserialize_dealloc_count: 0,
         }
     }
 
-    fn log_initial(&mut self) {
-        GLOBAL.reset();
-        self.initial = GLOBAL.get();
-    }
-
-    fn log_after_serialize(&mut self) {
-        self.after_serialize = GLOBAL.get();
-        GLOBAL.reset();
+    fn track_serialize<F: FnOnce()>(&mut self, f: F) {
+        let (bytes, count) = (GLOBAL.get_dealloc_bytes(), GLOBAL.get_dealloc_count());
+        self.serialize = measure_alloc(f);
+        self.serialize_dealloc_bytes = GLOBAL.get_dealloc_bytes() - bytes;
+        self.serialize_dealloc_count = GLOBAL.get_dealloc_count() - count;
     }
 
-    fn log_after_deserialize(&mut self) {
-        self.after_deserialize = GLOBAL.get();
-        GLOBAL.reset();
+    fn track_deserialize<F: FnOnce()>(&mut self, f: F) {
+        let (bytes, count) = (GLOBAL.get_dealloc_bytes(), GLOBAL.get_dealloc_count());
+        self.deserialize = measure_alloc(f);
+        self.deserialize_dealloc_bytes = GLOBAL.get_dealloc_bytes() - bytes;
+        self.deserialize_dealloc_count = GLOBAL.get_dealloc_count() - count;
     }
 
-    fn print_summary(&self, operation: &str) {
+    fn print_summary(&self, group_name: &str, operation: &str) {
+        MEMORY_USAGE.lock().unwrap().insert(group_name.to_string(), self.serialize + self.deserialize);
         println!("--- {} ---", operation);
-        println!("Memory before: {} bytes", self.initial);
-        println!("Memory after serialize: {} bytes", self.after_serialize);
-        println!("Memory after deserialize: {} bytes", self.after_deserialize);
-        println!("Memory used during {}: {} bytes", operation, self.after_deserialize + self.after_serialize - self.initial);
+        println!("Memory allocated during serialize: {} bytes", self.serialize);
+        println!("Memory allocated during deserialize: {} bytes", self.deserialize);
+        println!("Memory used during {}: {} bytes", operation, self.serialize + self.deserialize);
+        println!("Memory freed during serialize: {} bytes in {} deallocations", self.serialize_dealloc_bytes, self.serialize_dealloc_count);
+        println!("Memory freed during deserialize: {} bytes in {} deallocations", self.deserialize_dealloc_bytes, self.deserialize_dealloc_count);
+        #[cfg(feature = "jemalloc")]
+        if let Some(allocated) = trallocator::jemalloc_allocated() {
+            println!("jemalloc stats.allocated after {}: {} bytes", operation, allocated);
+        }
         println!("---------------------\n");
     }
 }
 
-// Structure to store benchmark results
+// Net bytes allocated while `f` runs, taken as the difference between two
+// snapshots of the allocator counter rather than by resetting it, so frees of
+// memory allocated earlier (e.g. by criterion) can't wrap the count around
+fn measure_alloc<F: FnOnce()>(f: F) -> i64 {
+    let before = GLOBAL.get();
+    f();
+    GLOBAL.get().wrapping_sub(before) as i64
+}
+
+// Average number of allocator calls made by each of `iterations` runs of `f`
+fn allocations_per_call<F: FnMut()>(iterations: u64, mut f: F) -> f64 {
+    let before = GLOBAL.allocations();
+    for _ in 0..iterations {
+        f();
+    }
+    (GLOBAL.allocations() - before) as f64 / iterations as f64
+}
+
+// Structure to store compression results
+#[derive(Clone)]
+struct CompressionResults {
+    raw_size: usize,
+    compressed_size: usize,
+    compression_ratio: f64,
+    compress_time_ns: f64,
+    decompress_time_ns: f64,
+}
+
+// Structure to store batch throughput results
 #[derive(Clone)]
-struct BenchmarkResults {
+struct ThroughputResults {
+    batch_size: usize,
+    serialized_size: usize,
+    serialize_mb_per_sec: f64,
+    deserialize_mb_per_sec: f64,
+}
+
+// Structure to store results for payloads other than `TestData`
+#[derive(Clone)]
+struct PayloadResults {
+    serialized_size: usize,
     serialize_time_ns: f64,
-    serialize_ops_per_sec: u64,
     deserialize_time_ns: f64,
-    deserialize_ops_per_sec: u64,
 }
 
-#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Debug, Clone)]
-struct TestData {
+// `TestData` with `name` borrowed from the input, so serde deserializes it
+// without allocating a `String`. serde_json can only borrow strings that
+// contain no escape sequences; it errors on the others
+#[derive(Serialize, Deserialize, Debug)]
+struct TestDataBorrowed<'a> {
     id: u32,
-    name: String,
+    name: &'a str,
     active: bool,
 }
 
-impl TestData {
-    fn new() -> Self {
-        TestData {
-            id: 1,
-            name: "Rust".to_string(),
-            active: true,
+impl<'a> From<&'a TestData> for TestDataBorrowed<'a> {
+    fn from(test_data: &'a TestData) -> Self {
+        TestDataBorrowed { id: test_data.id, name: &test_data.name, active: test_data.active }
+    }
+}
+
+// How the single-value and batch payloads are generated: fixed values, or
+// random ones from BENCH_SEED with names of BENCH_STRING_LEN characters, so a
+// randomized run can be reproduced exactly by reusing its parameters
+struct PayloadGenerator {
+    seed: Option<u64>,
+    name_chars: RangeInclusive<usize>,
+}
+
+impl PayloadGenerator {
+    fn from_env() -> Self {
+        let seed = std::env::var("BENCH_SEED").ok().and_then(|value| {
+            value.trim().parse().map_err(|_| eprintln!("Warning: invalid BENCH_SEED '{}', using TestData::new()", value)).ok()
+        });
+        let name_chars = match std::env::var("BENCH_STRING_LEN") {
+            Ok(value) => parse_length_range(&value).unwrap_or_else(|| {
+                eprintln!("Warning: invalid BENCH_STRING_LEN '{}', using {}-{}", value, DEFAULT_RANDOM_NAME_CHARS.start(), DEFAULT_RANDOM_NAME_CHARS.end());
+                DEFAULT_RANDOM_NAME_CHARS
+            }),
+            Err(_) => DEFAULT_RANDOM_NAME_CHARS,
+        };
+        PayloadGenerator { seed, name_chars }
+    }
+
+    // `count` records from a fresh generator, so the first one is always the
+    // single-value payload
+    fn random_records(&self, seed: u64, count: usize) -> Vec<TestData> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count).map(|_| TestData::random(&mut rng, &self.name_chars)).collect()
+    }
+
+    // One line stating every parameter, for the report headers
+    fn describe(&self) -> String {
+        match self.seed {
+            Some(seed) => format!("random TestData (BENCH_SEED {}, names of {}-{} chars), batches of {} records",
+                                  seed, self.name_chars.start(), self.name_chars.end(), batch_size()),
+            None => format!("fixed TestData::new(), batches of {} records", batch_size()),
         }
     }
 }
 
-// Store benchmark results
-fn store_results(format: &str, serialize_ns: f64, deserialize_ns: f64) {
-    let serialize_ops = (1_000_000_000.0 / serialize_ns) as u64;
-    let deserialize_ops = (1_000_000_000.0 / deserialize_ns) as u64;
-    
-    let mut results = BENCHMARK_RESULTS.lock().unwrap();
-    results.insert(format.to_string(), BenchmarkResults {
-        serialize_time_ns: serialize_ns,
-        serialize_ops_per_sec: serialize_ops,
-        deserialize_time_ns: deserialize_ns,
-        deserialize_ops_per_sec: deserialize_ops,
-    });
+// Parse "<min>-<max>" or a single "<len>" into a non-empty range of at least one character
+fn parse_length_range(value: &str) -> Option<RangeInclusive<usize>> {
+    let (min, max) = value.trim().split_once('-').unwrap_or((value.trim(), value.trim()));
+    let (min, max): (usize, usize) = (min.trim().parse().ok()?, max.trim().parse().ok()?);
+    (min >= 1 && min <= max).then_some(min..=max)
 }
 
-// Print results as a formatted table
-fn print_results_table() {
-    println!("\n{:-^80}", " Serialization Benchmark Results ");
-    
-    // Print table header
-    println!("{:<12} | {:<20} | {:<20} | {:<20} | {:<20}", 
-             "Format", 
-             "Serialization Time (ns)", 
-             "Serialization Ops/sec",
-             "Deserialization Time (ns)",
-             "Deserialization Ops/sec");
-    
-    println!("{:-<12}-+-{:-<20}-+-{:-<20}-+-{:-<20}-+-{:-<20}", 
-             "", "", "", "", "");
-    
-    // Print table rows
-    let results = BENCHMARK_RESULTS.lock().unwrap();
-    
-    // Sort formats alphabetically for consistent output
-    let mut formats: Vec<&String> = results.keys().collect();
-    formats.sort();
-    
-    for format in formats {
-        if let Some(result) = results.get(format) {
-            println!("{:<12} | {:<20.2} | {:<20} | {:<20.2} | {:<20}", 
-                     format,
-                     result.serialize_time_ns,
-                     format!("{} ops/sec", result.serialize_ops_per_sec),
-                     result.deserialize_time_ns,
-                     format!("{} ops/sec", result.deserialize_ops_per_sec));
+static GENERATOR: Lazy<PayloadGenerator> = Lazy::new(PayloadGenerator::from_env);
+
+// The `TestData` the single-value benchmarks run on
+static STANDARD_TEST_DATA: Lazy<TestData> = Lazy::new(|| match GENERATOR.seed {
+    Some(seed) => {
+        let data = GENERATOR.random_records(seed, 1).remove(0);
+        println!("Using random TestData from BENCH_SEED {}: {:?}", seed, data);
+        data
+    }
+    None => TestData::new(),
+});
+
+fn standard_test_data() -> TestData {
+    STANDARD_TEST_DATA.clone()
+}
+
+// The fixture payload: BENCH_FIXTURE when that names a loadable file, the
+// bundled `DEFAULT_FIXTURE_PATH` otherwise
+static FIXTURE: Lazy<FixtureData> = Lazy::new(|| {
+    if let Ok(path) = std::env::var("BENCH_FIXTURE") {
+        match FixtureData::load(&path) {
+            Ok(fixture) => {
+                println!("Using fixture {} ({} users)", path, fixture.users.len());
+                return fixture;
+            }
+            Err(err) => eprintln!("Warning: could not load BENCH_FIXTURE '{}': {}, using {}", path, err, DEFAULT_FIXTURE_PATH),
         }
     }
-    
-    println!("{:-^80}", "");
+    FixtureData::load(DEFAULT_FIXTURE_PATH)
+        .unwrap_or_else(|err| panic!("could not load {}: {}", DEFAULT_FIXTURE_PATH, err))
+});
+
+fn fixture_data() -> FixtureData {
+    FIXTURE.clone()
 }
 
-// Bincode
-fn benchmark_bincode(c: &mut Criterion) {
-    let test_data = TestData::new();
-    let mut tracker = MemoryTracker::new();
+// Read BENCH_BATCH_SIZE, falling back to `DEFAULT_BATCH_SIZE` when unset or invalid
+fn batch_size() -> usize {
+    match std::env::var("BENCH_BATCH_SIZE") {
+        Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+            eprintln!("Warning: invalid BENCH_BATCH_SIZE '{}', using {}", value, DEFAULT_BATCH_SIZE);
+            DEFAULT_BATCH_SIZE
+        }),
+        Err(_) => DEFAULT_BATCH_SIZE,
+    }
+}
 
-    tracker.log_initial();
-    let mut group = c.benchmark_group("bincode");
-    
-    group.bench_function("serialize", |b| {
-        b.iter(|| serialize(&black_box(&test_data)).unwrap())
-    });
-    tracker.log_after_serialize();
-    
-    let serialized_data = serialize(&test_data).unwrap();
-    
-    group.bench_function("deserialize", |b| {
-        b.iter(|| deserialize::<TestData>(&black_box(&serialized_data)).unwrap())
-    });
-    tracker.log_after_deserialize();
-    
-    group.finish();
-    
-    tracker.print_summary("Bincode");
+// Read BENCH_ITERS, falling back to `DEFAULT_SIMPLE_ITERS` when unset, invalid or zero
+fn simple_iters() -> usize {
+    match std::env::var("BENCH_ITERS") {
+        Ok(value) => value.trim().parse().ok().filter(|iters: &usize| *iters > 0).unwrap_or_else(|| {
+            eprintln!("Warning: invalid BENCH_ITERS '{}', using {}", value, DEFAULT_SIMPLE_ITERS);
+            DEFAULT_SIMPLE_ITERS
+        }),
+        Err(_) => DEFAULT_SIMPLE_ITERS,
+    }
 }
 
-// BCS
-fn benchmark_bcs(c: &mut Criterion) {
-    let test_data = TestData::new();
-    let mut tracker = MemoryTracker::new();
+// Read SERIALIZER_REGRESSION_THRESHOLD, falling back to `DEFAULT_REGRESSION_THRESHOLD`
+// when unset or invalid
+fn regression_threshold() -> f64 {
+    match std::env::var("SERIALIZER_REGRESSION_THRESHOLD") {
+        Ok(value) => value.trim().parse().ok().filter(|threshold: &f64| *threshold >= 0.0).unwrap_or_else(|| {
+            eprintln!("Warning: invalid SERIALIZER_REGRESSION_THRESHOLD '{}', using {}", value, DEFAULT_REGRESSION_THRESHOLD);
+            DEFAULT_REGRESSION_THRESHOLD
+        }),
+        Err(_) => DEFAULT_REGRESSION_THRESHOLD,
+    }
+}
 
-    tracker.log_initial();
-    let mut group = c.benchmark_group("bcs");
-    
-    group.bench_function("serialize", |b| {
-        b.iter(|| to_bytes(&black_box(&test_data)).unwrap())
-    });
-    tracker.log_after_serialize();
-    
-    let serialized_data = to_bytes(&test_data).unwrap();
-    
-    group.bench_function("deserialize", |b| {
-        b.iter(|| from_bytes::<TestData>(&black_box(&serialized_data)).unwrap())
-    });
-    tracker.log_after_deserialize();
-    
-    group.finish();
-    
-    tracker.print_summary("BCS");
+// Pin the benchmarking thread to the core index in BENCH_PIN_CORE, if set, so
+// the scheduler can't migrate it between samples
+fn pin_to_core() {
+    let value = match std::env::var("BENCH_PIN_CORE") {
+        Ok(value) => value,
+        Err(_) => return,
+    };
+    let index: usize = match value.trim().parse() {
+        Ok(index) => index,
+        Err(_) => {
+            eprintln!("Warning: invalid BENCH_PIN_CORE '{}', not pinning", value);
+            return;
+        }
+    };
+
+    let core = core_affinity::get_core_ids()
+        .and_then(|cores| cores.into_iter().find(|core| core.id == index));
+    match core {
+        Some(core) if core_affinity::set_for_current(core) => println!("Pinned benchmark thread to core {}", index),
+        Some(_) => eprintln!("Warning: could not pin the benchmark thread to core {}", index),
+        None => eprintln!("Warning: BENCH_PIN_CORE {} is not an available core, not pinning", index),
+    }
 }
 
-// Protobuf
-fn benchmark_protobuf(c: &mut Criterion) {
-    let test_data = proto::TestData {
-        id: 1,
-        name: "Rust".to_string(),
-        active: true,
-        ..Default::default()
+// A batch of `TestData` for the throughput benchmarks: random records when
+// BENCH_SEED is set, otherwise copies of the standard payload with distinct ids
+fn test_data_batch() -> Vec<TestData> {
+    match GENERATOR.seed {
+        Some(seed) => GENERATOR.random_records(seed, batch_size()),
+        None => (0..batch_size())
+            .map(|index| TestData { id: index as u32, ..standard_test_data() })
+            .collect(),
+    }
+}
+
+// The "medium" and "large" payloads, with longer names than `TestData::new()`
+fn sized_payloads() -> Vec<(&'static str, Vec<TestData>)> {
+    let records = |count: usize| -> Vec<TestData> {
+        (0..count)
+            .map(|index| TestData { id: index as u32, name: format!("record-{:05}", index), active: index % 2 == 0 })
+            .collect()
     };
-    let mut tracker = MemoryTracker::new();
+    vec![
+        ("medium", records(MEDIUM_PAYLOAD_RECORDS)),
+        ("large", records(LARGE_PAYLOAD_RECORDS)),
+    ]
+}
 
-    tracker.log_initial();
-    let mut group = c.benchmark_group("protobuf");
-    
-    group.bench_function("serialize", |b| {
-        b.iter(|| test_data.write_to_bytes().unwrap())
-    });
-    
-    tracker.log_after_serialize();
-    let serialized_data = test_data.write_to_bytes().unwrap();
-    
-    group.bench_function("deserialize", |b| {
-        b.iter(|| proto::TestData::parse_from_bytes(&black_box(&serialized_data)).unwrap())
-    });
-    tracker.log_after_deserialize();
-    
-    group.finish();
-    
-    tracker.print_summary("Protobuf");
+// One `Vec<TestData>` per entry of `COLLECTION_LENGTHS`, with the same records as `sized_payloads`
+fn collection_payloads() -> Vec<(&'static str, Vec<TestData>)> {
+    COLLECTION_LENGTHS.iter()
+        .map(|(length, count)| {
+            let records = (0..*count)
+                .map(|index| TestData { id: index as u32, name: format!("record-{:05}", index), active: index % 2 == 0 })
+                .collect();
+            (*length, records)
+        })
+        .collect()
 }
 
-// Serde JSON
-fn benchmark_serde_json(c: &mut Criterion) {
-    let test_data = TestData::new();
-    let mut tracker = MemoryTracker::new();
+// One `BlobData` per entry of `BLOB_SIZES`
+fn blob_payloads() -> Vec<(&'static str, BlobData)> {
+    BLOB_SIZES.iter()
+        .map(|(size, len)| (*size, BlobData::sample(*len)))
+        .collect()
+}
 
-    tracker.log_initial();
-    let mut group = c.benchmark_group("serde_json");
-    
-    group.bench_function("serialize", |b| {
-        b.iter(|| to_string(&black_box(&test_data)).unwrap())
-    });
-    tracker.log_after_serialize();
-    
-    let serialized_data = to_string(&test_data).unwrap();
-    
-    group.bench_function("deserialize" , |b| {
-        b.iter(|| from_str::<TestData>(&black_box(&serialized_data)).unwrap())
-    });
-    tracker.log_after_deserialize();
-    
-    group.finish();
-    
-    tracker.print_summary("Serde JSON");
+// One `SparseData` per entry of `SPARSE_VARIANTS`
+fn sparse_payloads() -> Vec<(&'static str, SparseData)> {
+    vec![
+        ("sparse", SparseData::sparse()),
+        ("full", SparseData::full()),
+    ]
 }
 
-// Borsh
-fn benchmark_borsh(c: &mut Criterion) {
-    let test_data = TestData::new();
-    let mut tracker = MemoryTracker::new();
+fn parse_quick_protobuf(bytes: &[u8]) -> quick_proto::TestData<'_> {
+    quick_proto::TestData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap()
+}
 
-    tracker.log_initial();
-    let mut group = c.benchmark_group("borsh");
-    
-    group.bench_function("serialize", |b| {
-        b.iter(|| borsh::to_vec(&black_box(&test_data)).unwrap())
-    });
-    
-    tracker.log_after_serialize();
-    let serialized_data = borsh::to_vec(&test_data).unwrap();
-    
-    group.bench_function("deserialize", |b| {
-        b.iter(|| TestData::try_from_slice(&black_box(&serialized_data)).unwrap())
-    });
-    
-    group.finish();
-    tracker.log_after_deserialize();
-    
-    tracker.print_summary("Borsh");
+fn serialize_capnp_packed(message: &capnp::message::Builder<capnp::message::HeapAllocator>) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    capnp::serialize_packed::write_message(&mut buffer, message).unwrap();
+    buffer
 }
 
-// Group all benchmarks
-fn criterion_benchmark(c: &mut Criterion) {
-    GLOBAL.reset();
-    
-    benchmark_bincode(c);
-    benchmark_bcs(c);
-    benchmark_protobuf(c);
-    benchmark_serde_json(c);
-    benchmark_borsh(c);
+fn build_flatbuffer_batch(batch: &[TestData]) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::with_capacity(batch.len() * 32);
+    let items: Vec<_> = batch.iter()
+        .map(|test_data| {
+            let name = builder.create_string(&test_data.name);
+            fbs::TestData::create(&mut builder, &fbs::TestDataArgs {
+                id: test_data.id,
+                name: Some(name),
+                active: test_data.active,
+            })
+        })
+        .collect();
+    let items = builder.create_vector(&items);
+    let root = fbs::TestDataBatch::create(&mut builder, &fbs::TestDataBatchArgs { items: Some(items) });
+    builder.finish(root, None);
+    builder.finished_data().to_vec()
+}
 
-    // After criterion runs, we can parse the json files.
-    analyze_criterion_results("bincode");
-    analyze_criterion_results("bcs");
-    analyze_criterion_results("protobuf");
-    analyze_criterion_results("serde_json");
-    analyze_criterion_results("borsh");
+// Cap'n Proto `BlobData` message, unpacked
+fn serialize_capnp_blob(blob: &BlobData) -> Vec<u8> {
+    let mut message = capnp::message::Builder::new_default();
+    let mut root = message.init_root::<message_capnp::blob_data::Builder>();
+    root.set_id(blob.id);
+    root.set_payload(blob.payload.as_slice());
+    serialize_capnp(&message)
+}
 
-    // Print the formatted table after all benchmarks are run
-    print_results_table();
+fn build_flatbuffer_blob(blob: &BlobData) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::with_capacity(blob.payload.len() + 32);
+    let payload = builder.create_vector(&blob.payload);
+    let root = fbs::BlobData::create(&mut builder, &fbs::BlobDataArgs { id: blob.id, payload: Some(payload) });
+    builder.finish(root, None);
+    builder.finished_data().to_vec()
 }
-use serde_json::Value;
-use std::fs;
-use std::fs::File;
-use std::io::BufReader;
-use std::path::Path;
 
-fn analyze_criterion_results(group_name: &str) {
-    let group_path = format!("target/criterion/{}", group_name);
-    let group_dir = Path::new(&group_path);
+fn capnp_command(command: Command) -> message_capnp::Command {
+    match command {
+        Command::Start => message_capnp::Command::Start,
+        Command::Stop => message_capnp::Command::Stop,
+        Command::Pause => message_capnp::Command::Pause,
+        Command::Resume => message_capnp::Command::Resume,
+        Command::Restart => message_capnp::Command::Restart,
+    }
+}
 
-    if !group_dir.exists() || !group_dir.is_dir() {
-        println!("Group directory not found.");
-        return;
+// Cap'n Proto `EnumTestData` message, the status as an unnamed union
+fn serialize_capnp_enum_data(data: &EnumTestData) -> Vec<u8> {
+    let mut message = capnp::message::Builder::new_default();
+    let mut root = message.init_root::<message_capnp::enum_test_data::Builder>();
+    root.set_id(data.id);
+    let mut status = root.reborrow().init_status();
+    match &data.status {
+        Status::Pending => status.set_pending(()),
+        Status::Active(since) => status.set_active(*since),
+        Status::Suspended(days, note) => {
+            let mut suspended = status.init_suspended();
+            suspended.set_days(*days);
+            suspended.set_note(note.as_str());
+        }
+        Status::Closed { reason, at } => {
+            let mut closed = status.init_closed();
+            closed.set_reason(reason.as_str());
+            closed.set_at(*at);
+        }
+        Status::Archived => status.set_archived(()),
+    }
+    let mut commands = root.init_commands(data.commands.len() as u32);
+    for (index, &command) in data.commands.iter().enumerate() {
+        commands.set(index as u32, capnp_command(command));
     }
+    serialize_capnp(&message)
+}
 
-    let mut total_serialize_ns = 0.0;
-    let mut total_deserialize_ns = 0.0;
-
-    if let Ok(entries) = fs::read_dir(group_dir) {
-        for entry in entries.flatten() {
-            let bench_path = entry.path();
-            if bench_path.is_dir() {
-                let estimates_path = bench_path.join("base/estimates.json");
-                if estimates_path.exists() {
-                    if let Ok(file) = File::open(estimates_path) {
-                        let reader = BufReader::new(file);
-                        if let Ok(json) = serde_json::from_reader::<_, Value>(reader) {
-                            if bench_path.file_name().unwrap().to_str().unwrap() == "serialize" {
-                                if let Some(slope) = json["slope"]["point_estimate"].as_f64() {
-                                    total_serialize_ns += slope;
-                                }
-                            } else if bench_path.file_name().unwrap().to_str().unwrap() == "deserialize" {
-                                if let Some(slope) = json["slope"]["point_estimate"].as_f64() {
-                                    total_deserialize_ns += slope;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+fn enum_data_from_capnp(data: message_capnp::enum_test_data::Reader) -> capnp::Result<EnumTestData> {
+    let status = match data.get_status()?.which()? {
+        message_capnp::status::Pending(()) => Status::Pending,
+        message_capnp::status::Active(since) => Status::Active(since),
+        message_capnp::status::Suspended(suspended) => {
+            let suspended = suspended?;
+            Status::Suspended(suspended.get_days(), suspended.get_note()?.to_string()?)
+        }
+        message_capnp::status::Closed(closed) => {
+            let closed = closed?;
+            Status::Closed { reason: closed.get_reason()?.to_string()?, at: closed.get_at() }
+        }
+        message_capnp::status::Archived(()) => Status::Archived,
+    };
+    let commands = data.get_commands()?.iter()
+        .map(|command| {
+            Ok(match command? {
+                message_capnp::Command::Start => Command::Start,
+                message_capnp::Command::Stop => Command::Stop,
+                message_capnp::Command::Pause => Command::Pause,
+                message_capnp::Command::Resume => Command::Resume,
+                message_capnp::Command::Restart => Command::Restart,
+            })
+        })
+        .collect::<capnp::Result<_>>()?;
+    Ok(EnumTestData { id: data.get_id(), status, commands })
+}
+
+// FlatBuffers `EnumTestData`, the status as a union and the commands as a
+// `[Command]` vector of bytes
+fn build_flatbuffer_enum_data(data: &EnumTestData) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::with_capacity(256);
+    let (status_type, status) = match &data.status {
+        Status::Pending => (fbs::Status::Pending, fbs::Pending::create(&mut builder, &fbs::PendingArgs {}).as_union_value()),
+        Status::Active(since) => (fbs::Status::Active, fbs::Active::create(&mut builder, &fbs::ActiveArgs { since: *since }).as_union_value()),
+        Status::Suspended(days, note) => {
+            let note = builder.create_string(note);
+            (fbs::Status::Suspended, fbs::Suspended::create(&mut builder, &fbs::SuspendedArgs { days: *days, note: Some(note) }).as_union_value())
+        }
+        Status::Closed { reason, at } => {
+            let reason = builder.create_string(reason);
+            (fbs::Status::Closed, fbs::Closed::create(&mut builder, &fbs::ClosedArgs { reason: Some(reason), at: *at }).as_union_value())
+        }
+        Status::Archived => (fbs::Status::Archived, fbs::Archived::create(&mut builder, &fbs::ArchivedArgs {}).as_union_value()),
+    };
+    let commands: Vec<fbs::Command> = data.commands.iter().map(|&command| fbs::Command(command as u8)).collect();
+    let commands = builder.create_vector(&commands);
+    let root = fbs::EnumTestData::create(&mut builder, &fbs::EnumTestDataArgs {
+        id: data.id,
+        status_type,
+        status: Some(status),
+        commands: Some(commands),
+    });
+    builder.finish(root, None);
+    builder.finished_data().to_vec()
+}
+
+// `None` on a missing status or an out-of-range command
+fn enum_data_from_flatbuffer(data: fbs::EnumTestData) -> Option<EnumTestData> {
+    let status = match data.status_type() {
+        fbs::Status::Pending => Status::Pending,
+        fbs::Status::Active => Status::Active(data.status_as_active()?.since()),
+        fbs::Status::Suspended => {
+            let suspended = data.status_as_suspended()?;
+            Status::Suspended(suspended.days(), suspended.note()?.to_string())
+        }
+        fbs::Status::Closed => {
+            let closed = data.status_as_closed()?;
+            Status::Closed { reason: closed.reason()?.to_string(), at: closed.at() }
         }
+        fbs::Status::Archived => Status::Archived,
+        _ => return None,
+    };
+    let commands = data.commands()?.iter()
+        .map(|command| match command {
+            fbs::Command::Start => Some(Command::Start),
+            fbs::Command::Stop => Some(Command::Stop),
+            fbs::Command::Pause => Some(Command::Pause),
+            fbs::Command::Resume => Some(Command::Resume),
+            fbs::Command::Restart => Some(Command::Restart),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    Some(EnumTestData { id: data.id(), status, commands })
+}
+
+// Cap'n Proto `MapTestData` message, the attributes as a list of key/value structs
+fn serialize_capnp_map_test_data(data: &MapTestData) -> Vec<u8> {
+    let mut message = capnp::message::Builder::new_default();
+    let mut root = message.init_root::<message_capnp::map_test_data::Builder>();
+    root.set_id(data.id);
+    let mut attributes = root.init_attributes(data.attributes.len() as u32);
+    for (index, (key, value)) in data.attributes.iter().enumerate() {
+        let mut attribute = attributes.reborrow().get(index as u32);
+        attribute.set_key(key.as_str());
+        attribute.set_value(value.as_str());
     }
+    serialize_capnp(&message)
+}
 
-    let total_serialize_ops = (1_000_000_000.0 / total_serialize_ns) as u64;
-    let total_deserialize_ops = (1_000_000_000.0 / total_deserialize_ns) as u64;
+fn map_test_data_from_capnp(data: message_capnp::map_test_data::Reader) -> capnp::Result<MapTestData> {
+    let attributes = data.get_attributes()?.iter()
+        .map(|attribute| Ok((attribute.get_key()?.to_string()?, attribute.get_value()?.to_string()?)))
+        .collect::<capnp::Result<_>>()?;
+    Ok(MapTestData { id: data.get_id(), attributes })
+}
 
-    store_results(group_name, total_serialize_ns, total_deserialize_ns);
-    println!("Total estimated serialize time of group '{}': {:.3} ns", group_name, total_serialize_ns);
-    println!("Total estimated deserialize time of group '{}': {:.3} ns", group_name, total_deserialize_ns);
-    println!("Total estimated serialize ops of group '{}': {:.3} ops/sec", group_name, total_serialize_ops);
+// FlatBuffers `MapTestData`, the attributes as a vector of key/value tables
+fn build_flatbuffer_map_test_data(data: &MapTestData) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::with_capacity(1024);
+    let attributes: Vec<_> = data.attributes.iter()
+        .map(|(key, value)| {
+            let key = builder.create_string(key);
+            let value = builder.create_string(value);
+            fbs::Attribute::create(&mut builder, &fbs::AttributeArgs { key: Some(key), value: Some(value) })
+        })
+        .collect();
+    let attributes = builder.create_vector(&attributes);
+    let root = fbs::MapTestData::create(&mut builder, &fbs::MapTestDataArgs { id: data.id, attributes: Some(attributes) });
+    builder.finish(root, None);
+    builder.finished_data().to_vec()
+}
+
+// `None` on a missing attribute list, key or value
+fn map_test_data_from_flatbuffer(data: fbs::MapTestData) -> Option<MapTestData> {
+    let attributes = data.attributes()?.iter()
+        .map(|attribute| Some((attribute.key()?.to_string(), attribute.value()?.to_string())))
+        .collect::<Option<_>>()?;
+    Some(MapTestData { id: data.id(), attributes })
+}
+
+// Schema for a `Vec<TestData>`: an Avro array of `AVRO_SCHEMA` records
+fn avro_batch_schema() -> apache_avro::Schema {
+    apache_avro::Schema::array(avro_schema())
+}
+
+fn avro_array(schema: &apache_avro::Schema, records: &[TestData]) -> apache_avro::types::Value {
+    apache_avro::types::Value::Array(records.iter().map(|test_data| avro_record(schema, test_data)).collect())
+}
+
+// Parse BENCH_FORMATS (comma-separated, e.g. `bincode,borsh`) into the formats to run.
+// SERIALIZER_FORMATS is accepted as an alias; a missing or empty list runs everything
+fn selected_formats() -> Vec<FormatEntry> {
+    let requested = ["BENCH_FORMATS", "SERIALIZER_FORMATS"].iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.trim().is_empty());
+    let requested = match requested {
+        Some(value) => value,
+        None => return FORMATS.to_vec(),
+    };
+
+    let mut selected = Vec::new();
+    for name in requested.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        match FORMATS.iter().find(|(format, _, _)| *format == name) {
+            Some(entry) => selected.push(*entry),
+            None => {
+                let valid: Vec<&str> = FORMATS.iter().map(|(format, _, _)| *format).collect();
+                eprintln!("Warning: unknown format '{}' in the format filter, valid formats are: {}", name, valid.join(", "));
+            }
+        }
+    }
+    selected
+}
+
+// Serialized bytes of the standard payload for every format
+fn serialized_payloads() -> Vec<(&'static str, Vec<u8>)> {
+    let test_data = standard_test_data();
+    vec![
+        ("bincode", serialize(&test_data).unwrap()),
+        ("bitcode", bitcode::serialize(&test_data).unwrap()),
+        ("bcs", to_bytes(&test_data).unwrap()),
+        ("protobuf", proto_test_data(&test_data).write_to_bytes().unwrap()),
+        ("quick_protobuf", serialize_quick_protobuf(&quick_proto_test_data(&test_data))),
+        ("prost", serialize_prost(&prost_test_data(&test_data))),
+        ("capnp", serialize_capnp(&build_capnp_message(&test_data))),
+        ("serde_json", to_string(&test_data).unwrap().into_bytes()),
+        #[cfg(target_arch = "x86_64")]
+        ("simd_json", simd_json::serde::to_string(&test_data).unwrap().into_bytes()),
+        ("sonic_rs", sonic_rs::to_string(&test_data).unwrap().into_bytes()),
+        ("serde_yaml", serde_yaml::to_string(&test_data).unwrap().into_bytes()),
+        ("ron", ron::to_string(&test_data).unwrap().into_bytes()),
+        ("toml", toml::to_string(&test_data).unwrap().into_bytes()),
+        ("xml", quick_xml::se::to_string(&test_data).unwrap().into_bytes()),
+        ("borsh", borsh::to_vec(&test_data).unwrap()),
+        ("musli_storage", musli::storage::to_vec(&test_data).unwrap()),
+        ("musli_wire", musli::wire::to_vec(&test_data).unwrap()),
+        ("nanoserde_json", nano_test_data(&test_data).serialize_json().into_bytes()),
+        ("nanoserde_bin", nano_test_data(&test_data).serialize_bin()),
+        ("xdr", serde_xdr::to_bytes(&test_data).unwrap()),
+        ("savefile", savefile::save_to_mem(SAVEFILE_VERSION, &test_data).unwrap()),
+        ("pickle", serde_pickle::to_vec(&test_data, SerOptions::new()).unwrap()),
+        ("der", rasn::der::encode(&der_test_data(&test_data)).unwrap()),
+        ("alkahest", serialize_alkahest(&test_data)),
+        ("speedy", test_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()),
+        ("avro", {
+            let schema = avro_schema();
+            apache_avro::to_avro_datum(&schema, avro_record(&schema, &test_data)).unwrap()
+        }),
+        ("flexbuffers", flexbuffers::to_vec(&test_data).unwrap()),
+        ("flatbuffers", {
+            let (buffer, head) = build_flatbuffer(&test_data);
+            buffer[head..].to_vec()
+        }),
+    ]
+}
+
+// Serialized size of the standard payload for every format, encoded once on
+// first use; the tables and reports read sizes from here
+fn standard_payload_sizes() -> &'static HashMap<&'static str, usize> {
+    static SIZES: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+    SIZES.get_or_init(|| serialized_payloads().into_iter().map(|(format, bytes)| (format, bytes.len())).collect())
+}
+
+// Compare the size each format predicts for the standard payload without
+// encoding it, as used to pre-size buffers, against the encoded length; a
+// wrong hint means a reallocation or wasted capacity. Only formats in
+// `groups` with a size API are listed
+fn report_size_hints(groups: &[&str]) {
+    let test_data = standard_test_data();
+    let hints: Vec<(&str, &str, usize)> = vec![
+        ("bincode", "bincode::serialized_size", bincode::serialized_size(&test_data).unwrap() as usize),
+        ("borsh", "borsh::object_length", borsh::object_length(&test_data).unwrap()),
+        ("speedy", "Writable::bytes_needed", <TestData as Writable<Endianness>>::bytes_needed(&test_data).unwrap()),
+        ("protobuf", "Message::compute_size", proto_test_data(&test_data).compute_size() as usize),
+        ("quick_protobuf", "MessageWrite::get_size", quick_proto_test_data(&test_data).get_size()),
+        ("prost", "Message::encoded_len", prost_test_data(&test_data).encoded_len()),
+    ];
+    let hints: Vec<(&str, &str, usize)> = hints.into_iter()
+        .filter(|(format, _, _)| groups.contains(format))
+        .collect();
+    if hints.is_empty() {
+        return;
+    }
+
+    let actual = standard_payload_sizes();
+
+    println!("\n{:-^80}", " Size hints (small payload) ");
+    println!("{:<16} | {:<26} | {:<10} | {:<10} | {:<6}", "Format", "Hint", "Predicted", "Actual", "Match");
+    println!("{:-<16}-+-{:-<26}-+-{:-<10}-+-{:-<10}-+-{:-<6}", "", "", "", "", "");
+    for (format, hint, predicted) in &hints {
+        let actual = actual[format];
+        let matches = if *predicted == actual { "yes" } else { "NO" };
+        println!("{:<16} | {:<26} | {:<10} | {:<10} | {:<6}", format, hint, predicted, actual, matches);
+    }
+    println!("{:-^80}", "");
+    for (format, hint, predicted) in &hints {
+        if *predicted != actual[format] {
+            eprintln!("Warning: {} predicts {} bytes for {} but encodes {}", hint, predicted, format, actual[format]);
+        }
+    }
+}
+
+// Store benchmark results
+fn store_results(format: &str, size: &'static str, serialize_ns: f64, serialize_std_dev: Option<f64>, deserialize_ns: f64, deserialize_std_dev: Option<f64>) {
+    let serialize_ops = (1_000_000_000.0 / serialize_ns) as u64;
+    let deserialize_ops = (1_000_000_000.0 / deserialize_ns) as u64;
+    
+    let payload_bytes = payload_bytes(format, size);
+    let mb_per_sec = |time_ns: f64| (payload_bytes > 0).then(|| payload_bytes as f64 * 1_000.0 / time_ns);
+
+    let mut results = BENCHMARK_RESULTS.lock().unwrap();
+    results.insert((format.to_string(), size), BenchmarkResults {
+        serialize_time_ns: serialize_ns,
+        serialize_std_dev_ns: serialize_std_dev,
+        serialize_ops_per_sec: serialize_ops,
+        deserialize_time_ns: deserialize_ns,
+        deserialize_std_dev_ns: deserialize_std_dev,
+        deserialize_ops_per_sec: deserialize_ops,
+        payload_bytes,
+        serialize_cpu_ns: None,
+        deserialize_cpu_ns: None,
+        serialize_mb_per_sec: mb_per_sec(serialize_ns),
+        deserialize_mb_per_sec: mb_per_sec(deserialize_ns),
+    });
+}
+
+// Add CPU times to a result `store_results` already stored
+fn store_cpu_times(format: &str, size: &'static str, serialize_cpu_ns: Option<f64>, deserialize_cpu_ns: Option<f64>) {
+    if let Some(result) = BENCHMARK_RESULTS.lock().unwrap().get_mut(&(format.to_string(), size)) {
+        result.serialize_cpu_ns = serialize_cpu_ns;
+        result.deserialize_cpu_ns = deserialize_cpu_ns;
+    }
+}
+
+// Serialized size of `format`'s payload at `size`, as recorded while
+// benchmarking; the standard small payload's comes from
+// `standard_payload_sizes`, since its groups do not record one. 0 if neither applies
+fn payload_bytes(format: &str, size: &str) -> usize {
+    let key = if size == "small" { format.to_string() } else { format!("{}_sizes/{}", format, size) };
+    if let Some(bytes) = PAYLOAD_SIZES.lock().unwrap().get(&key) {
+        return *bytes;
+    }
+    if size != "small" {
+        return 0;
+    }
+    standard_payload_sizes().get(format).copied().unwrap_or(0)
+}
+
+// Sizes of the other encodings a format offers for the standard payload,
+// shown next to its default one in the small table
+fn alternate_encodings(format: &str) -> Vec<(&'static str, usize)> {
+    let test_data = standard_test_data();
+    match format {
+        "serde_json" => vec![("pretty", serde_json::to_string_pretty(&test_data).unwrap().len())],
+        "ron" => vec![("pretty", ron::ser::to_string_pretty(&test_data, ron::ser::PrettyConfig::default()).unwrap().len())],
+        _ => vec![],
+    }
+}
+
+// Print one results table per payload size, limited to the groups that ran
+fn print_results_table(groups: &[&str]) {
+    println!("\nPayload: {}", GENERATOR.describe());
+
+    let results = BENCHMARK_RESULTS.lock().unwrap();
+    for size in PAYLOAD_SIZE_TIERS {
+        if results.keys().any(|(format, result_size)| *result_size == size && groups.contains(&format.as_str())) {
+            print_size_table(&results, groups, size);
+        }
+    }
+
+    // RON is often assumed to be wordier than JSON, so print the measured sizes
+    if groups.contains(&"ron") {
+        let test_data = standard_test_data();
+        println!("Note: RON encodes TestData in {} bytes vs serde_json's {} (RON leaves field names unquoted and omits the struct name)",
+                 ron::to_string(&test_data).unwrap().len(),
+                 to_string(&test_data).unwrap().len());
+    }
+
+    // Spell out YAML's cost against JSON, for anyone considering it on a hot path
+    if groups.contains(&"serde_yaml") && groups.contains(&"serde_json") {
+        let yaml = results.get(&("serde_yaml".to_string(), "small"));
+        let json = results.get(&("serde_json".to_string(), "small"));
+        if let (Some(yaml), Some(json)) = (yaml, json) {
+            let test_data = standard_test_data();
+            println!("Note: serde_yaml serializes {:.1}x and deserializes {:.1}x slower than serde_json, and encodes TestData in {} bytes vs {}",
+                     yaml.serialize_time_ns / json.serialize_time_ns,
+                     yaml.deserialize_time_ns / json.deserialize_time_ns,
+                     serde_yaml::to_string(&test_data).unwrap().len(),
+                     to_string(&test_data).unwrap().len());
+        }
+    }
+}
+
+// The rows of one results table, shared by the console and Markdown renderers
+struct ResultsTable {
+    size: &'static str,
+    // Indices into `RESULT_COLUMNS` of the columns shown
+    columns: Vec<usize>,
+    rows: Vec<[String; 13]>,
+    // Rows of `JSON_VARIANTS`, shown after the others
+    json_rows: Vec<[String; 13]>,
+    // The score weights, if any row was scored
+    score_weights: Option<[f64; 3]>,
+    // Whether any row is flagged by `loses_float_precision`
+    lossy_floats: bool,
+}
+
+fn results_table(results: &ResultsMap, groups: &[&str], size: &'static str) -> ResultsTable {
+    // Mark the fastest serializer and deserializer on the standard payload
+    let ranked = ranking_categories(results, &standard_payload_groups(groups), size);
+    let fastest = |category: usize| ranked[category].entries.first().map(|(format, _)| format.clone());
+    let marks = (fastest(0), fastest(1));
+
+    let weights = score_weights();
+    let scores = weighted_scores(results, &standard_payload_groups(groups), size, weights);
+
+    // Sort formats alphabetically for consistent output, or best score first
+    // with BENCH_SORT=score; the JSON variants follow under their own heading,
+    // in `JSON_VARIANTS` order
+    let mut formats: Vec<&str> = results.keys()
+        .filter(|(_, result_size)| *result_size == size)
+        .map(|(format, _)| format.as_str())
+        .filter(|format| groups.contains(format) && !JSON_VARIANTS.contains(format))
+        .collect();
+    formats.sort();
+    if std::env::var("BENCH_SORT").is_ok_and(|sort| sort.trim() == "score") {
+        // Stable, so unscored rows stay alphabetical at the end
+        formats.sort_by(|a, b| {
+            let score = |format: &&str| scores.get(*format).copied().unwrap_or(f64::NEG_INFINITY);
+            score(b).total_cmp(&score(a))
+        });
+    }
+
+    let json_variants: Vec<&str> = JSON_VARIANTS.iter()
+        .copied()
+        .filter(|variant| groups.contains(variant) && results.contains_key(&(variant.to_string(), size)))
+        .collect();
+
+    let rows = |formats: &[&str]| -> Vec<[String; 13]> {
+        formats.iter()
+            .map(|format| result_row_cells(format, size, &results[&(format.to_string(), size)], &marks, scores.get(*format).copied()))
+            .collect()
+    };
+
+    let has_cpu_times = formats.iter().chain(&json_variants)
+        .any(|format| results[&(format.to_string(), size)].serialize_cpu_ns.is_some());
+    let columns: Vec<usize> = (0..RESULT_COLUMNS.len())
+        .filter(|column| has_cpu_times || !CPU_COLUMNS.contains(column))
+        .collect();
+
+    ResultsTable {
+        size,
+        columns,
+        rows: rows(&formats),
+        json_rows: rows(&json_variants),
+        score_weights: (!scores.is_empty()).then_some(weights),
+        lossy_floats: formats.iter().chain(&json_variants).any(|format| loses_float_precision(format)),
+    }
+}
+
+fn results_table_title(size: &str) -> String {
+    format!("Serialization Benchmark Results ({}, {} allocator)", size, ALLOCATOR)
+}
+
+fn print_size_table(results: &ResultsMap, groups: &[&str], size: &'static str) {
+    let table = results_table(results, groups, size);
+
+    // Each column is as wide as its widest cell or header, so long format
+    // names and microsecond-scale timings still line up
+    let header = RESULT_COLUMNS.map(String::from);
+    let widths: Vec<usize> = table.columns.iter()
+        .map(|column| std::iter::once(&header).chain(&table.rows).chain(&table.json_rows)
+            .map(|row| row[*column].chars().count())
+            .max()
+            .unwrap_or(0))
+        .collect();
+    let table_width = widths.iter().sum::<usize>() + 3 * (widths.len() - 1);
+    let print_row = |cells: &[String; 13]| {
+        let cells: Vec<String> = table.columns.iter().zip(&widths)
+            .map(|(column, width)| format!("{:<width$}", cells[*column], width = width))
+            .collect();
+        println!("{}", cells.join(" | "));
+    };
+
+    println!("\n{:-^width$}", format!(" {} ", results_table_title(table.size)), width = table_width);
+    print_row(&header);
+    println!("{}", widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>().join("-+-"));
+
+    for row in &table.rows {
+        print_row(row);
+    }
+    if !table.json_rows.is_empty() {
+        println!("{:-^width$}", " json_variants ", width = table_width);
+        for row in &table.json_rows {
+            print_row(row);
+        }
+    }
+
+    println!("{:-^width$}", "", width = table_width);
+    if let Some([serialize, deserialize, size]) = table.score_weights {
+        println!("Score: 100 x weighted mean of best/value for serialize time, deserialize time and size (weights {}, {}, {}; higher is better)",
+                 serialize, deserialize, size);
+    }
+    if table.lossy_floats {
+        println!("{}", LOSSY_FLOAT_NOTE);
+    }
+}
+
+// A results table as GitHub-flavored Markdown. Every column but the format is
+// numeric and right-aligned; the JSON variants follow the other rows
+fn markdown_size_table(table: &ResultsTable) -> String {
+    let markdown_row = |cells: &[String; 13]| -> String {
+        let cells: Vec<String> = table.columns.iter()
+            .map(|column| cells[*column].replace('*', "\\*").replace('|', "\\|"))
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut markdown = format!("### {}\n\n", results_table_title(table.size));
+    markdown.push_str(&markdown_row(&RESULT_COLUMNS.map(String::from)));
+    let separators: Vec<&str> = table.columns.iter()
+        .map(|column| if *column == 0 { "---" } else { "---:" })
+        .collect();
+    markdown.push_str(&format!("| {} |\n", separators.join(" | ")));
+    for row in table.rows.iter().chain(&table.json_rows) {
+        markdown.push_str(&markdown_row(row));
+    }
+    if let Some([serialize, deserialize, size]) = table.score_weights {
+        markdown.push_str(&format!("\nScore: 100 x weighted mean of best/value for serialize time, deserialize time and size (weights {}, {}, {}; higher is better)\n",
+                                   serialize, deserialize, size));
+    }
+    if table.lossy_floats {
+        markdown.push_str(&format!("\n{}\n", LOSSY_FLOAT_NOTE));
+    }
+    markdown
+}
+
+// Where the Markdown tables are written unless BENCH_MD_OUT overrides it
+const DEFAULT_MARKDOWN_PATH: &str = "target/benchmark_results/results.md";
+
+// Print the results tables of `groups` as Markdown, ready to paste into a PR
+// or the README, and write them to `path`, creating its parent directories
+fn write_results_markdown(groups: &[&str], path: &str) {
+    let results = BENCHMARK_RESULTS.lock().unwrap();
+    let tables: Vec<String> = PAYLOAD_SIZE_TIERS.into_iter()
+        .filter(|size| results.keys().any(|(format, result_size)| result_size == size && groups.contains(&format.as_str())))
+        .map(|size| markdown_size_table(&results_table(&results, groups, size)))
+        .collect();
+    let markdown = tables.join("\n");
+
+    println!("\n{}", markdown);
+    let written = match std::path::Path::new(path).parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|()| fs::write(path, &markdown)),
+        None => fs::write(path, &markdown),
+    };
+    match written {
+        Ok(()) => println!("Markdown results written to {}", path),
+        Err(err) => eprintln!("Warning: could not write Markdown results to {}: {}", path, err),
+    }
+}
+
+// Footnote under a results table with a row flagged by `loses_float_precision`
+const LOSSY_FLOAT_NOTE: &str = "† does not roundtrip every f64 bit for bit (see the float precision check in the README)";
+
+// The cells of one results table row; `marks` names the fastest serializer
+// and deserializer, whose times get a `*`, and formats that lose float
+// precision get a `†`
+fn result_row_cells(format: &str, size: &str, result: &BenchmarkResults, marks: &(Option<String>, Option<String>), score: Option<f64>) -> [String; 13] {
+    let time_cell = |time_ns: f64, winner: &Option<String>| {
+        let marker = if winner.as_deref() == Some(format) { " *" } else { "" };
+        format!("{:.2}{}", time_ns, marker)
+    };
+    let mut size_cell = match result.payload_bytes {
+        0 => "-".to_string(),
+        bytes => bytes.to_string(),
+    };
+    if size == "small" {
+        for (encoding, bytes) in alternate_encodings(format) {
+            size_cell.push_str(&format!(" ({}: {})", encoding, bytes));
+        }
+    }
+    let format_cell = if loses_float_precision(format) { format!("{} †", format) } else { format.to_string() };
+    [
+        format_cell,
+        time_cell(result.serialize_time_ns, &marks.0),
+        format_std_dev(result.serialize_std_dev_ns),
+        format!("{} ops/sec", result.serialize_ops_per_sec),
+        format_mb_per_sec(result.serialize_mb_per_sec),
+        format_cpu_time(result.serialize_cpu_ns),
+        time_cell(result.deserialize_time_ns, &marks.1),
+        format_std_dev(result.deserialize_std_dev_ns),
+        format!("{} ops/sec", result.deserialize_ops_per_sec),
+        format_mb_per_sec(result.deserialize_mb_per_sec),
+        format_cpu_time(result.deserialize_cpu_ns),
+        size_cell,
+        score.map_or_else(|| "-".to_string(), |score| format!("{:.1}", score)),
+    ]
+}
+
+// Read BENCH_SCORE_WEIGHTS as "<serialize>,<deserialize>,<size>", falling back
+// to `DEFAULT_SCORE_WEIGHTS` when unset or invalid
+fn score_weights() -> [f64; 3] {
+    let value = match std::env::var("BENCH_SCORE_WEIGHTS") {
+        Ok(value) => value,
+        Err(_) => return DEFAULT_SCORE_WEIGHTS,
+    };
+    let weights: Vec<f64> = value.split(',').filter_map(|weight| weight.trim().parse().ok()).collect();
+    match <[f64; 3]>::try_from(weights) {
+        Ok(weights) if weights.iter().all(|weight| *weight >= 0.0) && weights.iter().sum::<f64>() > 0.0 => weights,
+        _ => {
+            eprintln!("Warning: invalid BENCH_SCORE_WEIGHTS '{}', using equal weights", value);
+            DEFAULT_SCORE_WEIGHTS
+        }
+    }
+}
+
+// Score each of `groups` from 0 to 100: every metric is normalized against the
+// best value in the table (best / value, so 1.0 is the best), then the three
+// are averaged with `weights`. Groups without a recorded size are not scored
+fn weighted_scores(results: &ResultsMap, groups: &[&str], size: &'static str, weights: [f64; 3]) -> HashMap<String, f64> {
+    // The small payload's sizes are cached; the sized ones were recorded while benchmarking
+    let small_sizes = standard_payload_sizes();
+    let recorded_sizes = PAYLOAD_SIZES.lock().unwrap();
+    let serialized_size = |group: &str| -> Option<f64> {
+        let bytes = match size {
+            "small" => small_sizes.get(group),
+            _ => recorded_sizes.get(&format!("{}_sizes/{}", group, size)),
+        };
+        bytes.map(|bytes| *bytes as f64)
+    };
+
+    let metrics: Vec<(&str, [f64; 3])> = groups.iter()
+        .filter_map(|group| {
+            let result = results.get(&(group.to_string(), size))?;
+            Some((*group, [result.serialize_time_ns, result.deserialize_time_ns, serialized_size(group)?]))
+        })
+        .filter(|(_, values)| values.iter().all(|value| *value > 0.0))
+        .collect();
+    let best: Vec<f64> = (0..3)
+        .map(|metric| metrics.iter().map(|(_, values)| values[metric]).fold(f64::INFINITY, f64::min))
+        .collect();
+    let total_weight: f64 = weights.iter().sum();
+
+    metrics.iter()
+        .map(|(group, values)| {
+            let weighted: f64 = (0..3).map(|metric| weights[metric] * best[metric] / values[metric]).sum();
+            (group.to_string(), 100.0 * weighted / total_weight)
+        })
+        .collect()
+}
+
+// Suffixes of the `FORMATS` groups that run a base format another way
+// (pre-allocated buffers, borrowed output, limits, gzip, ...)
+const VARIANT_SUFFIXES: [&str; 7] = ["_zero_alloc", "_reuse", "_writer", "_borrowed", "_limited", "_value", "_compressed"];
+
+// The groups among `groups` that run the standard `TestData` payload with a
+// base format, i.e. those registered in `FORMATS` minus the variants; only
+// these are ranked and scored against each other
+fn standard_payload_groups<'a>(groups: &[&'a str]) -> Vec<&'a str> {
+    groups.iter()
+        .copied()
+        .filter(|group| FORMATS.iter().any(|(_, _, format_groups)| format_groups.contains(group)))
+        .filter(|group| !VARIANT_SUFFIXES.iter().any(|suffix| group.ends_with(suffix)))
+        .collect()
+}
+
+// One ranking category: its title, the unit of its values, and the values
+// per format sorted best first
+struct RankingCategory {
+    title: &'static str,
+    unit: &'static str,
+    entries: Vec<(String, f64)>,
+}
+
+// The ranking categories for `groups` at one payload size. Lower is better in
+// every category; a format with no value for a category (e.g. no recorded
+// size) is left out of it
+fn ranking_categories(results: &ResultsMap, groups: &[&str], size: &'static str) -> Vec<RankingCategory> {
+    let timed = |time: fn(&BenchmarkResults) -> f64| -> Vec<(String, f64)> {
+        groups.iter()
+            .filter_map(|group| results.get(&(group.to_string(), size)).map(|result| (group.to_string(), time(result))))
+            .collect()
+    };
+    let payload_sizes = standard_payload_sizes();
+    let memory = MEMORY_USAGE.lock().unwrap();
+
+    let mut categories = vec![
+        RankingCategory { title: "Fastest serializer", unit: "ns", entries: timed(|result| result.serialize_time_ns) },
+        RankingCategory { title: "Fastest deserializer", unit: "ns", entries: timed(|result| result.deserialize_time_ns) },
+        RankingCategory {
+            title: "Most compact",
+            unit: "bytes",
+            entries: groups.iter()
+                .filter_map(|group| payload_sizes.get(group).map(|bytes| (group.to_string(), *bytes as f64)))
+                .collect(),
+        },
+        RankingCategory {
+            title: "Lowest memory",
+            unit: "bytes allocated",
+            entries: groups.iter()
+                .filter_map(|group| memory.get(*group).map(|bytes| (group.to_string(), *bytes as f64)))
+                .collect(),
+        },
+    ];
+    for category in &mut categories {
+        category.entries.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    }
+    categories
+}
+
+// Winner of each ranking category on the standard payload, then an all-round
+// score per format: the geometric mean of its rank in each category divided by
+// the number of ranked formats, so 1/n means first everywhere and 1.0 last
+fn print_ranking_summary(groups: &[&str]) {
+    let groups = standard_payload_groups(groups);
+    let categories = ranking_categories(&BENCHMARK_RESULTS.lock().unwrap(), &groups, "small");
+    if categories.iter().all(|category| category.entries.is_empty()) {
+        return;
+    }
+
+    println!("\n{:-^80}", " Ranking (small payload) ");
+    println!("{:<22} | {:<24} | {:<26}", "Category", "Winner", "Value");
+    println!("{:-<22}-+-{:-<24}-+-{:-<26}", "", "", "");
+    for category in &categories {
+        if let Some((format, value)) = category.entries.first() {
+            println!("{:<22} | {:<24} | {:<26}", category.title, format, format!("{:.2} {}", value, category.unit));
+        }
+    }
+    println!("Fastest serializer and deserializer are marked with * in the results table");
+
+    // Formats tied on a value share the better rank
+    let mut scores: Vec<(&str, f64)> = groups.iter()
+        .filter_map(|group| {
+            let normalized: Vec<f64> = categories.iter()
+                .filter_map(|category| {
+                    let (_, value) = category.entries.iter().find(|(format, _)| format == group)?;
+                    let rank = category.entries.iter().filter(|(_, other)| other < value).count() + 1;
+                    Some(rank as f64 / category.entries.len() as f64)
+                })
+                .collect();
+            if normalized.is_empty() {
+                return None;
+            }
+            let geometric_mean = (normalized.iter().map(|rank| rank.ln()).sum::<f64>() / normalized.len() as f64).exp();
+            Some((*group, geometric_mean))
+        })
+        .collect();
+    scores.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+
+    println!("\nAll-round score (geometric mean of normalized ranks, lower is better):");
+    for (position, (format, score)) in scores.iter().enumerate() {
+        println!("{:>3}. {:<24} {:.3}", position + 1, format, score);
+    }
+    println!("{:-^80}", "");
+}
+
+// Standard deviation cell; criterion doesn't always record one, so show n/a
+fn format_std_dev(std_dev_ns: Option<f64>) -> String {
+    match std_dev_ns {
+        Some(std_dev) => format!("± {:.2}", std_dev),
+        None => "n/a".to_string(),
+    }
+}
+
+// Throughput cell; `-` for rows without a recorded payload size
+fn format_mb_per_sec(mb_per_sec: Option<f64>) -> String {
+    mb_per_sec.map_or_else(|| "-".to_string(), |mb_per_sec| format!("{:.2}", mb_per_sec))
+}
+
+// CPU time cell; only simple mode with the `cpu-time` feature measures one
+fn format_cpu_time(cpu_ns: Option<f64>) -> String {
+    cpu_ns.map_or_else(|| "-".to_string(), |ns| format!("{:.2}", ns))
+}
+
+// Where the results file is written unless BENCH_JSON_OUT overrides it
+const DEFAULT_RESULTS_PATH: &str = "target/benchmark_results/results.json";
+
+// Write the results, with this run's metadata, as a `ResultsFile`
+fn write_results_json(path: &str) {
+    let mut results: BTreeMap<String, BTreeMap<String, BenchmarkResults>> = BTreeMap::new();
+    for ((format, size), result) in BENCHMARK_RESULTS.lock().unwrap().iter() {
+        results.entry(format.clone()).or_default().insert(size.to_string(), result.clone());
+    }
+
+    let file = ResultsFile { metadata: RunMetadata::current(), results };
+    match file.write(path) {
+        Ok(()) => println!("Benchmark results written to {}", path),
+        Err(err) => eprintln!("Warning: could not write benchmark results to {}: {}", path, err),
+    }
+}
+
+// SQLite database the `persist` feature appends every run's results to
+#[cfg(feature = "persist")]
+const HISTORY_DB: &str = "serializer_history.db";
+
+// Number of runs `query_trend` shows unless BENCH_TREND_RUNS overrides it
+#[cfg(feature = "persist")]
+const DEFAULT_TREND_RUNS: usize = 10;
+
+// Persist this run to `HISTORY_DB`, then print the trend of each format named
+// in BENCH_TREND (comma-separated)
+#[cfg(feature = "persist")]
+fn persist_results() {
+    if let Err(err) = persist_to_sqlite(HISTORY_DB) {
+        eprintln!("Warning: could not persist the results to {}: {}", HISTORY_DB, err);
+        return;
+    }
+    println!("Results appended to {}", HISTORY_DB);
+
+    let n_runs = std::env::var("BENCH_TREND_RUNS").ok()
+        .and_then(|runs| runs.trim().parse().ok())
+        .unwrap_or(DEFAULT_TREND_RUNS);
+    if let Ok(formats) = std::env::var("BENCH_TREND") {
+        for format in formats.split(',').map(str::trim).filter(|format| !format.is_empty()) {
+            if let Err(err) = query_trend(format, n_runs) {
+                eprintln!("Warning: could not read the trend of '{}' from {}: {}", format, HISTORY_DB, err);
+            }
+        }
+    }
+}
+
+// Insert one row per result into the `results` table of the database at
+// `path`, creating both if needed. The medium and large results are stored
+// under `<format>/<size>`, the small ones under the plain format name
+#[cfg(feature = "persist")]
+fn persist_to_sqlite(path: &str) -> rusqlite::Result<()> {
+    let mut connection = rusqlite::Connection::open(path)?;
+    connection.execute_batch(
+        "CREATE TABLE IF NOT EXISTS results (
+            run_id TEXT NOT NULL,
+            timestamp INTEGER NOT NULL,
+            format TEXT NOT NULL,
+            serialize_ns REAL NOT NULL,
+            deserialize_ns REAL NOT NULL,
+            size_bytes INTEGER NOT NULL
+        )",
+    )?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    let run_id = format!("{}-{}", timestamp, std::process::id());
+
+    let results = BENCHMARK_RESULTS.lock().unwrap();
+    let transaction = connection.transaction()?;
+    {
+        let mut insert = transaction.prepare(
+            "INSERT INTO results (run_id, timestamp, format, serialize_ns, deserialize_ns, size_bytes) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for ((format, size), result) in results.iter() {
+            let format = if *size == "small" { format.clone() } else { format!("{}/{}", format, size) };
+            insert.execute(rusqlite::params![
+                run_id, timestamp, format, result.serialize_time_ns, result.deserialize_time_ns, result.payload_bytes as i64,
+            ])?;
+        }
+    }
+    transaction.commit()
+}
+
+// Print the last `n_runs` results of `format` in `HISTORY_DB`, newest first
+#[cfg(feature = "persist")]
+fn query_trend(format: &str, n_runs: usize) -> rusqlite::Result<()> {
+    let connection = rusqlite::Connection::open(HISTORY_DB)?;
+    let mut query = connection.prepare(
+        "SELECT run_id, timestamp, serialize_ns, deserialize_ns, size_bytes FROM results
+         WHERE format = ?1 ORDER BY timestamp DESC, rowid DESC LIMIT ?2",
+    )?;
+    let rows = query.query_map(rusqlite::params![format, n_runs as i64], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, f64>(2)?, row.get::<_, f64>(3)?, row.get::<_, i64>(4)?))
+    })?;
+
+    println!("\n{:-^80}", format!(" Trend: {} (last {} runs) ", format, n_runs));
+    println!("{:<22} | {:<12} | {:<16} | {:<16} | {:<6}", "Run", "Timestamp", "Serialize (ns)", "Deserialize (ns)", "Bytes");
+    println!("{:-<22}-+-{:-<12}-+-{:-<16}-+-{:-<16}-+-{:-<6}", "", "", "", "", "");
+    for row in rows {
+        let (run_id, timestamp, serialize_ns, deserialize_ns, size_bytes) = row?;
+        println!("{:<22} | {:<12} | {:<16.2} | {:<16.2} | {:<6}", run_id, timestamp, serialize_ns, deserialize_ns, size_bytes);
+    }
+    println!("{:-^80}", "");
+    Ok(())
+}
+
+// Width in pixels of the longest bar in the HTML report's charts
+const HTML_CHART_BAR_WIDTH: f64 = 520.0;
+
+const HTML_REPORT_HEAD: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Serialization Benchmark Results</title>
+<style>
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: right; }
+th { background: #f0f0f0; cursor: pointer; user-select: none; }
+td:first-child, th:first-child { text-align: left; }
+svg text { font-size: 12px; }
+rect.serialize { fill: #4e79a7; }
+rect.deserialize { fill: #f28e2b; }
+rect.size { fill: #59a14f; }
+</style>
+</head>
+<body>
+<h1>Serialization Benchmark Results</h1>
+<p>Click a column header to sort by it.</p>
+"#;
+
+// Sorts a table by the clicked column, numerically when its cells carry a
+// `data-value`; cells without a usable number (n/a) sort last
+const HTML_REPORT_TAIL: &str = r#"<script>
+document.querySelectorAll("table.sortable").forEach(function (table) {
+  Array.from(table.tHead.rows[0].cells).forEach(function (th, column) {
+    th.addEventListener("click", function () {
+      var ascending = th.dataset.order !== "asc";
+      th.dataset.order = ascending ? "asc" : "desc";
+      var key = function (row) {
+        var cell = row.cells[column];
+        return cell.dataset.value !== undefined ? parseFloat(cell.dataset.value) : cell.textContent;
+      };
+      var body = table.tBodies[0];
+      Array.from(body.rows).sort(function (a, b) {
+        var x = key(a), y = key(b);
+        if (typeof x === "string") {
+          return ascending ? x.localeCompare(y) : y.localeCompare(x);
+        }
+        if (isNaN(x) || isNaN(y)) {
+          return isNaN(x) - isNaN(y);
+        }
+        return ascending ? x - y : y - x;
+      }).forEach(function (row) { body.appendChild(row); });
+    });
+  });
+});
+</script>
+</body>
+</html>
+"#;
+
+// Where the HTML report is written unless BENCH_HTML_OUT overrides it
+const DEFAULT_HTML_PATH: &str = "target/benchmark_results/report.html";
+
+// Write the results as a self-contained HTML report to `path`, creating its
+// parent directories: per payload size, a sortable table and a grouped bar
+// chart of the serialize and deserialize times. Rows start in the same order
+// as the stdout tables, and nothing is loaded from outside the file, so it can
+// be committed or shared as is
+fn write_results_html(path: &str) {
+    let results = BENCHMARK_RESULTS.lock().unwrap();
+    let mut html = String::from(HTML_REPORT_HEAD);
+    html.push_str(&format!("<p>Payload: {}</p>\n", GENERATOR.describe()));
+
+    for size in PAYLOAD_SIZE_TIERS {
+        let mut formats: Vec<&str> = results.keys()
+            .filter(|(format, result_size)| *result_size == size && !JSON_VARIANTS.contains(&format.as_str()))
+            .map(|(format, _)| format.as_str())
+            .collect();
+        formats.sort();
+        formats.extend(JSON_VARIANTS.iter().copied().filter(|variant| results.contains_key(&(variant.to_string(), size))));
+        if formats.is_empty() {
+            continue;
+        }
+
+        let rows: Vec<(&str, &BenchmarkResults)> = formats.iter()
+            .map(|format| (*format, &results[&(format.to_string(), size)]))
+            .collect();
+        html.push_str(&format!("<h2>{}</h2>\n", results_table_title(size)));
+        html.push_str(&html_results_table(&rows));
+        if rows.iter().any(|(format, _)| loses_float_precision(format)) {
+            html.push_str(&format!("<p>{}</p>\n", LOSSY_FLOAT_NOTE));
+        }
+        html.push_str(&html_time_chart(&rows));
+
+        // Rows without a recorded size are left out rather than drawn as empty bars
+        let sized_rows: Vec<(&str, &BenchmarkResults)> = rows.iter()
+            .copied()
+            .filter(|(_, result)| result.payload_bytes > 0)
+            .collect();
+        if !sized_rows.is_empty() {
+            html.push_str(&html_bar_chart("Payload Size (bytes)", "size", &sized_rows, 0, |result| result.payload_bytes as f64));
+        }
+    }
+    html.push_str(HTML_REPORT_TAIL);
+
+    let written = match std::path::Path::new(path).parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|()| fs::write(path, &html)),
+        None => fs::write(path, &html),
+    };
+    match written {
+        Ok(()) => println!("HTML report written to {}", path),
+        Err(err) => eprintln!("Warning: could not write the HTML report to {}: {}", path, err),
+    }
+}
+
+// The columns of the stdout table; numeric cells carry their raw value in
+// `data-value` for sorting
+fn html_results_table(rows: &[(&str, &BenchmarkResults)]) -> String {
+    let std_dev_cell = |std_dev_ns: Option<f64>| match std_dev_ns {
+        Some(std_dev) => format!("<td data-value=\"{}\">{}</td>", std_dev, format_std_dev(Some(std_dev))),
+        None => format!("<td data-value=\"\">{}</td>", format_std_dev(None)),
+    };
+
+    let mb_per_sec_cell = |mb_per_sec: Option<f64>| match mb_per_sec {
+        Some(mb_per_sec) => format!("<td data-value=\"{}\">{}</td>", mb_per_sec, format_mb_per_sec(Some(mb_per_sec))),
+        None => format!("<td data-value=\"\">{}</td>", format_mb_per_sec(None)),
+    };
+
+    let has_cpu_times = rows.iter().any(|(_, result)| result.serialize_cpu_ns.is_some());
+    let cpu_cell = |cpu_ns: Option<f64>| match (has_cpu_times, cpu_ns) {
+        (false, _) => String::new(),
+        (true, Some(ns)) => format!("<td data-value=\"{}\">{}</td>", ns, format_cpu_time(Some(ns))),
+        (true, None) => format!("<td data-value=\"\">{}</td>", format_cpu_time(None)),
+    };
+
+    let mut table = String::from("<table class=\"sortable\">\n<thead><tr>");
+    for (column, header) in RESULT_COLUMNS.iter().enumerate() {
+        // The score is only computed for the console table
+        if *header == "Score" || (!has_cpu_times && CPU_COLUMNS.contains(&column)) {
+            continue;
+        }
+        table.push_str(&format!("<th>{}</th>", header));
+    }
+    table.push_str("</tr></thead>\n<tbody>\n");
+    for (format, result) in rows {
+        table.push_str(&format!(
+            "<tr><td>{}</td><td data-value=\"{}\">{:.2}</td>{}<td data-value=\"{}\">{} ops/sec</td>{}{}<td data-value=\"{}\">{:.2}</td>{}<td data-value=\"{}\">{} ops/sec</td>{}{}<td data-value=\"{}\">{}</td></tr>\n",
+            if loses_float_precision(format) { format!("{} †", format) } else { format.to_string() },
+            result.serialize_time_ns, result.serialize_time_ns,
+            std_dev_cell(result.serialize_std_dev_ns),
+            result.serialize_ops_per_sec, result.serialize_ops_per_sec,
+            mb_per_sec_cell(result.serialize_mb_per_sec),
+            cpu_cell(result.serialize_cpu_ns),
+            result.deserialize_time_ns, result.deserialize_time_ns,
+            std_dev_cell(result.deserialize_std_dev_ns),
+            result.deserialize_ops_per_sec, result.deserialize_ops_per_sec,
+            mb_per_sec_cell(result.deserialize_mb_per_sec),
+            cpu_cell(result.deserialize_cpu_ns),
+            result.payload_bytes, if result.payload_bytes == 0 { "-".to_string() } else { result.payload_bytes.to_string() },
+        ));
+    }
+    table.push_str("</tbody>\n</table>\n");
+    table
+}
+
+// Horizontal inline SVG bar chart of `value` per row, scaled to the largest one
+// and labelled with `decimals` decimal places
+fn html_bar_chart(title: &str, class: &str, rows: &[(&str, &BenchmarkResults)], decimals: usize, value: impl Fn(&BenchmarkResults) -> f64) -> String {
+    const LABEL_WIDTH: f64 = 200.0;
+    const ROW_HEIGHT: f64 = 22.0;
+    const BAR_HEIGHT: f64 = 16.0;
+
+    let max = rows.iter().map(|(_, result)| value(result)).fold(0.0, f64::max);
+    let height = ROW_HEIGHT * rows.len() as f64;
+    let mut chart = format!(
+        "<h3>{}</h3>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        title, LABEL_WIDTH + HTML_CHART_BAR_WIDTH + 120.0, height,
+    );
+    for (index, (format, result)) in rows.iter().enumerate() {
+        let y = index as f64 * ROW_HEIGHT;
+        let width = if max > 0.0 { value(result) / max * HTML_CHART_BAR_WIDTH } else { 0.0 };
+        chart.push_str(&format!(
+            "<text x=\"{:.0}\" y=\"{:.0}\" text-anchor=\"end\">{}</text><rect class=\"{}\" x=\"{:.0}\" y=\"{:.0}\" width=\"{:.1}\" height=\"{:.0}\"/><text x=\"{:.1}\" y=\"{:.0}\">{:.decimals$}</text>\n",
+            LABEL_WIDTH - 6.0, y + BAR_HEIGHT - 3.0, format,
+            class, LABEL_WIDTH, y, width, BAR_HEIGHT,
+            LABEL_WIDTH + width + 4.0, y + BAR_HEIGHT - 3.0, value(result),
+            decimals = decimals,
+        ));
+    }
+    chart.push_str("</svg>\n");
+    chart
+}
+
+// Horizontal inline SVG chart with a serialize and a deserialize bar per row,
+// both scaled to the slowest time of either so they can be compared directly
+fn html_time_chart(rows: &[(&str, &BenchmarkResults)]) -> String {
+    const LABEL_WIDTH: f64 = 200.0;
+    const LEGEND_HEIGHT: f64 = 24.0;
+    const BAR_HEIGHT: f64 = 14.0;
+    const ROW_HEIGHT: f64 = 2.0 * BAR_HEIGHT + 8.0;
+
+    let max = rows.iter()
+        .map(|(_, result)| result.serialize_time_ns.max(result.deserialize_time_ns))
+        .fold(0.0, f64::max);
+    let height = LEGEND_HEIGHT + ROW_HEIGHT * rows.len() as f64;
+    let mut chart = format!(
+        "<h3>Serialize / Deserialize Time (ns)</h3>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        LABEL_WIDTH + HTML_CHART_BAR_WIDTH + 120.0, height,
+    );
+    chart.push_str(&format!(
+        "<rect class=\"serialize\" x=\"{:.0}\" y=\"0\" width=\"12\" height=\"12\"/><text x=\"{:.0}\" y=\"11\">serialize</text><rect class=\"deserialize\" x=\"{:.0}\" y=\"0\" width=\"12\" height=\"12\"/><text x=\"{:.0}\" y=\"11\">deserialize</text>\n",
+        LABEL_WIDTH, LABEL_WIDTH + 16.0, LABEL_WIDTH + 100.0, LABEL_WIDTH + 116.0,
+    ));
+    for (index, (format, result)) in rows.iter().enumerate() {
+        let y = LEGEND_HEIGHT + index as f64 * ROW_HEIGHT;
+        chart.push_str(&format!(
+            "<text x=\"{:.0}\" y=\"{:.0}\" text-anchor=\"end\">{}</text>\n",
+            LABEL_WIDTH - 6.0, y + BAR_HEIGHT + 3.0, format,
+        ));
+        for (offset, class, time_ns) in [(0.0, "serialize", result.serialize_time_ns), (BAR_HEIGHT, "deserialize", result.deserialize_time_ns)] {
+            let width = if max > 0.0 { time_ns / max * HTML_CHART_BAR_WIDTH } else { 0.0 };
+            chart.push_str(&format!(
+                "<rect class=\"{}\" x=\"{:.0}\" y=\"{:.0}\" width=\"{:.1}\" height=\"{:.0}\"/><text x=\"{:.1}\" y=\"{:.0}\">{:.2}</text>\n",
+                class, LABEL_WIDTH, y + offset, width, BAR_HEIGHT,
+                LABEL_WIDTH + width + 4.0, y + offset + BAR_HEIGHT - 3.0, time_ns,
+            ));
+        }
+    }
+    chart.push_str("</svg>\n");
+    chart
+}
+
+// Compare the current results with a results file written by an earlier run
+// (see `write_results_json`) in a table of per-format time and ops/sec deltas, for each
+// format and payload size that appears in both. Returns whether any serialize
+// or deserialize time got slower by more than `threshold_pct` percent, or the
+// baseline could not be read
+fn compare_against_baseline(path: &str, threshold_pct: f64) -> bool {
+    let baseline = match ResultsFile::read(path) {
+        Ok(baseline) => baseline.results,
+        // A gate that can't compare must not pass
+        Err(err) => {
+            eprintln!("REGRESSION DETECTED: could not read the baseline results in {}: {}", path, err);
+            return true;
+        }
+    };
+    let change = |current: f64, baseline: f64| (current / baseline - 1.0) * 100.0;
+
+    let results = BENCHMARK_RESULTS.lock().unwrap();
+    let mut keys: Vec<&(String, &'static str)> = results.keys().collect();
+    keys.sort();
+
+    println!("\n{:-^165}", format!(" Changes against {} (threshold {:.1}%) ", path, threshold_pct));
+
+    println!("{:<24} | {:<7} | {:<14} | {:<14} | {:<9} | {:<10} | {:<14} | {:<14} | {:<9} | {:<10} | {:<10}",
+             "Format",
+             "Payload",
+             "Base ser (ns)",
+             "Ser (ns)",
+             "Ser %",
+             "Ser ops %",
+             "Base de (ns)",
+             "De (ns)",
+             "De %",
+             "De ops %",
+             "Status");
+
+    println!("{:-<24}-+-{:-<7}-+-{:-<14}-+-{:-<14}-+-{:-<9}-+-{:-<10}-+-{:-<14}-+-{:-<14}-+-{:-<9}-+-{:-<10}-+-{:-<10}",
+             "", "", "", "", "", "", "", "", "", "", "");
+
+    let mut regressed = false;
+    for key in keys {
+        let (format, size) = key;
+        let Some(previous) = baseline.get(format).and_then(|sizes| sizes.get(*size)) else {
+            continue;
+        };
+        let current = &results[key];
+        let serialize_change = change(current.serialize_time_ns, previous.serialize_time_ns);
+        let deserialize_change = change(current.deserialize_time_ns, previous.deserialize_time_ns);
+        let flagged = serialize_change > threshold_pct || deserialize_change > threshold_pct;
+
+        println!("{:<24} | {:<7} | {:<14.2} | {:<14.2} | {:<9} | {:<10} | {:<14.2} | {:<14.2} | {:<9} | {:<10} | {:<10}",
+                 format,
+                 size,
+                 previous.serialize_time_ns,
+                 current.serialize_time_ns,
+                 format!("{:+.1}%", serialize_change),
+                 format!("{:+.1}%", change(current.serialize_ops_per_sec as f64, previous.serialize_ops_per_sec as f64)),
+                 previous.deserialize_time_ns,
+                 current.deserialize_time_ns,
+                 format!("{:+.1}%", deserialize_change),
+                 format!("{:+.1}%", change(current.deserialize_ops_per_sec as f64, previous.deserialize_ops_per_sec as f64)),
+                 if flagged { "REGRESSION" } else { "ok" });
+        regressed |= flagged;
+    }
+
+    println!("{:-^165}", "");
+
+    if regressed {
+        eprintln!("REGRESSION DETECTED: at least one time is more than {:.1}% slower than in {}", threshold_pct, path);
+    }
+    regressed
+}
+
+// Compare serde_json's byte and `io::Read` APIs against the `String`-based
+// `to_string` and `from_str` ones the main table uses
+fn print_json_reader_report() {
+    let to_string_ns = read_point_estimate("serde_json", "serialize");
+    let from_str_ns = read_point_estimate("serde_json", "deserialize");
+    if to_string_ns.is_none() && from_str_ns.is_none() {
+        eprintln!("Warning: skipping the serde_json API report: no usable estimates under target/criterion/serde_json");
+        return;
+    }
+
+    println!("\n{:-^80}", " serde_json: String vs bytes vs reader ");
+    if let Some(to_string_ns) = to_string_ns {
+        println!("{:<32} | {:.2} ns", "serialize (to_string)", to_string_ns);
+        if let Some(to_vec_ns) = read_point_estimate("serde_json", "serialize_vec") {
+            println!("{:<32} | {:.2} ns ({:+.1}% vs to_string)", "serialize_vec (to_vec)", to_vec_ns, (to_vec_ns / to_string_ns - 1.0) * 100.0);
+        }
+    }
+    if let Some(from_str_ns) = from_str_ns {
+        println!("{:<32} | {:.2} ns", "deserialize (from_str)", from_str_ns);
+        if let Some(from_slice_ns) = read_point_estimate("serde_json", "deserialize_slice") {
+            println!("{:<32} | {:.2} ns ({:+.1}% vs from_str)", "deserialize_slice (from_slice)", from_slice_ns, (from_slice_ns / from_str_ns - 1.0) * 100.0);
+        }
+        if let Some(from_reader_ns) = read_point_estimate("serde_json", "deserialize_reader") {
+            println!("{:<32} | {:.2} ns ({:+.1}% vs from_str)", "deserialize_reader (from_reader)", from_reader_ns, (from_reader_ns / from_str_ns - 1.0) * 100.0);
+        }
+    }
+    println!("{:-^80}", "");
+}
+
+// Compare reading only `id` out of a FlexBuffers buffer against deserializing
+// the whole `TestData` from FlexBuffers and from serde_json
+fn print_flexbuffers_access_report() {
+    let read_id_ns = read_point_estimate("flexbuffers", "read_id");
+    let deserialize_ns = read_point_estimate("flexbuffers", "deserialize");
+    let json_ns = read_point_estimate("serde_json", "deserialize");
+
+    match (read_id_ns, deserialize_ns) {
+        (Some(read_id_ns), Some(deserialize_ns)) => {
+            println!("\n{:-^80}", " FlexBuffers: field access vs deserialize ");
+            println!("{:<32} | {:.2} ns", "read_id (Reader, `id` only)", read_id_ns);
+            println!("{:<32} | {:.2} ns ({:.1}x read_id)", "deserialize (from_slice)", deserialize_ns, deserialize_ns / read_id_ns);
+            if let Some(json_ns) = json_ns {
+                println!("{:<32} | {:.2} ns ({:.1}x read_id)", "serde_json deserialize", json_ns, json_ns / read_id_ns);
+            }
+            println!("{:-^80}", "");
+        }
+        _ => eprintln!("Warning: skipping the FlexBuffers access report: no usable estimates under target/criterion/flexbuffers"),
+    }
+}
+
+// Encoded size of `NewtypeData` against the same values without the wrappers
+// for each format whose newtype group ran, asserting the two match for the
+// formats in `NEWTYPE_TRANSPARENT_FORMATS`. The protobuf formats are left out:
+// their schema holds plain fields, so there is no wrapper to encode
+fn print_newtype_size_report(groups: &[&str]) {
+    let data = NewtypeData::sample();
+    let plain = data.unwrapped();
+    let sizes = [
+        ("bincode", serialize(&data).unwrap().len(), serialize(&plain).unwrap().len()),
+        ("bcs", to_bytes(&data).unwrap().len(), to_bytes(&plain).unwrap().len()),
+        ("serde_json", serde_json::to_vec(&data).unwrap().len(), serde_json::to_vec(&plain).unwrap().len()),
+        #[cfg(target_arch = "x86_64")]
+        ("simd_json", simd_json::serde::to_vec(&data).unwrap().len(), simd_json::serde::to_vec(&plain).unwrap().len()),
+        ("sonic_rs", sonic_rs::to_vec(&data).unwrap().len(), sonic_rs::to_vec(&plain).unwrap().len()),
+        ("serde_yaml", serde_yaml::to_string(&data).unwrap().len(), serde_yaml::to_string(&plain).unwrap().len()),
+        ("ron", ron::to_string(&data).unwrap().len(), ron::to_string(&plain).unwrap().len()),
+        ("toml", toml::to_string(&data).unwrap().len(), toml::to_string(&plain).unwrap().len()),
+        ("xml", quick_xml::se::to_string(&data).unwrap().len(), quick_xml::se::to_string(&plain).unwrap().len()),
+        ("borsh", borsh::to_vec(&data).unwrap().len(), borsh::to_vec(&plain).unwrap().len()),
+        ("musli_storage", musli::storage::to_vec(&data).unwrap().len(), musli::storage::to_vec(&plain).unwrap().len()),
+        ("musli_wire", musli::wire::to_vec(&data).unwrap().len(), musli::wire::to_vec(&plain).unwrap().len()),
+        ("flexbuffers", flexbuffers::to_vec(&data).unwrap().len(), flexbuffers::to_vec(&plain).unwrap().len()),
+        ("xdr", serde_xdr::to_bytes(&data).unwrap().len(), serde_xdr::to_bytes(&plain).unwrap().len()),
+        ("savefile", savefile::save_to_mem(SAVEFILE_VERSION, &data).unwrap().len(), savefile::save_to_mem(SAVEFILE_VERSION, &plain).unwrap().len()),
+        ("pickle", serde_pickle::to_vec(&data, SerOptions::new()).unwrap().len(), serde_pickle::to_vec(&plain, SerOptions::new()).unwrap().len()),
+        ("speedy", data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap().len(), plain.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap().len()),
+    ];
+    let rows: Vec<_> = sizes.into_iter()
+        .filter(|(format, _, _)| groups.contains(&format!("{}_newtype", format).as_str()))
+        .collect();
+    if rows.is_empty() {
+        return;
+    }
+
+    println!("\n{:-^80}", " Newtype wrapper overhead ");
+    println!("{:<16} | {:<16} | {:<16} | {:<16}", "Format", "Newtype (bytes)", "Plain (bytes)", "Overhead (bytes)");
+    println!("{:-<16}-+-{:-<16}-+-{:-<16}-+-{:-<16}", "", "", "", "");
+    for (format, newtype_size, plain_size) in rows {
+        if NEWTYPE_TRANSPARENT_FORMATS.contains(&format) {
+            assert_eq!(newtype_size, plain_size, "{}: newtype wrappers changed the encoded size", format);
+        }
+        println!("{:<16} | {:<16} | {:<16} | {:<16}", format, newtype_size, plain_size, newtype_size as i64 - plain_size as i64);
+    }
+    println!("{:-^80}", "");
+}
+
+// Allocator calls per decode and bytes held by the result, for decoding the
+// same string pairs into a `HashMap` and into a `BTreeMap`
+fn print_map_allocation_report(groups: &[&str]) {
+    let metadata = MapData::sample().metadata;
+    let ordered: BTreeMap<&String, &String> = metadata.iter().collect();
+
+    let mut rows = Vec::new();
+    if groups.contains(&"bincode_map") {
+        let bytes = serialize(&ordered).unwrap();
+        rows.push(("bincode", "HashMap", decode_allocations(|| deserialize::<HashMap<String, String>>(&bytes).unwrap())));
+        rows.push(("bincode", "BTreeMap", decode_allocations(|| deserialize::<BTreeMap<String, String>>(&bytes).unwrap())));
+    }
+    if groups.contains(&"serde_json_map") {
+        let bytes = serde_json::to_vec(&ordered).unwrap();
+        rows.push(("serde_json", "HashMap", decode_allocations(|| serde_json::from_slice::<HashMap<String, String>>(&bytes).unwrap())));
+        rows.push(("serde_json", "BTreeMap", decode_allocations(|| serde_json::from_slice::<BTreeMap<String, String>>(&bytes).unwrap())));
+    }
+    if rows.is_empty() {
+        return;
+    }
+
+    println!("\n{:-^80}", format!(" Map decode allocations ({} string pairs) ", payloads::MAP_ENTRIES));
+    println!("{:<12} | {:<10} | {:<20} | {:<20}", "Format", "Map", "Allocations/decode", "Bytes held");
+    println!("{:-<12}-+-{:-<10}-+-{:-<20}-+-{:-<20}", "", "", "", "");
+    for (format, map, (allocations, held)) in rows {
+        println!("{:<12} | {:<10} | {:<20.1} | {:<20}", format, map, allocations, held);
+    }
+    println!("{:-^80}", "");
+}
+
+// Allocator calls per deserialize into the owned `TestData` and into
+// `TestDataBorrowed`, for each format whose borrowed group ran
+fn print_borrowed_allocation_report(groups: &[&str]) {
+    let test_data = standard_test_data();
+
+    let mut rows = Vec::new();
+    if groups.contains(&"serde_json_borrowed") {
+        let bytes = serde_json::to_vec(&test_data).unwrap();
+        rows.push(("serde_json", "owned", decode_allocations(|| serde_json::from_slice::<TestData>(&bytes).unwrap())));
+        rows.push(("serde_json", "borrowed", decode_allocations(|| serde_json::from_slice::<TestDataBorrowed>(&bytes).unwrap().id)));
+    }
+    if groups.contains(&"bincode_borrowed") {
+        let bytes = serialize(&test_data).unwrap();
+        rows.push(("bincode", "owned", decode_allocations(|| deserialize::<TestData>(&bytes).unwrap())));
+        rows.push(("bincode", "borrowed", decode_allocations(|| deserialize::<TestDataBorrowed>(&bytes).unwrap().id)));
+    }
+    if rows.is_empty() {
+        return;
+    }
+
+    println!("\n{:-^80}", " Deserialize allocations: owned vs borrowed name ");
+    println!("{:<12} | {:<10} | {:<20} | {:<20}", "Format", "TestData", "Allocations/decode", "Bytes held");
+    println!("{:-<12}-+-{:-<10}-+-{:-<20}-+-{:-<20}", "", "", "", "");
+    for (format, kind, (allocations, held)) in rows {
+        println!("{:<12} | {:<10} | {:<20.1} | {:<20}", format, kind, allocations, held);
+    }
+    println!("{:-^80}", "");
+}
+
+// Allocator calls per run of `decode`, and the net bytes still held while its
+// result is alive
+fn decode_allocations<T>(decode: impl Fn() -> T) -> (f64, i64) {
+    let allocations = allocations_per_call(REUSE_ALLOC_SAMPLES, || drop(black_box(decode())));
+    let mut decoded = None;
+    let held = measure_alloc(|| decoded = Some(decode()));
+    drop(decoded);
+    (allocations, held)
+}
+
+// Store compression results
+fn store_compression_results(key: &str, raw_size: usize, compressed_size: usize, compress_ns: f64, decompress_ns: f64) {
+    let mut results = COMPRESSION_RESULTS.lock().unwrap();
+    results.insert(key.to_string(), CompressionResults {
+        raw_size,
+        compressed_size,
+        compression_ratio: raw_size as f64 / compressed_size as f64,
+        compress_time_ns: compress_ns,
+        decompress_time_ns: decompress_ns,
+    });
+}
+
+// Print compression results as a formatted table
+fn print_compression_table() {
+    println!("\n{:-^80}", " Compression Results ");
+
+    println!("{:<18} | {:<10} | {:<15} | {:<8} | {:<20} | {:<20}",
+             "Format + Codec",
+             "Raw (B)",
+             "Compressed (B)",
+             "Ratio",
+             "Compress Time (ns)",
+             "Decompress Time (ns)");
+
+    println!("{:-<18}-+-{:-<10}-+-{:-<15}-+-{:-<8}-+-{:-<20}-+-{:-<20}",
+             "", "", "", "", "", "");
+
+    let results = COMPRESSION_RESULTS.lock().unwrap();
+
+    let mut keys: Vec<&String> = results.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        if let Some(result) = results.get(key) {
+            println!("{:<18} | {:<10} | {:<15} | {:<8.2} | {:<20.2} | {:<20.2}",
+                     key,
+                     result.raw_size,
+                     result.compressed_size,
+                     result.compression_ratio,
+                     result.compress_time_ns,
+                     result.decompress_time_ns);
+        }
+    }
+
+    println!("{:-^80}", "");
+}
+
+fn record_payload_size(group_name: &str, size: usize) {
+    PAYLOAD_SIZES.lock().unwrap().insert(group_name.to_string(), size);
+}
+
+// Store enum payload results
+fn store_enum_results(group_name: &str, serialize_ns: f64, deserialize_ns: f64) {
+    let serialized_size = PAYLOAD_SIZES.lock().unwrap().get(group_name).copied().unwrap_or(0);
+
+    let mut results = ENUM_RESULTS.lock().unwrap();
+    results.insert(group_name.to_string(), PayloadResults {
+        serialized_size,
+        serialize_time_ns: serialize_ns,
+        deserialize_time_ns: deserialize_ns,
+    });
+}
+
+// Print enum payload results as a formatted table
+fn print_enum_results_table() {
+    println!("\n{:-^86}", " Enum Payload Results ");
+
+    println!("{:<20} | {:<12} | {:<23} | {:<25}",
+             "Format",
+             "Size (bytes)",
+             "Serialization Time (ns)",
+             "Deserialization Time (ns)");
+
+    println!("{:-<20}-+-{:-<12}-+-{:-<23}-+-{:-<25}",
+             "", "", "", "");
+
+    let results = ENUM_RESULTS.lock().unwrap();
+
+    let mut formats: Vec<&String> = results.keys().collect();
+    formats.sort();
+
+    for format in formats {
+        if let Some(result) = results.get(format) {
+            println!("{:<20} | {:<12} | {:<23.2} | {:<25.2}",
+                     format,
+                     result.serialized_size,
+                     result.serialize_time_ns,
+                     result.deserialize_time_ns);
+        }
+    }
+
+    println!("{:-^86}", "");
+}
+
+fn store_big_string_results(group_name: &str, serialize_ns: f64, deserialize_ns: f64) {
+    let serialized_size = PAYLOAD_SIZES.lock().unwrap().get(group_name).copied().unwrap_or(0);
+
+    let mut results = BIG_STRING_RESULTS.lock().unwrap();
+    results.insert(group_name.to_string(), PayloadResults {
+        serialized_size,
+        serialize_time_ns: serialize_ns,
+        deserialize_time_ns: deserialize_ns,
+    });
+}
+
+// Print big string results as MB/s of text, fastest serializer first, so the
+// gap between a plain copy and an escaping scan is visible at a glance
+fn print_big_string_table() {
+    let text_len = BigStringData::sample().text.len();
+    let mb_per_sec = |ns: f64| text_len as f64 / ns * 1_000_000_000.0 / 1_000_000.0;
+
+    println!("\n{:-^100}", format!(" Big String Results ({} bytes of text) ", text_len));
+
+    println!("{:<26} | {:<12} | {:<25} | {:<27}",
+             "Format",
+             "Size (bytes)",
+             "Serialize Throughput (MB/s)",
+             "Deserialize Throughput (MB/s)");
+
+    println!("{:-<26}-+-{:-<12}-+-{:-<25}-+-{:-<27}",
+             "", "", "", "");
+
+    let results = BIG_STRING_RESULTS.lock().unwrap();
+
+    let mut formats: Vec<&String> = results.keys().collect();
+    formats.sort_by(|a, b| results[*a].serialize_time_ns.total_cmp(&results[*b].serialize_time_ns));
+
+    for format in formats {
+        let result = &results[format];
+        println!("{:<26} | {:<12} | {:<25.1} | {:<27.1}",
+                 format,
+                 result.serialized_size,
+                 mb_per_sec(result.serialize_time_ns),
+                 mb_per_sec(result.deserialize_time_ns));
+    }
+
+    println!("{:-^100}", "");
+}
+
+// Store the results of one variant of a multi-variant payload
+fn store_variant_results(results: &Mutex<VariantResultsMap>, group_name: &str, variant: &'static str, serialize_ns: f64, deserialize_ns: f64) {
+    let serialized_size = PAYLOAD_SIZES.lock().unwrap()
+        .get(&format!("{}/{}", group_name, variant))
+        .copied()
+        .unwrap_or(0);
+
+    let mut results = results.lock().unwrap();
+    results.insert((group_name.to_string(), variant), PayloadResults {
+        serialized_size,
+        serialize_time_ns: serialize_ns,
+        deserialize_time_ns: deserialize_ns,
+    });
+}
+
+// Print multi-variant payload results, one row per format and variant
+fn print_variant_results_table(title: &str, results: &Mutex<VariantResultsMap>, variants: &[&str]) {
+    println!("\n{:-^134}", format!(" {} ", title));
+
+    println!("{:<24} | {:<7} | {:<12} | {:<23} | {:<21} | {:<25} | {:<23}",
+             "Format",
+             "Payload",
+             "Size (bytes)",
+             "Serialization Time (ns)",
+             "Serialization Ops/sec",
+             "Deserialization Time (ns)",
+             "Deserialization Ops/sec");
+
+    println!("{:-<24}-+-{:-<7}-+-{:-<12}-+-{:-<23}-+-{:-<21}-+-{:-<25}-+-{:-<23}",
+             "", "", "", "", "", "", "");
+
+    let results = results.lock().unwrap();
+
+    // Group rows by format, variants in the given order
+    let mut keys: Vec<&(String, &str)> = results.keys().collect();
+    keys.sort_by_key(|(format, variant)| (format.clone(), variants.iter().position(|label| label == variant)));
+
+    for key in keys {
+        let result = &results[key];
+        println!("{:<24} | {:<7} | {:<12} | {:<23.2} | {:<21} | {:<25.2} | {:<23}",
+                 key.0,
+                 key.1,
+                 result.serialized_size,
+                 result.serialize_time_ns,
+                 format!("{} ops/sec", (1_000_000_000.0 / result.serialize_time_ns) as u64),
+                 result.deserialize_time_ns,
+                 format!("{} ops/sec", (1_000_000_000.0 / result.deserialize_time_ns) as u64));
+    }
+
+    println!("{:-^134}", "");
+}
+
+// Print collection results as time and bytes per record at the shortest and
+// longest length; the ratio between the two shows how well a format amortizes
+// its fixed per-call overhead
+fn print_collection_table() {
+    let (shortest, longest) = (COLLECTION_LENGTHS[0], COLLECTION_LENGTHS[COLLECTION_LENGTHS.len() - 1]);
+    let column = |what: &str, (length, _): (&str, usize)| format!("{} ns/record (n={})", what, length);
+
+    println!("\n{:-^136}", " Collection Results (per record) ");
+
+    println!("{:<26} | {:<16} | {:<24} | {:<24} | {:<24} | {:<24}",
+             "Format",
+             format!("Bytes/record (n={})", longest.0),
+             column("Ser", shortest),
+             column("Ser", longest),
+             column("De", shortest),
+             column("De", longest));
+
+    println!("{:-<26}-+-{:-<16}-+-{:-<24}-+-{:-<24}-+-{:-<24}-+-{:-<24}",
+             "", "", "", "", "", "");
+
+    let results = COLLECTION_RESULTS.lock().unwrap();
+
+    let mut groups: Vec<&String> = results.keys().map(|(group, _)| group).collect();
+    groups.sort();
+    groups.dedup();
+
+    for group in groups {
+        // Both ends are needed for the comparison
+        let (Some(first), Some(last)) = (results.get(&(group.clone(), shortest.0)), results.get(&(group.clone(), longest.0))) else {
+            continue;
+        };
+        let per_record = |ns: f64, (_, records): (&str, usize)| ns / records as f64;
+
+        println!("{:<26} | {:<16.1} | {:<24.2} | {:<24.2} | {:<24.2} | {:<24.2}",
+                 group,
+                 last.serialized_size as f64 / longest.1 as f64,
+                 per_record(first.serialize_time_ns, shortest),
+                 per_record(last.serialize_time_ns, longest),
+                 per_record(first.deserialize_time_ns, shortest),
+                 per_record(last.deserialize_time_ns, longest));
+    }
+
+    println!("{:-^136}", "");
+}
+
+// Store batch throughput results; MB/s is the serialized size over the time per batch
+fn store_throughput_results(group_name: &str, batch_size: usize, serialize_ns: f64, deserialize_ns: f64) {
+    let serialized_size = PAYLOAD_SIZES.lock().unwrap().get(group_name).copied().unwrap_or(0);
+    let mb_per_sec = |ns: f64| serialized_size as f64 / ns * 1_000_000_000.0 / 1_000_000.0;
+
+    let mut results = THROUGHPUT_RESULTS.lock().unwrap();
+    results.insert(group_name.to_string(), ThroughputResults {
+        batch_size,
+        serialized_size,
+        serialize_mb_per_sec: mb_per_sec(serialize_ns),
+        deserialize_mb_per_sec: mb_per_sec(deserialize_ns),
+    });
+}
+
+// Print batch throughput results as a formatted table
+fn print_throughput_table() {
+    println!("\n{:-^108}", " Batch Throughput Results ");
+
+    println!("{:<20} | {:<8} | {:<12} | {:<27} | {:<29}",
+             "Format",
+             "Items",
+             "Size (bytes)",
+             "Serialize Throughput (MB/s)",
+             "Deserialize Throughput (MB/s)");
+
+    println!("{:-<20}-+-{:-<8}-+-{:-<12}-+-{:-<27}-+-{:-<29}",
+             "", "", "", "", "");
+
+    let results = THROUGHPUT_RESULTS.lock().unwrap();
+
+    let mut formats: Vec<&String> = results.keys().collect();
+    formats.sort();
+
+    for format in formats {
+        if let Some(result) = results.get(format) {
+            println!("{:<20} | {:<8} | {:<12} | {:<27.2} | {:<29.2}",
+                     format,
+                     result.batch_size,
+                     result.serialized_size,
+                     result.serialize_mb_per_sec,
+                     result.deserialize_mb_per_sec);
+        }
+    }
+
+    println!("{:-^108}", "");
+}
+
+// Send `MetricsData::float_precision_sample()` through every format and
+// compare each value's `to_bits()`. A format that changes any value, or can't
+// encode or decode the vector at all, is recorded in `LOSSY_FLOAT_FORMATS` and
+// flagged in the results table rather than stopping the run. BCS is left
+// out, as it has no float type to lose precision in
+fn roundtrip_float_precision_check() {
+    let data = MetricsData::float_precision_sample();
+    let check = |format: &'static str, decoded: Option<MetricsData>| {
+        let lost = match decoded {
+            Some(decoded) if decoded.values.len() == data.values.len() => {
+                decoded.values.iter().zip(&data.values).filter(|(a, b)| a.to_bits() != b.to_bits()).count()
+            }
+            _ => data.values.len(),
+        };
+        if lost > 0 {
+            eprintln!("Warning: {}: {} of {} f64 values did not survive the roundtrip bit for bit", format, lost, data.values.len());
+            LOSSY_FLOAT_FORMATS.lock().unwrap().insert(format, lost);
+        }
+    };
+    check("bincode", serialize(&data).ok().and_then(|bytes| deserialize(&bytes).ok()));
+    check("bitcode", bitcode::serialize(&data).ok().and_then(|bytes| bitcode::deserialize(&bytes).ok()));
+    check("protobuf", proto::MetricsData::parse_from_bytes(&payloads::proto_metrics(&data).write_to_bytes().unwrap()).ok()
+        .map(|message| payloads::metrics_from_proto(&message)));
+    let message = payloads::quick_proto_metrics(&data);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    check("quick_protobuf", quick_proto::MetricsData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).ok()
+        .map(|decoded| MetricsData { timestamps: decoded.timestamps, values: decoded.values }));
+    check("serde_json", to_string(&data).ok().and_then(|text| from_str(&text).ok()));
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::to_vec(&data).ok().and_then(|mut bytes| simd_json::serde::from_slice(&mut bytes).ok()));
+    check("sonic_rs", sonic_rs::to_string(&data).ok().and_then(|text| sonic_rs::from_str(&text).ok()));
+    check("serde_yaml", serde_yaml::to_string(&data).ok().and_then(|text| serde_yaml::from_str(&text).ok()));
+    check("ron", ron::to_string(&data).ok().and_then(|text| ron::from_str(&text).ok()));
+    check("toml", toml::to_string(&data).ok().and_then(|text| toml::from_str(&text).ok()));
+    check("xml", quick_xml::se::to_string(&data).ok().and_then(|text| quick_xml::de::from_str(&text).ok()));
+    check("borsh", borsh::to_vec(&data).ok().and_then(|bytes| MetricsData::try_from_slice(&bytes).ok()));
+    check("musli_storage", musli::storage::to_vec(&data).ok().and_then(|bytes| musli::storage::from_slice(&bytes).ok()));
+    check("musli_wire", musli::wire::to_vec(&data).ok().and_then(|bytes| musli::wire::from_slice(&bytes).ok()));
+    check("flexbuffers", flexbuffers::to_vec(&data).ok().and_then(|bytes| flexbuffers::from_slice(&bytes).ok()));
+    check("xdr", serde_xdr::to_bytes(&data).ok().and_then(|bytes| serde_xdr::from_bytes::<_, MetricsData>(bytes).ok()));
+    check("savefile", savefile::save_to_mem(SAVEFILE_VERSION, &data).ok().and_then(|bytes| savefile::load_from_mem(&bytes, SAVEFILE_VERSION).ok()));
+    check("pickle", serde_pickle::to_vec(&data, SerOptions::new()).ok().and_then(|bytes| serde_pickle::from_slice(&bytes, DeOptions::new()).ok()));
+    check("speedy", data.write_to_vec_with_ctx(Endianness::LittleEndian).ok()
+        .and_then(|bytes| MetricsData::read_from_buffer_with_ctx(Endianness::LittleEndian, &bytes).ok()));
+}
+
+// Whether `group` belongs to a format `roundtrip_float_precision_check` found lossy
+fn loses_float_precision(group: &str) -> bool {
+    LOSSY_FLOAT_FORMATS.lock().unwrap().keys()
+        .any(|format| group == *format || group.starts_with(&format!("{}_", format)))
+}
+
+// Compare a decoded value against the original, field by field
+fn assert_test_data_eq(format: &str, expected: &TestData, id: u32, name: &str, active: bool) {
+    assert_eq!(expected.id, id, "{}: `id` did not survive the roundtrip", format);
+    assert_eq!(expected.name, name, "{}: `name` did not survive the roundtrip", format);
+    assert_eq!(expected.active, active, "{}: `active` did not survive the roundtrip", format);
+}
+
+// Serialize and deserialize once per format so corrupted output fails fast
+fn roundtrip_correctness_check() {
+    let test_data = standard_test_data();
+
+    let decoded = deserialize::<TestData>(&serialize(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("bincode", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = bitcode::deserialize::<TestData>(&bitcode::serialize(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("bitcode", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = from_bytes::<TestData>(&to_bytes(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("bcs", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let proto_data = proto_test_data(&test_data);
+    let decoded = proto::TestData::parse_from_bytes(&proto_data.write_to_bytes().unwrap()).unwrap();
+    assert_test_data_eq("protobuf", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let serialized_data = serialize_quick_protobuf(&quick_proto_test_data(&test_data));
+    let decoded = parse_quick_protobuf(&serialized_data);
+    assert_test_data_eq("quick_protobuf", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = prost_proto::TestData::decode(serialize_prost(&prost_test_data(&test_data)).as_slice()).unwrap();
+    assert_test_data_eq("prost", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let serialized_data = serialize_capnp(&build_capnp_message(&test_data));
+    let message = capnp::serialize::read_message_from_flat_slice(&mut serialized_data.as_slice(), capnp::message::ReaderOptions::new()).unwrap();
+    let decoded = message.get_root::<message_capnp::test_data::Reader>().unwrap();
+    assert_test_data_eq("capnp", &test_data, decoded.get_id(), decoded.get_name().unwrap().to_str().unwrap(), decoded.get_active());
+
+    let decoded = from_str::<TestData>(&to_string(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("serde_json", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let serialized_data = to_string(&test_data).unwrap();
+    let decoded = from_str::<TestDataBorrowed>(&serialized_data).unwrap();
+    assert_test_data_eq("serde_json_borrowed", &test_data, decoded.id, decoded.name, decoded.active);
+
+    let serialized_data = serialize(&test_data).unwrap();
+    let decoded = deserialize::<TestDataBorrowed>(&serialized_data).unwrap();
+    assert_test_data_eq("bincode_borrowed", &test_data, decoded.id, decoded.name, decoded.active);
+
+    let decoded = limited_bincode_options().deserialize::<TestData>(&serialized_data).unwrap();
+    assert_test_data_eq("bincode_limited", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let value = serde_json::to_value(&test_data).unwrap();
+    assert_eq!(serde_json::from_slice::<Value>(&serde_json::to_vec(&value).unwrap()).unwrap(), value, "json_value: `Value` did not survive the roundtrip");
+    assert_eq!(flexbuffers::from_slice::<Value>(&flexbuffers::to_vec(&value).unwrap()).unwrap(), value, "flexbuffers_value: `Value` did not survive the roundtrip");
+    let decoded = serde_json::from_value::<TestData>(value).unwrap();
+    assert_test_data_eq("json_value", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        let mut json = simd_json::serde::to_string(&test_data).unwrap();
+        // SAFETY: `json` is dropped right after parsing, so any invalid UTF-8
+        // simd-json leaves behind in it is never observed
+        let decoded = unsafe { simd_json::serde::from_str::<TestData>(&mut json) }.unwrap();
+        assert_test_data_eq("simd_json", &test_data, decoded.id, &decoded.name, decoded.active);
+    }
+
+    let decoded = sonic_rs::from_str::<TestData>(&sonic_rs::to_string(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("sonic_rs", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = serde_yaml::from_str::<TestData>(&serde_yaml::to_string(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("serde_yaml", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = ron::from_str::<TestData>(&ron::to_string(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("ron", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = toml::from_str::<TestData>(&toml::to_string(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("toml", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = quick_xml::de::from_str::<TestData>(&quick_xml::se::to_string(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("xml", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = TestData::try_from_slice(&borsh::to_vec(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("borsh", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = musli::storage::from_slice::<TestData>(&musli::storage::to_vec(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("musli_storage", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = musli::wire::from_slice::<TestData>(&musli::wire::to_vec(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("musli_wire", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let nano_data = nano_test_data(&test_data);
+    let decoded = NanoTestData::deserialize_json(&nano_data.serialize_json()).unwrap();
+    assert_test_data_eq("nanoserde_json", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = NanoTestData::deserialize_bin(&nano_data.serialize_bin()).unwrap();
+    assert_test_data_eq("nanoserde_bin", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = serde_xdr::from_bytes::<_, TestData>(serde_xdr::to_bytes(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("xdr", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = savefile::load_from_mem::<TestData>(&savefile::save_to_mem(SAVEFILE_VERSION, &test_data).unwrap(), SAVEFILE_VERSION).unwrap();
+    assert_test_data_eq("savefile", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = serde_pickle::from_slice::<TestData>(&serde_pickle::to_vec(&test_data, SerOptions::new()).unwrap(), DeOptions::new()).unwrap();
+    assert_test_data_eq("pickle", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = rasn::der::decode::<DerTestData>(&rasn::der::encode(&der_test_data(&test_data)).unwrap()).unwrap();
+    assert_test_data_eq("der", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let serialized_data = serialize_alkahest(&test_data);
+    let decoded = alkahest::deserialize::<AlkahestTestData, AlkahestTestDataView>(&serialized_data).unwrap();
+    assert_test_data_eq("alkahest", &test_data, decoded.id, decoded.name, decoded.active);
+
+    let serialized_data = test_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap();
+    let decoded = TestData::read_from_buffer_with_ctx(Endianness::LittleEndian, &serialized_data).unwrap();
+    assert_test_data_eq("speedy", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let schema = avro_schema();
+    let serialized_data = apache_avro::to_avro_datum(&schema, avro_record(&schema, &test_data)).unwrap();
+    let value = apache_avro::from_avro_datum(&schema, &mut serialized_data.as_slice(), None).unwrap();
+    let decoded = apache_avro::from_value::<TestData>(&value).unwrap();
+    assert_test_data_eq("avro", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let decoded = flexbuffers::from_slice::<TestData>(&flexbuffers::to_vec(&test_data).unwrap()).unwrap();
+    assert_test_data_eq("flexbuffers", &test_data, decoded.id, &decoded.name, decoded.active);
+
+    let (buffer, head) = build_flatbuffer(&test_data);
+    let decoded = fbs::root_as_test_data(&buffer[head..]).unwrap();
+    assert_test_data_eq("flatbuffers", &test_data, decoded.id(), decoded.name().unwrap_or_default(), decoded.active());
+
+    // The hand-written sample, and the one `#[derive(BenchmarkData)]` fills in
+    for nested in [NestedTestData::sample(), NestedTestData::default_instance()] {
+        let check = |format: &str, decoded: NestedTestData| assert_eq!(decoded, nested, "{}: nested payload did not survive the roundtrip", format);
+        check("bincode", deserialize(&serialize(&nested).unwrap()).unwrap());
+        check("bcs", from_bytes(&to_bytes(&nested).unwrap()).unwrap());
+        let message = payloads::proto_nested(&nested);
+        assert_eq!(proto::NestedTestData::parse_from_bytes(&message.write_to_bytes().unwrap()).unwrap(), message, "protobuf: nested payload did not survive the roundtrip");
+        let message = payloads::quick_proto_nested(&nested);
+        let mut serialized_data = Vec::with_capacity(message.get_size());
+        message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+        let decoded = quick_proto::NestedTestData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+        assert_eq!(decoded, message, "quick_protobuf: nested payload did not survive the roundtrip");
+        check("serde_json", from_str(&to_string(&nested).unwrap()).unwrap());
+        #[cfg(target_arch = "x86_64")]
+        check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&nested).unwrap()).unwrap());
+        check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&nested).unwrap()).unwrap());
+        check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&nested).unwrap()).unwrap());
+        check("ron", ron::from_str(&ron::to_string(&nested).unwrap()).unwrap());
+        check("borsh", NestedTestData::try_from_slice(&borsh::to_vec(&nested).unwrap()).unwrap());
+        check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&nested).unwrap()).unwrap());
+        check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&nested).unwrap()).unwrap());
+        let message = payloads::nano_nested(&nested);
+        assert_eq!(NanoNestedTestData::deserialize_json(&message.serialize_json()).unwrap(), message, "nanoserde_json: nested payload did not survive the roundtrip");
+        assert_eq!(NanoNestedTestData::deserialize_bin(&message.serialize_bin()).unwrap(), message, "nanoserde_bin: nested payload did not survive the roundtrip");
+        check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&nested).unwrap()).unwrap());
+        check("xdr", serde_xdr::from_bytes::<_, NestedTestData>(serde_xdr::to_bytes(&nested).unwrap()).unwrap());
+        check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &nested).unwrap(), SAVEFILE_VERSION).unwrap());
+        check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&nested, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+        let message = payloads::der_nested(&nested);
+        assert_eq!(rasn::der::decode::<DerNestedTestData>(&rasn::der::encode(&message).unwrap()).unwrap(), message, "der: nested payload did not survive the roundtrip");
+        check("speedy", NestedTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, &nested.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+    }
+
+    let events = Event::sample();
+
+    assert_eq!(deserialize::<Vec<Event>>(&serialize(&events).unwrap()).unwrap(), events, "bincode: enum payload did not survive the roundtrip");
+    assert_eq!(from_bytes::<Vec<Event>>(&to_bytes(&events).unwrap()).unwrap(), events, "bcs: enum payload did not survive the roundtrip");
+    let decoded = proto::EventLog::parse_from_bytes(&payloads::proto_events(&events).write_to_bytes().unwrap()).unwrap();
+    assert_eq!(payloads::events_from_proto(&decoded), events, "protobuf: enum payload did not survive the roundtrip");
+    assert_eq!(from_str::<Vec<Event>>(&to_string(&events).unwrap()).unwrap(), events, "serde_json: enum payload did not survive the roundtrip");
+    assert_eq!(serde_yaml::from_str::<Vec<Event>>(&serde_yaml::to_string(&events).unwrap()).unwrap(), events, "serde_yaml: enum payload did not survive the roundtrip");
+    assert_eq!(Vec::<Event>::try_from_slice(&borsh::to_vec(&events).unwrap()).unwrap(), events, "borsh: enum payload did not survive the roundtrip");
+    assert_eq!(musli::storage::from_slice::<Vec<Event>>(&musli::storage::to_vec(&events).unwrap()).unwrap(), events, "musli_storage: enum payload did not survive the roundtrip");
+    assert_eq!(musli::wire::from_slice::<Vec<Event>>(&musli::wire::to_vec(&events).unwrap()).unwrap(), events, "musli_wire: enum payload did not survive the roundtrip");
+    #[cfg(target_arch = "x86_64")]
+    assert_eq!(simd_json::serde::from_slice::<Vec<Event>>(&mut simd_json::serde::to_vec(&events).unwrap()).unwrap(), events, "simd_json: enum payload did not survive the roundtrip");
+    assert_eq!(sonic_rs::from_str::<Vec<Event>>(&sonic_rs::to_string(&events).unwrap()).unwrap(), events, "sonic_rs: enum payload did not survive the roundtrip");
+    assert_eq!(ron::from_str::<Vec<Event>>(&ron::to_string(&events).unwrap()).unwrap(), events, "ron: enum payload did not survive the roundtrip");
+    assert_eq!(flexbuffers::from_slice::<Vec<Event>>(&flexbuffers::to_vec(&events).unwrap()).unwrap(), events, "flexbuffers: enum payload did not survive the roundtrip");
+    assert_eq!(serde_xdr::from_bytes::<_, Vec<Event>>(&serde_xdr::to_bytes(&events).unwrap()).unwrap(), events, "xdr: enum payload did not survive the roundtrip");
+    assert_eq!(savefile::load_from_mem::<Vec<Event>>(&savefile::save_to_mem(SAVEFILE_VERSION, &events).unwrap(), SAVEFILE_VERSION).unwrap(), events, "savefile: enum payload did not survive the roundtrip");
+    assert_eq!(serde_pickle::from_slice::<Vec<Event>>(&serde_pickle::to_vec(&events, SerOptions::new()).unwrap(), DeOptions::new()).unwrap(), events, "pickle: enum payload did not survive the roundtrip");
+    assert_eq!(Vec::<Event>::read_from_buffer_with_ctx(Endianness::LittleEndian, &events.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap(), events, "speedy: enum payload did not survive the roundtrip");
+
+    let maps = MapData::sample();
+
+    assert_eq!(deserialize::<MapData>(&serialize(&maps).unwrap()).unwrap(), maps, "bincode: map payload did not survive the roundtrip");
+    let decoded = proto::MapData::parse_from_bytes(&payloads::proto_map_data(&maps).write_to_bytes().unwrap()).unwrap();
+    assert_eq!(payloads::map_data_from_proto(&decoded), maps, "protobuf: map payload did not survive the roundtrip");
+    let serialized_data = {
+        let message = payloads::quick_proto_map_data(&maps);
+        let mut buffer = Vec::with_capacity(message.get_size());
+        message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+        buffer
+    };
+    let decoded = quick_proto::MapData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, payloads::quick_proto_map_data(&maps), "quick_protobuf: map payload did not survive the roundtrip");
+    assert_eq!(from_str::<MapData>(&to_string(&maps).unwrap()).unwrap(), maps, "serde_json: map payload did not survive the roundtrip");
+    assert_eq!(sonic_rs::from_str::<MapData>(&sonic_rs::to_string(&maps).unwrap()).unwrap(), maps, "sonic_rs: map payload did not survive the roundtrip");
+    assert_eq!(serde_yaml::from_str::<MapData>(&serde_yaml::to_string(&maps).unwrap()).unwrap(), maps, "serde_yaml: map payload did not survive the roundtrip");
+    assert_eq!(ron::from_str::<MapData>(&ron::to_string(&maps).unwrap()).unwrap(), maps, "ron: map payload did not survive the roundtrip");
+    assert_eq!(MapData::try_from_slice(&borsh::to_vec(&maps).unwrap()).unwrap(), maps, "borsh: map payload did not survive the roundtrip");
+    assert_eq!(musli::storage::from_slice::<MapData>(&musli::storage::to_vec(&maps).unwrap()).unwrap(), maps, "musli_storage: map payload did not survive the roundtrip");
+    assert_eq!(musli::wire::from_slice::<MapData>(&musli::wire::to_vec(&maps).unwrap()).unwrap(), maps, "musli_wire: map payload did not survive the roundtrip");
+    assert_eq!(savefile::load_from_mem::<MapData>(&savefile::save_to_mem(SAVEFILE_VERSION, &maps).unwrap(), SAVEFILE_VERSION).unwrap(), maps, "savefile: map payload did not survive the roundtrip");
+    assert_eq!(serde_pickle::from_slice::<MapData>(&serde_pickle::to_vec(&maps, SerOptions::new()).unwrap(), DeOptions::new()).unwrap(), maps, "pickle: map payload did not survive the roundtrip");
+    assert_eq!(MapData::read_from_buffer_with_ctx(Endianness::LittleEndian, &maps.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap(), maps, "speedy: map payload did not survive the roundtrip");
+
+    let map_test_data = MapTestData::sample();
+    let check = |format: &str, decoded: MapTestData| assert_eq!(decoded, map_test_data, "{}: string map payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&map_test_data).unwrap()).unwrap());
+    // Equal as maps only: BCS writes the entries sorted by key
+    check("bcs", from_bytes(&to_bytes(&map_test_data).unwrap()).unwrap());
+    check("protobuf", payloads::map_test_data_from_proto(&proto::MapTestData::parse_from_bytes(&payloads::proto_map_test_data(&map_test_data).write_to_bytes().unwrap()).unwrap()));
+    let message = payloads::quick_proto_map_test_data(&map_test_data);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::MapTestData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, message, "quick_protobuf: string map payload did not survive the roundtrip");
+    check("prost", payloads::map_test_data_from_prost(&prost_proto::MapTestData::decode(payloads::prost_map_test_data(&map_test_data).encode_to_vec().as_slice()).unwrap()));
+    let serialized_data = serialize_capnp_map_test_data(&map_test_data);
+    let message = capnp::serialize::read_message_from_flat_slice(&mut serialized_data.as_slice(), capnp::message::ReaderOptions::new()).unwrap();
+    check("capnp", map_test_data_from_capnp(message.get_root().unwrap()).unwrap());
+    let serialized_data = build_flatbuffer_map_test_data(&map_test_data);
+    check("flatbuffers", map_test_data_from_flatbuffer(flatbuffers::root::<fbs::MapTestData>(&serialized_data).unwrap()).unwrap());
+    let schema = avro_map_test_data_schema();
+    let serialized_data = apache_avro::to_avro_datum(&schema, avro_map_test_data_record(&schema, &map_test_data)).unwrap();
+    check("avro", apache_avro::from_value(&apache_avro::from_avro_datum(&schema, &mut serialized_data.as_slice(), None).unwrap()).unwrap());
+    let nano = payloads::nano_map_test_data(&map_test_data);
+    assert_eq!(NanoMapTestData::deserialize_json(&nano.serialize_json()).unwrap(), nano, "nanoserde_json: string map payload did not survive the roundtrip");
+    assert_eq!(NanoMapTestData::deserialize_bin(&nano.serialize_bin()).unwrap(), nano, "nanoserde_bin: string map payload did not survive the roundtrip");
+    check("serde_json", from_str(&to_string(&map_test_data).unwrap()).unwrap());
+    check("borsh", MapTestData::try_from_slice(&borsh::to_vec(&map_test_data).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&map_test_data).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&map_test_data).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &map_test_data).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("speedy", MapTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, &map_test_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    for (variant, data) in sparse_payloads() {
+        let check = |format: &str, decoded: SparseData| assert_eq!(decoded, data, "{}: {} payload did not survive the roundtrip", format, variant);
+
+        check("bincode", deserialize(&serialize(&data).unwrap()).unwrap());
+        check("protobuf", payloads::sparse_from_proto(&proto::SparseData::parse_from_bytes(&payloads::proto_sparse(&data).write_to_bytes().unwrap()).unwrap()));
+        let message = payloads::quick_proto_sparse(&data);
+        let mut serialized_data = Vec::with_capacity(message.get_size());
+        message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+        let decoded = quick_proto::SparseData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+        assert_eq!(decoded, message, "quick_protobuf: {} payload did not survive the roundtrip", variant);
+        check("serde_json", from_str(&to_string(&data).unwrap()).unwrap());
+        #[cfg(target_arch = "x86_64")]
+        check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&data).unwrap()).unwrap());
+        check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&data).unwrap()).unwrap());
+        check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&data).unwrap()).unwrap());
+        check("ron", ron::from_str(&ron::to_string(&data).unwrap()).unwrap());
+        check("toml", toml::from_str(&toml::to_string(&data).unwrap()).unwrap());
+        check("borsh", SparseData::try_from_slice(&borsh::to_vec(&data).unwrap()).unwrap());
+        check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&data).unwrap()).unwrap());
+        check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&data).unwrap()).unwrap());
+        check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&data).unwrap()).unwrap());
+        check("xdr", serde_xdr::from_bytes::<_, SparseData>(serde_xdr::to_bytes(&data).unwrap()).unwrap());
+        check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &data).unwrap(), SAVEFILE_VERSION).unwrap());
+        check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&data, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+        check("speedy", SparseData::read_from_buffer_with_ctx(Endianness::LittleEndian, &data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+    }
+
+    // Compared bit for bit, so a text format that prints or parses a float
+    // even one ulp off fails here
+    let metrics = MetricsData::sample();
+    let check = |format: &str, decoded: MetricsData| {
+        assert_eq!(decoded.timestamps, metrics.timestamps, "{}: metrics timestamps did not survive the roundtrip", format);
+        let lost = decoded.values.iter().zip(&metrics.values).filter(|(a, b)| a.to_bits() != b.to_bits()).count();
+        assert!(decoded.values.len() == metrics.values.len() && lost == 0, "{}: {} metrics values lost precision in the roundtrip", format, lost);
+    };
+    check("bincode", deserialize(&serialize(&metrics).unwrap()).unwrap());
+    check("protobuf", payloads::metrics_from_proto(&proto::MetricsData::parse_from_bytes(&payloads::proto_metrics(&metrics).write_to_bytes().unwrap()).unwrap()));
+    let message = payloads::quick_proto_metrics(&metrics);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::MetricsData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    check("quick_protobuf", MetricsData { timestamps: decoded.timestamps, values: decoded.values });
+    check("serde_json", from_str(&to_string(&metrics).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&metrics).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&metrics).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&metrics).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&metrics).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&metrics).unwrap()).unwrap());
+    check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&metrics).unwrap()).unwrap());
+    check("borsh", MetricsData::try_from_slice(&borsh::to_vec(&metrics).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&metrics).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&metrics).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&metrics).unwrap()).unwrap());
+    check("xdr", serde_xdr::from_bytes::<_, MetricsData>(serde_xdr::to_bytes(&metrics).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &metrics).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&metrics, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", MetricsData::read_from_buffer_with_ctx(Endianness::LittleEndian, &metrics.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    let text = UnicodeData::sample();
+    let check = |format: &str, decoded: UnicodeData| assert_eq!(decoded, text, "{}: unicode payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&text).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&text).unwrap()).unwrap());
+    check("protobuf", payloads::unicode_from_proto(&proto::UnicodeData::parse_from_bytes(&payloads::proto_unicode(&text).write_to_bytes().unwrap()).unwrap()));
+    let message = payloads::quick_proto_unicode(&text);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::UnicodeData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, message, "quick_protobuf: unicode payload did not survive the roundtrip");
+    check("serde_json", from_str(&to_string(&text).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&text).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&text).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&text).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&text).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&text).unwrap()).unwrap());
+    check("borsh", UnicodeData::try_from_slice(&borsh::to_vec(&text).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&text).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&text).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&text).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &text).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&text, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", UnicodeData::read_from_buffer_with_ctx(Endianness::LittleEndian, &text.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    let data = BigStringData::sample();
+    let check = |format: &str, decoded: BigStringData| assert_eq!(decoded, data, "{}: big string payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&data).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&data).unwrap()).unwrap());
+    let message = proto::BigString { text: data.text.clone(), ..Default::default() };
+    check("protobuf", BigStringData { text: proto::BigString::parse_from_bytes(&message.write_to_bytes().unwrap()).unwrap().text });
+    let message = quick_proto::BigString { text: Cow::Borrowed(&data.text) };
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::BigString::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, message, "quick_protobuf: big string payload did not survive the roundtrip");
+    let message = prost_proto::BigString { text: data.text.clone() };
+    check("prost", BigStringData { text: prost_proto::BigString::decode(message.encode_to_vec().as_slice()).unwrap().text });
+    check("serde_json", from_str(&to_string(&data).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&data).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&data).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&data).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&data).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&data).unwrap()).unwrap());
+    check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&data).unwrap()).unwrap());
+    check("borsh", BigStringData::try_from_slice(&borsh::to_vec(&data).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&data).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&data).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&data).unwrap()).unwrap());
+    check("xdr", serde_xdr::from_bytes::<_, BigStringData>(serde_xdr::to_bytes(&data).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &data).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&data, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", BigStringData::read_from_buffer_with_ctx(Endianness::LittleEndian, &data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    // Once as sampled and once with `updated_at` unset
+    for data in [TimestampData::sample(), TimestampData { updated_at: None, ..TimestampData::sample() }] {
+        let check = |format: &str, decoded: TimestampData| assert_eq!(decoded, data, "{}: timestamp payload lost precision in the roundtrip", format);
+        check("bincode", deserialize(&serialize(&data).unwrap()).unwrap());
+        check("bcs", from_bytes(&to_bytes(&data).unwrap()).unwrap());
+        check("protobuf", payloads::timestamp_data_from_proto(&proto::TimestampData::parse_from_bytes(&payloads::proto_timestamp_data(&data).write_to_bytes().unwrap()).unwrap()).unwrap());
+        let message = payloads::quick_proto_timestamp_data(&data);
+        let mut serialized_data = Vec::with_capacity(message.get_size());
+        message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+        let decoded = quick_proto::TimestampData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+        assert_eq!(decoded, message, "quick_protobuf: timestamp payload lost precision in the roundtrip");
+        check("prost", payloads::timestamp_data_from_prost(&prost_proto::TimestampData::decode(payloads::prost_timestamp_data(&data).encode_to_vec().as_slice()).unwrap()).unwrap());
+        check("serde_json", from_str(&to_string(&data).unwrap()).unwrap());
+        #[cfg(target_arch = "x86_64")]
+        check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&data).unwrap()).unwrap());
+        check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&data).unwrap()).unwrap());
+        check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&data).unwrap()).unwrap());
+        check("ron", ron::from_str(&ron::to_string(&data).unwrap()).unwrap());
+        check("toml", toml::from_str(&toml::to_string(&data).unwrap()).unwrap());
+        // quick-xml writes `None` as an empty `<updated_at/>`, which it reads back as an empty string
+        if data.updated_at.is_some() {
+            check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&data).unwrap()).unwrap());
+        }
+        check("borsh", TimestampData::try_from_slice(&borsh::to_vec(&data).unwrap()).unwrap());
+        check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&data).unwrap()).unwrap());
+        check("xdr", serde_xdr::from_bytes::<_, TimestampData>(serde_xdr::to_bytes(&data).unwrap()).unwrap());
+        check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&data, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+        check("speedy", TimestampData::read_from_buffer_with_ctx(Endianness::LittleEndian, &data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+    }
+
+    let wide = WideInts::sample();
+    let check = |format: &str, decoded: WideInts| assert_eq!(decoded, wide, "{}: 128-bit integers did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&wide).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&wide).unwrap()).unwrap());
+    let message = proto::WideInts::parse_from_bytes(&payloads::proto_wide_ints(&wide).write_to_bytes().unwrap()).unwrap();
+    check("protobuf", WideInts::from_decimal(&message.amount, &message.delta).unwrap());
+    let message = payloads::quick_proto_wide_ints(&wide);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::WideInts::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    check("quick_protobuf", WideInts::from_decimal(&decoded.amount, &decoded.delta).unwrap());
+    let message = prost_proto::WideInts::decode(payloads::prost_wide_ints(&wide).encode_to_vec().as_slice()).unwrap();
+    check("prost", WideInts::from_decimal(&message.amount, &message.delta).unwrap());
+    check("serde_json", from_str(&to_string(&wide).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&wide).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&wide).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&wide).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&wide).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&wide).unwrap()).unwrap());
+    check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&wide).unwrap()).unwrap());
+    check("borsh", WideInts::try_from_slice(&borsh::to_vec(&wide).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&wide).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&wide).unwrap()).unwrap());
+    check("xdr", serde_xdr::from_bytes::<_, WideInts>(serde_xdr::to_bytes(&wide).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &wide).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&wide, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", WideInts::read_from_buffer_with_ctx(Endianness::LittleEndian, &wide.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    let deep = DeepNested::sample();
+    let check = |format: &str, decoded: DeepNested| assert_eq!(decoded, deep, "{}: deep nesting payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&deep).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&deep).unwrap()).unwrap());
+    check("protobuf", payloads::deep_from_proto(&proto::DeepNested::parse_from_bytes(&payloads::proto_deep(&deep).write_to_bytes().unwrap()).unwrap()));
+    let message = payloads::quick_proto_deep(&deep);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::DeepNested::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, message, "quick_protobuf: deep nesting payload did not survive the roundtrip");
+    check("prost", payloads::deep_from_prost(&prost_proto::DeepNested::decode(payloads::prost_deep(&deep).encode_to_vec().as_slice()).unwrap()));
+    check("serde_json", from_str(&to_string(&deep).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&deep).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&deep).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&deep).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&deep).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&deep).unwrap()).unwrap());
+    check("borsh", DeepNested::try_from_slice(&borsh::to_vec(&deep).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&deep).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&deep).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&deep).unwrap()).unwrap());
+    check("xdr", serde_xdr::from_bytes::<_, DeepNested>(serde_xdr::to_bytes(&deep).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &deep).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&deep, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", DeepNested::read_from_buffer_with_ctx(Endianness::LittleEndian, &deep.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    let newtype = NewtypeData::sample();
+    let check = |format: &str, decoded: NewtypeData| assert_eq!(decoded, newtype, "{}: newtype payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&newtype).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&newtype).unwrap()).unwrap());
+    check("protobuf", payloads::newtype_from_proto(&proto::NewtypeData::parse_from_bytes(&payloads::proto_newtype(&newtype).write_to_bytes().unwrap()).unwrap()).unwrap());
+    let message = payloads::quick_proto_newtype(&newtype);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::NewtypeData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, message, "quick_protobuf: newtype payload did not survive the roundtrip");
+    check("prost", payloads::newtype_from_prost(&prost_proto::NewtypeData::decode(payloads::prost_newtype(&newtype).encode_to_vec().as_slice()).unwrap()).unwrap());
+    check("serde_json", from_str(&to_string(&newtype).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&newtype).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&newtype).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&newtype).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&newtype).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&newtype).unwrap()).unwrap());
+    check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&newtype).unwrap()).unwrap());
+    check("borsh", NewtypeData::try_from_slice(&borsh::to_vec(&newtype).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&newtype).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&newtype).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&newtype).unwrap()).unwrap());
+    check("xdr", serde_xdr::from_bytes::<_, NewtypeData>(serde_xdr::to_bytes(&newtype).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &newtype).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&newtype, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", NewtypeData::read_from_buffer_with_ctx(Endianness::LittleEndian, &newtype.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    let uuid_data = UuidTestData::sample();
+    let check = |format: &str, decoded: UuidTestData| assert_eq!(decoded, uuid_data, "{}: UUID payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&uuid_data).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&uuid_data).unwrap()).unwrap());
+    check("protobuf", payloads::uuid_data_from_proto(&proto::UuidTestData::parse_from_bytes(&payloads::proto_uuid_data(&uuid_data).write_to_bytes().unwrap()).unwrap()).unwrap());
+    let message = payloads::quick_proto_uuid_data(&uuid_data);
+    let mut serialized_data = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+    let decoded = quick_proto::UuidTestData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+    assert_eq!(decoded, message, "quick_protobuf: UUID payload did not survive the roundtrip");
+    check("prost", payloads::uuid_data_from_prost(&prost_proto::UuidTestData::decode(payloads::prost_uuid_data(&uuid_data).encode_to_vec().as_slice()).unwrap()).unwrap());
+    check("serde_json", from_str(&to_string(&uuid_data).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&uuid_data).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&uuid_data).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&uuid_data).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&uuid_data).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&uuid_data).unwrap()).unwrap());
+    check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&uuid_data).unwrap()).unwrap());
+    check("borsh", UuidTestData::try_from_slice(&borsh::to_vec(&uuid_data).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&uuid_data).unwrap()).unwrap());
+    check("xdr", serde_xdr::from_bytes::<_, UuidTestData>(serde_xdr::to_bytes(&uuid_data).unwrap()).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&uuid_data, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", UuidTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, &uuid_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+
+    // The sample record with each status variant in turn
+    for status in Status::variants() {
+        let enum_data = EnumTestData { status, ..EnumTestData::sample() };
+        let check = |format: &str, decoded: EnumTestData| assert_eq!(decoded, enum_data, "{}: enum data payload did not survive the roundtrip", format);
+        check("bincode", deserialize(&serialize(&enum_data).unwrap()).unwrap());
+        check("bcs", from_bytes(&to_bytes(&enum_data).unwrap()).unwrap());
+        check("protobuf", payloads::enum_data_from_proto(&proto::EnumTestData::parse_from_bytes(&payloads::proto_enum_data(&enum_data).write_to_bytes().unwrap()).unwrap()).unwrap());
+        let message = payloads::quick_proto_enum_data(&enum_data);
+        let mut serialized_data = Vec::with_capacity(message.get_size());
+        message.write_message(&mut Writer::new(&mut serialized_data)).unwrap();
+        let decoded = quick_proto::EnumTestData::from_reader(&mut BytesReader::from_bytes(&serialized_data), &serialized_data).unwrap();
+        assert_eq!(decoded, message, "quick_protobuf: enum data payload did not survive the roundtrip");
+        check("prost", payloads::enum_data_from_prost(&prost_proto::EnumTestData::decode(payloads::prost_enum_data(&enum_data).encode_to_vec().as_slice()).unwrap()).unwrap());
+        check("serde_json", from_str(&to_string(&enum_data).unwrap()).unwrap());
+        #[cfg(target_arch = "x86_64")]
+        check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&enum_data).unwrap()).unwrap());
+        check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&enum_data).unwrap()).unwrap());
+        check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&enum_data).unwrap()).unwrap());
+        check("ron", ron::from_str(&ron::to_string(&enum_data).unwrap()).unwrap());
+        check("toml", toml::from_str(&toml::to_string(&enum_data).unwrap()).unwrap());
+        check("borsh", EnumTestData::try_from_slice(&borsh::to_vec(&enum_data).unwrap()).unwrap());
+        check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&enum_data).unwrap()).unwrap());
+        check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&enum_data).unwrap()).unwrap());
+        check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&enum_data).unwrap()).unwrap());
+        check("xdr", serde_xdr::from_bytes::<_, EnumTestData>(serde_xdr::to_bytes(&enum_data).unwrap()).unwrap());
+        check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &enum_data).unwrap(), SAVEFILE_VERSION).unwrap());
+        check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&enum_data, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+        check("speedy", EnumTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, &enum_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+        let serialized_data = serialize_capnp_enum_data(&enum_data);
+        let message = capnp::serialize::read_message_from_flat_slice(&mut serialized_data.as_slice(), capnp::message::ReaderOptions::new()).unwrap();
+        check("capnp", enum_data_from_capnp(message.get_root().unwrap()).unwrap());
+        let serialized_data = build_flatbuffer_enum_data(&enum_data);
+        check("flatbuffers", enum_data_from_flatbuffer(flatbuffers::root::<fbs::EnumTestData>(&serialized_data).unwrap()).unwrap());
+    }
+
+    let fixture = fixture_data();
+    let check = |format: &str, decoded: FixtureData| assert_eq!(decoded, fixture, "{}: fixture payload did not survive the roundtrip", format);
+    check("bincode", deserialize(&serialize(&fixture).unwrap()).unwrap());
+    check("bcs", from_bytes(&to_bytes(&fixture).unwrap()).unwrap());
+    check("serde_json", from_str(&to_string(&fixture).unwrap()).unwrap());
+    #[cfg(target_arch = "x86_64")]
+    check("simd_json", simd_json::serde::from_slice(&mut simd_json::serde::to_vec(&fixture).unwrap()).unwrap());
+    check("sonic_rs", sonic_rs::from_str(&sonic_rs::to_string(&fixture).unwrap()).unwrap());
+    check("serde_yaml", serde_yaml::from_str(&serde_yaml::to_string(&fixture).unwrap()).unwrap());
+    check("ron", ron::from_str(&ron::to_string(&fixture).unwrap()).unwrap());
+    check("toml", toml::from_str(&toml::to_string(&fixture).unwrap()).unwrap());
+    check("xml", quick_xml::de::from_str(&quick_xml::se::to_string(&fixture).unwrap()).unwrap());
+    check("borsh", FixtureData::try_from_slice(&borsh::to_vec(&fixture).unwrap()).unwrap());
+    check("musli_storage", musli::storage::from_slice(&musli::storage::to_vec(&fixture).unwrap()).unwrap());
+    check("musli_wire", musli::wire::from_slice(&musli::wire::to_vec(&fixture).unwrap()).unwrap());
+    check("flexbuffers", flexbuffers::from_slice(&flexbuffers::to_vec(&fixture).unwrap()).unwrap());
+    check("savefile", savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, &fixture).unwrap(), SAVEFILE_VERSION).unwrap());
+    check("pickle", serde_pickle::from_slice(&serde_pickle::to_vec(&fixture, SerOptions::new()).unwrap(), DeOptions::new()).unwrap());
+    check("speedy", FixtureData::read_from_buffer_with_ctx(Endianness::LittleEndian, &fixture.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap()).unwrap());
+}
+
+// Bincode
+fn benchmark_bincode(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("bincode");
+    
+    group.throughput(Throughput::Bytes(payload_bytes("bincode", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize(&black_box(&test_data)).unwrap())
+        });
+    });
+    
+    let serialized_data = serialize(&test_data).unwrap();
+    
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| deserialize::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+    
+    group.finish();
+    
+    tracker.print_summary("bincode", "Bincode");
+}
+
+// bitcode, through its serde integration; the encoding packs values at bit
+// granularity, so it is the one to compare with bincode on size
+fn benchmark_bitcode(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("bitcode");
+
+    group.throughput(Throughput::Bytes(payload_bytes("bitcode", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| bitcode::serialize(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = bitcode::serialize(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| bitcode::deserialize::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("bitcode", "bitcode");
+
+    let bincode_size = serialize(&test_data).unwrap().len();
+    println!("bitcode serialized size: {} bytes (bincode: {} bytes)\n", serialized_data.len(), bincode_size);
+}
+
+// BCS
+fn benchmark_bcs(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("bcs");
+    
+    group.throughput(Throughput::Bytes(payload_bytes("bcs", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| to_bytes(&black_box(&test_data)).unwrap())
+        });
+    });
+    
+    let serialized_data = to_bytes(&test_data).unwrap();
+    
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| from_bytes::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+    
+    group.finish();
+    
+    tracker.print_summary("bcs", "BCS");
+}
+
+// Protobuf
+fn benchmark_protobuf(c: &mut Criterion) {
+    let test_data = proto::TestData {
+        id: 1,
+        name: "Rust".to_string(),
+        active: true,
+        ..Default::default()
+    };
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("protobuf");
+    
+    group.throughput(Throughput::Bytes(payload_bytes("protobuf", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| test_data.write_to_bytes().unwrap())
+        });
+    });
+    
+    let serialized_data = test_data.write_to_bytes().unwrap();
+    
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| proto::TestData::parse_from_bytes(black_box(&serialized_data)).unwrap())
+        });
+    });
+    
+    group.finish();
+    
+    tracker.print_summary("protobuf", "Protobuf");
+}
+
+// quick-protobuf. Deserialize stops at the borrowed message, whose `Cow` name
+// points into the input, so the zero-copy read is what gets measured
+fn benchmark_quick_protobuf(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let message = quick_proto_test_data(&test_data);
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("quick_protobuf");
+
+    group.throughput(Throughput::Bytes(payload_bytes("quick_protobuf", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_quick_protobuf(black_box(&message)))
+        });
+    });
+
+    let serialized_data = serialize_quick_protobuf(&message);
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| parse_quick_protobuf(black_box(&serialized_data)))
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("quick_protobuf", "quick-protobuf");
+}
+
+// prost, the same `TestData` message as rust-protobuf from prost-build's
+// generated struct; decode copies the name into an owned `String`
+fn benchmark_prost(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let message = prost_test_data(&test_data);
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("prost");
+
+    group.throughput(Throughput::Bytes(payload_bytes("prost", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_prost(black_box(&message)))
+        });
+    });
+
+    let serialized_data = serialize_prost(&message);
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| prost_proto::TestData::decode(black_box(serialized_data.as_slice())).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("prost", "prost");
+}
+
+// Cap'n Proto. Serialize builds the message and writes it with the standard
+// (unpacked) framing; `serialize_packed` does the same with packed framing.
+// Like FlatBuffers, "deserialize" reads every field in place from the buffer
+fn benchmark_capnp(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("capnp");
+
+    group.throughput(Throughput::Bytes(payload_bytes("capnp", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_capnp(&build_capnp_message(black_box(&test_data))))
+        });
+    });
+
+    group.bench_function("serialize_packed", |b| {
+        b.iter(|| serialize_capnp_packed(&build_capnp_message(black_box(&test_data))))
+    });
+
+    let message = build_capnp_message(&test_data);
+    let serialized_data = serialize_capnp(&message);
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| {
+                let mut input = black_box(serialized_data.as_slice());
+                let message = capnp::serialize::read_message_from_flat_slice(&mut input, capnp::message::ReaderOptions::new()).unwrap();
+                let data = message.get_root::<message_capnp::test_data::Reader>().unwrap();
+                black_box((data.get_id(), data.get_name().unwrap().to_str().unwrap(), data.get_active()));
+            })
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("capnp", "Cap'n Proto");
+
+    println!("Cap'n Proto serialized size: {} bytes unpacked, {} bytes packed\n", serialized_data.len(), serialize_capnp_packed(&message).len());
+}
+
+// Serde JSON
+fn benchmark_serde_json(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("serde_json");
+    
+    group.throughput(Throughput::Bytes(payload_bytes("serde_json", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| to_string(&black_box(&test_data)).unwrap())
+        });
+    });
+    
+    let serialized_data = to_string(&test_data).unwrap();
+    
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize" , |b| {
+            b.iter(|| from_str::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    // The byte-oriented API a socket writer would use, skipping the `String`
+    // wrapper and, on the way back, the UTF-8 check of `&str`
+    group.bench_function("serialize_vec", |b| {
+        b.iter(|| serde_json::to_vec(black_box(&test_data)).unwrap())
+    });
+    group.bench_function("deserialize_slice", |b| {
+        b.iter(|| serde_json::from_slice::<TestData>(black_box(serialized_data.as_bytes())).unwrap())
+    });
+
+    // Same bytes parsed through `io::Read`, as when reading from a socket; the
+    // cursor is built (and dropped) outside the timed routine
+    let serialized_bytes = serialized_data.as_bytes().to_vec();
+    group.bench_function("deserialize_reader", |b| {
+        b.iter_batched_ref(
+            || Cursor::new(serialized_bytes.clone()),
+            |reader| serde_json::from_reader::<_, TestData>(reader).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+    
+    group.finish();
+    
+    tracker.print_summary("serde_json", "Serde JSON");
+}
+
+// SIMD JSON, only built where simd-json's SIMD backends are available
+#[cfg(target_arch = "x86_64")]
+fn benchmark_simd_json(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("simd_json");
+
+    group.throughput(Throughput::Bytes(payload_bytes("simd_json", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| simd_json::serde::to_string(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = simd_json::serde::to_string(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    // simd-json parses in place and mutates its input, so every iteration gets
+    // a fresh copy; `iter_batched` keeps that clone out of the measured time
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter_batched(
+                || serialized_data.clone(),
+                // SAFETY: each buffer is a throwaway copy, so any invalid UTF-8
+                // simd-json leaves behind in it is never observed
+                |mut buffer| unsafe { simd_json::serde::from_str::<TestData>(black_box(&mut buffer)) }.unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("simd_json", "SIMD JSON");
+}
+
+// sonic-rs. Like serde_json and simd_json it turns `TestData` into the same
+// JSON text and back, so the three rows under `json_variants` compare directly
+fn benchmark_sonic_rs(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("sonic_rs");
+
+    group.throughput(Throughput::Bytes(payload_bytes("sonic_rs", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| sonic_rs::to_string(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = sonic_rs::to_string(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| sonic_rs::from_str::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("sonic_rs", "sonic-rs");
+}
+
+// Serde YAML
+fn benchmark_serde_yaml(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("serde_yaml");
+
+    group.throughput(Throughput::Bytes(payload_bytes("serde_yaml", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serde_yaml::to_string(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = serde_yaml::to_string(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| serde_yaml::from_str::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("serde_yaml", "Serde YAML");
+}
+
+// RON
+fn benchmark_ron(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("ron");
+
+    group.throughput(Throughput::Bytes(payload_bytes("ron", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| ron::to_string(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = ron::to_string(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| ron::from_str::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("ron", "RON");
+
+    let json_size = to_string(&test_data).unwrap().len();
+    println!("RON serialized size: {} bytes (serde_json: {} bytes)\n", serialized_data.len(), json_size);
+}
+
+// TOML. A TOML document must be a table at the top level, which `TestData`
+// (a struct of scalars) maps onto directly. Bare values, `Vec<TestData>` and
+// unit enum variants have no top-level TOML form, so TOML only runs this
+// single-struct payload
+fn benchmark_toml(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("toml");
+
+    group.throughput(Throughput::Bytes(payload_bytes("toml", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| toml::to_string(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = toml::to_string(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| toml::from_str::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("toml", "TOML");
+
+    let json_size = to_string(&test_data).unwrap().len();
+    println!("TOML serialized size: {} bytes (serde_json: {} bytes)\n", serialized_data.len(), json_size);
+}
+
+// XML through quick-xml's serde integration. `TestData` becomes a `<TestData>`
+// element with one child element per field, so every field name is written
+// twice (opening and closing tag), roughly doubling the size of the JSON text
+fn benchmark_xml(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("xml");
+
+    group.throughput(Throughput::Bytes(payload_bytes("xml", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| quick_xml::se::to_string(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = quick_xml::se::to_string(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| quick_xml::de::from_str::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("xml", "XML");
+
+    let json_size = to_string(&test_data).unwrap().len();
+    println!("XML serialized size: {} bytes (serde_json: {} bytes)\n", serialized_data.len(), json_size);
+}
+
+// Borsh
+fn benchmark_borsh(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("borsh");
+    
+    group.throughput(Throughput::Bytes(payload_bytes("borsh", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| borsh::to_vec(&black_box(&test_data)).unwrap())
+        });
+    });
+    
+    let serialized_data = borsh::to_vec(&test_data).unwrap();
+    
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| TestData::try_from_slice(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+    
+    tracker.print_summary("borsh", "Borsh");
+}
+
+// Musli (storage and wire encodings)
+fn benchmark_musli(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("musli_storage");
+
+    group.throughput(Throughput::Bytes(payload_bytes("musli_storage", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| musli::storage::to_vec(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = musli::storage::to_vec(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| musli::storage::from_slice::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("musli_storage", "Musli (storage)");
+
+    let mut group = c.benchmark_group("musli_wire");
+
+    group.throughput(Throughput::Bytes(payload_bytes("musli_wire", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| musli::wire::to_vec(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = musli::wire::to_vec(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| musli::wire::from_slice::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("musli_wire", "Musli (wire)");
+}
+
+// Nanoserde (JSON and binary)
+fn benchmark_nanoserde(c: &mut Criterion) {
+    let test_data = nano_test_data(&standard_test_data());
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("nanoserde_json");
+
+    group.throughput(Throughput::Bytes(payload_bytes("nanoserde_json", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| black_box(&test_data).serialize_json())
+        });
+    });
+
+    let serialized_data = test_data.serialize_json();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| NanoTestData::deserialize_json(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("nanoserde_json", "Nanoserde (JSON)");
+
+    let mut group = c.benchmark_group("nanoserde_bin");
+
+    group.throughput(Throughput::Bytes(payload_bytes("nanoserde_bin", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| black_box(&test_data).serialize_bin())
+        });
+    });
+
+    let serialized_data = test_data.serialize_bin();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| NanoTestData::deserialize_bin(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("nanoserde_bin", "Nanoserde (binary)");
+}
+
+// FlatBuffers
+fn benchmark_flatbuffers(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("flatbuffers");
+
+    group.throughput(Throughput::Bytes(payload_bytes("flatbuffers", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| build_flatbuffer(black_box(&test_data)))
+        });
+    });
+
+    let (buffer, head) = build_flatbuffer(&test_data);
+    let serialized_data = &buffer[head..];
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    // There is no parse step: "deserialize" verifies the buffer and reads
+    // every field in place through the generated accessors
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| {
+                let data = fbs::root_as_test_data(black_box(serialized_data)).unwrap();
+                (data.id(), data.name(), data.active())
+            })
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("flatbuffers", "FlatBuffers");
+}
+
+// FlexBuffers, FlatBuffers' schema-less encoding, through its serde integration
+fn benchmark_flexbuffers(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("flexbuffers");
+
+    group.throughput(Throughput::Bytes(payload_bytes("flexbuffers", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| flexbuffers::to_vec(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = flexbuffers::to_vec(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| flexbuffers::from_slice::<TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    // Structs are written as FlexBuffers maps, so one field can be read in
+    // place without deserializing the rest
+    group.bench_function("read_id", |b| {
+        b.iter(|| {
+            let root = flexbuffers::Reader::get_root(black_box(serialized_data.as_slice())).unwrap();
+            root.as_map().idx("id").as_u32()
+        })
+    });
+
+    group.finish();
+
+    tracker.print_summary("flexbuffers", "FlexBuffers");
+
+    let json_size = to_string(&test_data).unwrap().len();
+    println!("FlexBuffers serialized size: {} bytes (serde_json: {} bytes)\n", serialized_data.len(), json_size);
+}
+
+// XDR
+fn benchmark_xdr(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("xdr");
+
+    group.throughput(Throughput::Bytes(payload_bytes("xdr", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serde_xdr::to_bytes(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = serde_xdr::to_bytes(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| serde_xdr::from_bytes::<_, TestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("xdr", "XDR");
+
+    // XDR pads every item to a 4-byte boundary: the bool takes a full word and
+    // the string is zero-padded after its length prefix
+    assert_eq!(serialized_data.len() % 4, 0, "XDR output is not 4-byte aligned");
+    let padding = (4 - test_data.name.len() % 4) % 4 + (4 - std::mem::size_of::<bool>());
+    println!("XDR serialized size: {} bytes ({} bytes of alignment padding)\n", serialized_data.len(), padding);
+}
+
+// Savefile
+fn benchmark_savefile(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("savefile");
+
+    group.throughput(Throughput::Bytes(payload_bytes("savefile", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| savefile::save_to_mem(SAVEFILE_VERSION, black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = savefile::save_to_mem(SAVEFILE_VERSION, &test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| savefile::load_from_mem::<TestData>(black_box(&serialized_data), SAVEFILE_VERSION).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("savefile", "Savefile");
+}
+
+// Pickle (Python interop)
+fn benchmark_pickle(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("pickle");
+
+    group.throughput(Throughput::Bytes(payload_bytes("pickle", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serde_pickle::to_vec(black_box(&test_data), SerOptions::new()).unwrap())
+        });
+    });
+
+    let serialized_data = serde_pickle::to_vec(&test_data, SerOptions::new()).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| serde_pickle::from_slice::<TestData>(black_box(&serialized_data), DeOptions::new()).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("pickle", "Pickle");
+
+    // The protocol header, memo and stop opcodes are a fixed framing cost on top
+    // of the field data, which dominates for a payload this small
+    println!("Pickle serialized size: {} bytes\n", serialized_data.len());
+}
+
+// ASN.1 DER
+fn benchmark_der(c: &mut Criterion) {
+    let test_data = der_test_data(&standard_test_data());
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("der");
+
+    group.throughput(Throughput::Bytes(payload_bytes("der", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| rasn::der::encode(black_box(&test_data)).unwrap())
+        });
+    });
+
+    let serialized_data = rasn::der::encode(&test_data).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| rasn::der::decode::<DerTestData>(black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("der", "ASN.1 DER");
+
+    // Every field and the enclosing SEQUENCE carry a tag and length byte (TLV)
+    let bincode_size = serialize(&standard_test_data()).unwrap().len();
+    println!("DER serialized size: {} bytes (bincode: {} bytes)\n", serialized_data.len(), bincode_size);
+}
+
+// Alkahest. Serializes into a caller-provided buffer and deserializes into a
+// view that borrows the name from that buffer, as a shared-memory IPC reader would
+fn benchmark_alkahest(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut buffer = [0u8; ZERO_ALLOC_BUFFER_SIZE];
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("alkahest");
+
+    group.throughput(Throughput::Bytes(payload_bytes("alkahest", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| alkahest::serialize::<AlkahestTestData, _>(black_box(&test_data), &mut buffer).unwrap())
+        });
+    });
+
+    let (len, _) = alkahest::serialize::<AlkahestTestData, _>(&test_data, &mut buffer).unwrap();
+    let serialized_data = &buffer[..len];
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| {
+                let view = alkahest::deserialize::<AlkahestTestData, AlkahestTestDataView>(black_box(serialized_data)).unwrap();
+                (view.id, view.name, view.active)
+            })
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("alkahest", "Alkahest");
+
+    // The buffer layout follows the in-process formula rather than a wire
+    // format, so this size isn't comparable with the standalone blobs above
+    println!("Alkahest serialized size: {} bytes (in-process layout, not directly comparable to other formats)\n", serialized_data.len());
+}
+
+// Speedy
+fn benchmark_speedy(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("speedy");
+
+    group.throughput(Throughput::Bytes(payload_bytes("speedy", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| black_box(&test_data).write_to_vec_with_ctx(Endianness::LittleEndian).unwrap())
+        });
+    });
+
+    let serialized_data = test_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| TestData::read_from_buffer_with_ctx(Endianness::LittleEndian, black_box(&serialized_data)).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("speedy", "Speedy");
+}
+
+// Apache Avro. The schema is parsed once and passed in with `bench_with_input`;
+// serialize builds a `Record` and encodes it as a bare datum (no container
+// header), and deserialize decodes the datum back into an Avro `Value`
+fn benchmark_avro(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let schema = avro_schema();
+    let mut tracker = MemoryTracker::new();
+
+    let mut group = c.benchmark_group("avro");
+
+    group.throughput(Throughput::Bytes(payload_bytes("avro", "small") as u64));
+    tracker.track_serialize(|| {
+        group.bench_with_input("serialize", &schema, |b, schema| {
+            b.iter(|| apache_avro::to_avro_datum(schema, avro_record(schema, black_box(&test_data))).unwrap())
+        });
+    });
+
+    let serialized_data = apache_avro::to_avro_datum(&schema, avro_record(&schema, &test_data)).unwrap();
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_with_input("deserialize", &schema, |b, schema| {
+            b.iter(|| apache_avro::from_avro_datum(schema, &mut black_box(serialized_data.as_slice()), None).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("avro", "Avro");
+
+    println!("Avro serialized size: {} bytes\n", serialized_data.len());
+}
+
+// Write through a cursor over `buffer` and return how many bytes were written
+fn write_to_slice(buffer: &mut [u8], write: impl FnOnce(&mut &mut [u8])) -> usize {
+    let capacity = buffer.len();
+    let mut cursor = buffer;
+    write(&mut cursor);
+    capacity - cursor.len()
+}
+
+// Serialize into a pre-allocated stack buffer instead of a fresh `Vec`, then
+// deserialize from the written prefix of that same buffer
+fn benchmark_zero_alloc<T, R, S, D>(c: &mut Criterion, group_name: &str, label: &str, test_data: &T, serialize_into: S, deserialize_from: D)
+where
+    S: Fn(&T, &mut [u8]) -> usize,
+    D: Fn(&[u8]) -> R,
+{
+    let mut buffer = [0u8; ZERO_ALLOC_BUFFER_SIZE];
+    let mut tracker = MemoryTracker::new();
+
+    let len = serialize_into(test_data, &mut buffer);
+    record_payload_size(group_name, len);
+
+    let mut group = c.benchmark_group(group_name);
+
+    group.throughput(Throughput::Bytes(len as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_into(black_box(test_data), &mut buffer))
+        });
+    });
+
+    // The bench left the same encoding in the buffer
+    let serialized_data = &buffer[..len];
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| deserialize_from(black_box(serialized_data)))
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary(group_name, label);
+}
+
+// Bincode (zero-allocation)
+fn benchmark_bincode_zero_alloc(c: &mut Criterion) {
+    benchmark_zero_alloc(
+        c, "bincode_zero_alloc", "Bincode (zero-alloc)", &standard_test_data(),
+        |data, buffer| write_to_slice(buffer, |cursor| bincode::serialize_into(cursor, data).unwrap()),
+        |bytes| deserialize::<TestData>(bytes).unwrap(),
+    );
+}
+
+// BCS (zero-allocation)
+fn benchmark_bcs_zero_alloc(c: &mut Criterion) {
+    benchmark_zero_alloc(
+        c, "bcs_zero_alloc", "BCS (zero-alloc)", &standard_test_data(),
+        |data, buffer| write_to_slice(buffer, |cursor| bcs::serialize_into(cursor, data).unwrap()),
+        |bytes| from_bytes::<TestData>(bytes).unwrap(),
+    );
+}
+
+// Protobuf (zero-allocation)
+fn benchmark_protobuf_zero_alloc(c: &mut Criterion) {
+    benchmark_zero_alloc(
+        c, "protobuf_zero_alloc", "Protobuf (zero-alloc)", &proto_test_data(&standard_test_data()),
+        |data, buffer| {
+            let mut os = CodedOutputStream::bytes(buffer);
+            data.write_to(&mut os).unwrap();
+            os.flush().unwrap();
+            os.total_bytes_written() as usize
+        },
+        |bytes| proto::TestData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// Borsh (zero-allocation)
+fn benchmark_borsh_zero_alloc(c: &mut Criterion) {
+    benchmark_zero_alloc(
+        c, "borsh_zero_alloc", "Borsh (zero-alloc)", &standard_test_data(),
+        |data, buffer| write_to_slice(buffer, |cursor| BorshSerialize::serialize(data, cursor).unwrap()),
+        |bytes| TestData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli storage (zero-allocation)
+fn benchmark_musli_storage_zero_alloc(c: &mut Criterion) {
+    benchmark_zero_alloc(
+        c, "musli_storage_zero_alloc", "Musli (storage, zero-alloc)", &standard_test_data(),
+        |data, buffer| musli::storage::to_slice(buffer, data).unwrap(),
+        |bytes| musli::storage::from_slice::<TestData>(bytes).unwrap(),
+    );
+}
+
+// Musli wire (zero-allocation)
+fn benchmark_musli_wire_zero_alloc(c: &mut Criterion) {
+    benchmark_zero_alloc(
+        c, "musli_wire_zero_alloc", "Musli (wire, zero-alloc)", &standard_test_data(),
+        |data, buffer| musli::wire::to_slice(buffer, data).unwrap(),
+        |bytes| musli::wire::from_slice::<TestData>(bytes).unwrap(),
+    );
+}
+
+// Serialize into a scratch `Vec` that is cleared and reused across iterations,
+// the way hot loops call these APIs. After the group, the allocations per
+// serialize call are counted for both the reused and a fresh `Vec`
+fn benchmark_reuse<T, R, S, F, D>(c: &mut Criterion, group_name: &str, label: &str, test_data: &T, serialize_into: S, serialize_fresh: F, deserialize_fn: D)
+where
+    S: Fn(&T, &mut Vec<u8>),
+    F: Fn(&T) -> Vec<u8>,
+    D: Fn(&[u8]) -> R,
+{
+    let mut buffer = Vec::with_capacity(REUSE_BUFFER_CAPACITY);
+    let mut tracker = MemoryTracker::new();
+
+    let serialized_data = serialize_fresh(test_data);
+    record_payload_size(group_name, serialized_data.len());
+
+    let mut group = c.benchmark_group(group_name);
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| {
+                buffer.clear();
+                serialize_into(black_box(test_data), &mut buffer);
+            })
+        });
+    });
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| deserialize_fn(black_box(&serialized_data)))
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary(group_name, label);
+
+    let reused = allocations_per_call(REUSE_ALLOC_SAMPLES, || {
+        buffer.clear();
+        serialize_into(black_box(test_data), &mut buffer);
+    });
+    let fresh = allocations_per_call(REUSE_ALLOC_SAMPLES, || {
+        black_box(serialize_fresh(black_box(test_data)));
+    });
+    println!("{} allocations per serialize: {:.2} reused, {:.2} with a fresh Vec\n", label, reused, fresh);
+}
+
+// Bincode (reused buffer)
+fn benchmark_bincode_reuse(c: &mut Criterion) {
+    benchmark_reuse(
+        c, "bincode_reuse", "Bincode (reuse)", &standard_test_data(),
+        |data, buffer| bincode::serialize_into(buffer, data).unwrap(),
+        |data| serialize(data).unwrap(),
+        |bytes| deserialize::<TestData>(bytes).unwrap(),
+    );
+}
+
+// BCS (reused buffer)
+fn benchmark_bcs_reuse(c: &mut Criterion) {
+    benchmark_reuse(
+        c, "bcs_reuse", "BCS (reuse)", &standard_test_data(),
+        |data, buffer| bcs::serialize_into(buffer, data).unwrap(),
+        |data| to_bytes(data).unwrap(),
+        |bytes| from_bytes::<TestData>(bytes).unwrap(),
+    );
+}
+
+// Borsh (reused buffer)
+fn benchmark_borsh_reuse(c: &mut Criterion) {
+    benchmark_reuse(
+        c, "borsh_reuse", "Borsh (reuse)", &standard_test_data(),
+        |data, buffer| BorshSerialize::serialize(data, buffer).unwrap(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| TestData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Serde JSON written with `to_writer` into a reused `Vec`; compare with the
+// `serde_json` row, whose `to_string` allocates a new `String` per call
+fn benchmark_serde_json_writer(c: &mut Criterion) {
+    benchmark_reuse(
+        c, "serde_json_writer", "Serde JSON (to_writer)", &standard_test_data(),
+        |data, buffer| serde_json::to_writer(buffer, data).unwrap(),
+        |data| to_string(data).unwrap().into_bytes(),
+        |bytes| serde_json::from_slice::<TestData>(bytes).unwrap(),
+    );
+}
+
+// Serde JSON deserializing into `TestDataBorrowed`; compare with the
+// `serde_json` row, which allocates a `String` for the name
+fn benchmark_serde_json_borrowed(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    benchmark_payload(
+        c, "serde_json_borrowed", "Serde JSON (borrowed)", &TestDataBorrowed::from(&test_data),
+        |data| serde_json::to_vec(data).unwrap(),
+        |bytes| serde_json::from_slice::<TestDataBorrowed>(bytes).unwrap().name.len(),
+    );
+}
+
+// Bincode deserializing into `TestDataBorrowed`; compare with the `bincode` row
+fn benchmark_bincode_borrowed(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    benchmark_payload(
+        c, "bincode_borrowed", "Bincode (borrowed)", &TestDataBorrowed::from(&test_data),
+        |data| serialize(data).unwrap(),
+        |bytes| deserialize::<TestDataBorrowed>(bytes).unwrap().name.len(),
+    );
+}
+
+// Serde JSON parsing into and writing from a dynamic `serde_json::Value` instead
+// of `TestData`; compare with the `serde_json` row
+fn benchmark_json_value(c: &mut Criterion) {
+    benchmark_payload(
+        c, "json_value", "Serde JSON (Value)", &serde_json::to_value(standard_test_data()).unwrap(),
+        |value| serde_json::to_vec(value).unwrap(),
+        |bytes| serde_json::from_slice::<Value>(bytes).unwrap(),
+    );
+}
+
+// The same `Value` through FlexBuffers, which is self-describing like JSON;
+// compare with the `flexbuffers` row
+fn benchmark_flexbuffers_value(c: &mut Criterion) {
+    benchmark_payload(
+        c, "flexbuffers_value", "FlexBuffers (Value)", &serde_json::to_value(standard_test_data()).unwrap(),
+        |value| flexbuffers::to_vec(value).unwrap(),
+        |bytes| flexbuffers::from_slice::<Value>(bytes).unwrap(),
+    );
+}
+
+// The options behind `bincode::deserialize` plus a byte limit, the guard
+// against forged length prefixes in untrusted input; the encoding is unchanged
+fn limited_bincode_options() -> impl bincode::Options + Copy {
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(UNTRUSTED_INPUT_LIMIT)
+}
+
+// Bincode deserializing with a byte limit; compare with the `bincode` row
+fn benchmark_bincode_limited(c: &mut Criterion) {
+    let options = limited_bincode_options();
+    benchmark_payload(
+        c, "bincode_limited", "Bincode (limited)", &standard_test_data(),
+        |data| options.serialize(data).unwrap(),
+        |bytes| options.deserialize::<TestData>(bytes).unwrap(),
+    );
+}
+
+// Serialize and deserialize an arbitrary payload with the given codec pair
+fn benchmark_payload<T, R, S, D>(c: &mut Criterion, group_name: &str, label: &str, data: &T, serialize_fn: S, deserialize_fn: D)
+where
+    S: Fn(&T) -> Vec<u8>,
+    D: Fn(&[u8]) -> R,
+{
+    benchmark_payload_with_throughput(c, group_name, label, data, None, serialize_fn, deserialize_fn);
+}
+
+// `benchmark_payload`, reporting `throughput` for both directions when given,
+// and the serialized length otherwise
+fn benchmark_payload_with_throughput<T, R, S, D>(c: &mut Criterion, group_name: &str, label: &str, data: &T, throughput: Option<Throughput>, serialize_fn: S, deserialize_fn: D)
+where
+    S: Fn(&T) -> Vec<u8>,
+    D: Fn(&[u8]) -> R,
+{
+    let mut tracker = MemoryTracker::new();
+
+    let serialized_data = serialize_fn(data);
+    record_payload_size(group_name, serialized_data.len());
+
+    let mut group = c.benchmark_group(group_name);
+    group.throughput(throughput.unwrap_or(Throughput::Bytes(serialized_data.len() as u64)));
+
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_fn(black_box(data)))
+        });
+    });
+
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| deserialize_fn(black_box(&serialized_data)))
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary(group_name, label);
+}
+
+// quick-protobuf (enum payload as a oneof)
+fn benchmark_quick_protobuf_enum(c: &mut Criterion) {
+    let events = Event::sample();
+    benchmark_payload(
+        c, "quick_protobuf_enum", "quick-protobuf (enum)", &payloads::quick_proto_events(&events),
+        |log| {
+            let mut buffer = Vec::with_capacity(log.get_size());
+            log.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::EventLog::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().events.len(),
+    );
+}
+
+// Protobuf (enum payload as a oneof)
+fn benchmark_protobuf_enum(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_enum", "Protobuf (enum)", &payloads::proto_events(&Event::sample()),
+        |log| log.write_to_bytes().unwrap(),
+        |bytes| proto::EventLog::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// Borsh (enum payload)
+fn benchmark_borsh_enum(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_enum", "Borsh (enum)", &Event::sample(),
+        |events| borsh::to_vec(events).unwrap(),
+        |bytes| Vec::<Event>::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (enum payload, storage and wire encodings)
+fn benchmark_musli_enum(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_enum", "Musli (storage, enum)", &Event::sample(),
+        |events| musli::storage::to_vec(events).unwrap(),
+        |bytes| musli::storage::from_slice::<Vec<Event>>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_enum", "Musli (wire, enum)", &Event::sample(),
+        |events| musli::wire::to_vec(events).unwrap(),
+        |bytes| musli::wire::from_slice::<Vec<Event>>(bytes).unwrap(),
+    );
+}
+
+// Savefile (enum payload)
+fn benchmark_savefile_enum(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_enum", "Savefile (enum)", &Event::sample(),
+        |events| savefile::save_to_mem(SAVEFILE_VERSION, events).unwrap(),
+        |bytes| savefile::load_from_mem::<Vec<Event>>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (enum payload)
+fn benchmark_speedy_enum(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_enum", "Speedy (enum)", &Event::sample(),
+        |events| events.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| Vec::<Event>::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (nested payload as sub-messages and a repeated field)
+fn benchmark_protobuf_nested(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_nested", "Protobuf (nested)", &payloads::proto_nested(&NestedTestData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::NestedTestData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (nested payload, strings borrowed from the input)
+fn benchmark_quick_protobuf_nested(c: &mut Criterion) {
+    let nested = NestedTestData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_nested", "quick-protobuf (nested)", &payloads::quick_proto_nested(&nested),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::NestedTestData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().tags.len(),
+    );
+}
+
+// Borsh (nested payload)
+fn benchmark_borsh_nested(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_nested", "Borsh (nested)", &NestedTestData::sample(),
+        |nested| borsh::to_vec(nested).unwrap(),
+        |bytes| NestedTestData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (nested payload, storage and wire encodings)
+fn benchmark_musli_nested(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_nested", "Musli (storage, nested)", &NestedTestData::sample(),
+        |nested| musli::storage::to_vec(nested).unwrap(),
+        |bytes| musli::storage::from_slice::<NestedTestData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_nested", "Musli (wire, nested)", &NestedTestData::sample(),
+        |nested| musli::wire::to_vec(nested).unwrap(),
+        |bytes| musli::wire::from_slice::<NestedTestData>(bytes).unwrap(),
+    );
+}
+
+// Nanoserde (nested payload, JSON and binary modes)
+fn benchmark_nanoserde_nested(c: &mut Criterion) {
+    let nested = payloads::nano_nested(&NestedTestData::sample());
+    benchmark_payload(
+        c, "nanoserde_json_nested", "Nanoserde (JSON, nested)", &nested,
+        |nested| nested.serialize_json().into_bytes(),
+        |bytes| NanoNestedTestData::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+    benchmark_payload(
+        c, "nanoserde_bin_nested", "Nanoserde (binary, nested)", &nested,
+        |nested| nested.serialize_bin(),
+        |bytes| NanoNestedTestData::deserialize_bin(bytes).unwrap(),
+    );
+}
+
+// Savefile (nested payload)
+fn benchmark_savefile_nested(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_nested", "Savefile (nested)", &NestedTestData::sample(),
+        |nested| savefile::save_to_mem(SAVEFILE_VERSION, nested).unwrap(),
+        |bytes| savefile::load_from_mem::<NestedTestData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// ASN.1 DER (nested payload as nested SEQUENCEs)
+fn benchmark_der_nested(c: &mut Criterion) {
+    benchmark_payload(
+        c, "der_nested", "ASN.1 DER (nested)", &payloads::der_nested(&NestedTestData::sample()),
+        |nested| rasn::der::encode(nested).unwrap(),
+        |bytes| rasn::der::decode::<DerNestedTestData>(bytes).unwrap(),
+    );
+}
+
+// Speedy (nested payload)
+fn benchmark_speedy_nested(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_nested", "Speedy (nested)", &NestedTestData::sample(),
+        |nested| nested.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| NestedTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (map payload as `map<>` fields)
+fn benchmark_protobuf_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_map", "Protobuf (map)", &payloads::proto_map_data(&MapData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::MapData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (map payload, metadata strings borrowed from the input)
+fn benchmark_quick_protobuf_map(c: &mut Criterion) {
+    let maps = MapData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_map", "quick-protobuf (map)", &payloads::quick_proto_map_data(&maps),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::MapData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().metadata.len(),
+    );
+}
+
+// Borsh (map payload), which sorts `HashMap` entries by key before writing them
+fn benchmark_borsh_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_map", "Borsh (map)", &MapData::sample(),
+        |maps| borsh::to_vec(maps).unwrap(),
+        |bytes| MapData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (map payload, storage and wire encodings)
+fn benchmark_musli_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_map", "Musli (storage, map)", &MapData::sample(),
+        |maps| musli::storage::to_vec(maps).unwrap(),
+        |bytes| musli::storage::from_slice::<MapData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_map", "Musli (wire, map)", &MapData::sample(),
+        |maps| musli::wire::to_vec(maps).unwrap(),
+        |bytes| musli::wire::from_slice::<MapData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (map payload)
+fn benchmark_savefile_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_map", "Savefile (map)", &MapData::sample(),
+        |maps| savefile::save_to_mem(SAVEFILE_VERSION, maps).unwrap(),
+        |bytes| savefile::load_from_mem::<MapData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (map payload)
+fn benchmark_speedy_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_map", "Speedy (map)", &MapData::sample(),
+        |maps| maps.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| MapData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (string map payload as `map<string, string>`)
+fn benchmark_protobuf_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_string_map", "Protobuf (string map)", &payloads::proto_map_test_data(&MapTestData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::MapTestData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (string map payload, the strings borrowed from the input)
+fn benchmark_quick_protobuf_string_map(c: &mut Criterion) {
+    let data = MapTestData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_string_map", "quick-protobuf (string map)", &payloads::quick_proto_map_test_data(&data),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::MapTestData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().attributes.len(),
+    );
+}
+
+// prost (string map payload)
+fn benchmark_prost_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_string_map", "prost (string map)", &payloads::prost_map_test_data(&MapTestData::sample()),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::MapTestData::decode(bytes).unwrap(),
+    );
+}
+
+// Cap'n Proto (string map payload as a list of key/value structs, decoded
+// into `MapTestData`)
+fn benchmark_capnp_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "capnp_string_map", "Cap'n Proto (string map)", &MapTestData::sample(),
+        serialize_capnp_map_test_data,
+        |bytes| {
+            let mut input = bytes;
+            let message = capnp::serialize::read_message_from_flat_slice(&mut input, capnp::message::ReaderOptions::new()).unwrap();
+            map_test_data_from_capnp(message.get_root().unwrap()).unwrap()
+        },
+    );
+}
+
+// Borsh (string map payload)
+fn benchmark_borsh_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_string_map", "Borsh (string map)", &MapTestData::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| MapTestData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// FlatBuffers (string map payload as a vector of key/value tables, verified
+// and decoded into `MapTestData`)
+fn benchmark_flatbuffers_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "flatbuffers_string_map", "FlatBuffers (string map)", &MapTestData::sample(),
+        build_flatbuffer_map_test_data,
+        |bytes| map_test_data_from_flatbuffer(flatbuffers::root::<fbs::MapTestData>(bytes).unwrap()).unwrap(),
+    );
+}
+
+// Avro (string map payload as an Avro `map`)
+fn benchmark_avro_string_map(c: &mut Criterion) {
+    let schema = avro_map_test_data_schema();
+    benchmark_payload(
+        c, "avro_string_map", "Avro (string map)", &MapTestData::sample(),
+        |data| apache_avro::to_avro_datum(&schema, avro_map_test_data_record(&schema, data)).unwrap(),
+        |bytes| apache_avro::from_avro_datum(&schema, &mut &bytes[..], None).unwrap(),
+    );
+}
+
+// Musli (string map payload, storage and wire encodings)
+fn benchmark_musli_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_string_map", "Musli (storage, string map)", &MapTestData::sample(),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<MapTestData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_string_map", "Musli (wire, string map)", &MapTestData::sample(),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<MapTestData>(bytes).unwrap(),
+    );
+}
+
+// nanoserde (string map payload, JSON and binary)
+fn benchmark_nanoserde_string_map(c: &mut Criterion) {
+    let data = payloads::nano_map_test_data(&MapTestData::sample());
+    benchmark_payload(
+        c, "nanoserde_json_string_map", "Nanoserde (JSON, string map)", &data,
+        |data| data.serialize_json().into_bytes(),
+        |bytes| NanoMapTestData::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+    benchmark_payload(
+        c, "nanoserde_bin_string_map", "Nanoserde (binary, string map)", &data,
+        |data| data.serialize_bin(),
+        |bytes| NanoMapTestData::deserialize_bin(bytes).unwrap(),
+    );
+}
+
+// Savefile (string map payload)
+fn benchmark_savefile_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_string_map", "Savefile (string map)", &MapTestData::sample(),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<MapTestData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (string map payload)
+fn benchmark_speedy_string_map(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_string_map", "Speedy (string map)", &MapTestData::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| MapTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (metrics payload as packed repeated fields)
+fn benchmark_protobuf_metrics(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_metrics", "Protobuf (metrics)", &payloads::proto_metrics(&MetricsData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::MetricsData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (metrics payload as packed repeated fields)
+fn benchmark_quick_protobuf_metrics(c: &mut Criterion) {
+    benchmark_payload(
+        c, "quick_protobuf_metrics", "quick-protobuf (metrics)", &payloads::quick_proto_metrics(&MetricsData::sample()),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::MetricsData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap(),
+    );
+}
+
+// Serde JSON (the metrics payload's float values on their own), where the time
+// goes to ryu on serialize and float parsing on deserialize
+fn benchmark_serde_json_metrics_values(c: &mut Criterion) {
+    benchmark_payload(
+        c, "serde_json_metrics_values", "Serde JSON (metrics, values only)", &MetricsData::sample().values,
+        |values| serde_json::to_vec(values).unwrap(),
+        |bytes| serde_json::from_slice::<Vec<f64>>(bytes).unwrap(),
+    );
+}
+
+// Borsh (metrics payload)
+fn benchmark_borsh_metrics(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_metrics", "Borsh (metrics)", &MetricsData::sample(),
+        |metrics| borsh::to_vec(metrics).unwrap(),
+        |bytes| MetricsData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (metrics payload, storage and wire encodings)
+fn benchmark_musli_metrics(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_metrics", "Musli (storage, metrics)", &MetricsData::sample(),
+        |metrics| musli::storage::to_vec(metrics).unwrap(),
+        |bytes| musli::storage::from_slice::<MetricsData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_metrics", "Musli (wire, metrics)", &MetricsData::sample(),
+        |metrics| musli::wire::to_vec(metrics).unwrap(),
+        |bytes| musli::wire::from_slice::<MetricsData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (metrics payload)
+fn benchmark_savefile_metrics(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_metrics", "Savefile (metrics)", &MetricsData::sample(),
+        |metrics| savefile::save_to_mem(SAVEFILE_VERSION, metrics).unwrap(),
+        |bytes| savefile::load_from_mem::<MetricsData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (metrics payload)
+fn benchmark_speedy_metrics(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_metrics", "Speedy (metrics)", &MetricsData::sample(),
+        |metrics| metrics.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| MetricsData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Criterion throughput of the big string benches: the text length, so MB/s is
+// comparable across formats whatever their encoded size
+fn big_string_throughput(data: &BigStringData) -> Throughput {
+    Throughput::Bytes(data.text.len() as u64)
+}
+
+// Protobuf (big string payload)
+fn benchmark_protobuf_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    let message = proto::BigString { text: data.text.clone(), ..Default::default() };
+    benchmark_payload_with_throughput(
+        c, "protobuf_big_string", "Protobuf (big string)", &message, Some(big_string_throughput(&data)),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::BigString::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (big string payload, borrowing the text from the input)
+fn benchmark_quick_protobuf_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    let message = quick_proto::BigString { text: Cow::Borrowed(&data.text) };
+    benchmark_payload_with_throughput(
+        c, "quick_protobuf_big_string", "quick-protobuf (big string)", &message, Some(big_string_throughput(&data)),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::BigString::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().text.len(),
+    );
+}
+
+// prost (big string payload)
+fn benchmark_prost_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    let message = prost_proto::BigString { text: data.text.clone() };
+    benchmark_payload_with_throughput(
+        c, "prost_big_string", "prost (big string)", &message, Some(big_string_throughput(&data)),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::BigString::decode(bytes).unwrap(),
+    );
+}
+
+// Borsh (big string payload)
+fn benchmark_borsh_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    benchmark_payload_with_throughput(
+        c, "borsh_big_string", "Borsh (big string)", &data, Some(big_string_throughput(&data)),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| BigStringData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (big string payload, storage and wire encodings)
+fn benchmark_musli_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    benchmark_payload_with_throughput(
+        c, "musli_storage_big_string", "Musli (storage, big string)", &data, Some(big_string_throughput(&data)),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<BigStringData>(bytes).unwrap(),
+    );
+    benchmark_payload_with_throughput(
+        c, "musli_wire_big_string", "Musli (wire, big string)", &data, Some(big_string_throughput(&data)),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<BigStringData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (big string payload)
+fn benchmark_savefile_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    benchmark_payload_with_throughput(
+        c, "savefile_big_string", "Savefile (big string)", &data, Some(big_string_throughput(&data)),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<BigStringData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (big string payload)
+fn benchmark_speedy_big_string(c: &mut Criterion) {
+    let data = BigStringData::sample();
+    benchmark_payload_with_throughput(
+        c, "speedy_big_string", "Speedy (big string)", &data, Some(big_string_throughput(&data)),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| BigStringData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (timestamp payload as google.protobuf.Timestamp)
+fn benchmark_protobuf_timestamp(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_timestamp", "Protobuf (timestamp)", &payloads::proto_timestamp_data(&TimestampData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::TimestampData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (timestamp payload)
+fn benchmark_quick_protobuf_timestamp(c: &mut Criterion) {
+    benchmark_payload(
+        c, "quick_protobuf_timestamp", "quick-protobuf (timestamp)", &payloads::quick_proto_timestamp_data(&TimestampData::sample()),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::TimestampData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap(),
+    );
+}
+
+// prost (timestamp payload)
+fn benchmark_prost_timestamp(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_timestamp", "prost (timestamp)", &payloads::prost_timestamp_data(&TimestampData::sample()),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::TimestampData::decode(bytes).unwrap(),
+    );
+}
+
+// Borsh (timestamp payload, i64 nanoseconds)
+fn benchmark_borsh_timestamp(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_timestamp", "Borsh (timestamp)", &TimestampData::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| TimestampData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Speedy (timestamp payload, i64 nanoseconds)
+fn benchmark_speedy_timestamp(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_timestamp", "Speedy (timestamp)", &TimestampData::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| TimestampData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (128-bit integer payload as decimal strings); the formatting and
+// parsing of the strings is part of the measured time, as it is for JSON
+fn benchmark_protobuf_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_wide_ints", "Protobuf (wide ints)", &WideInts::sample(),
+        |data| payloads::proto_wide_ints(data).write_to_bytes().unwrap(),
+        |bytes| {
+            let message = proto::WideInts::parse_from_bytes(bytes).unwrap();
+            WideInts::from_decimal(&message.amount, &message.delta).unwrap()
+        },
+    );
+}
+
+// quick-protobuf (128-bit integer payload as decimal strings)
+fn benchmark_quick_protobuf_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "quick_protobuf_wide_ints", "quick-protobuf (wide ints)", &WideInts::sample(),
+        |data| {
+            let message = payloads::quick_proto_wide_ints(data);
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| {
+            let message = quick_proto::WideInts::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap();
+            WideInts::from_decimal(&message.amount, &message.delta).unwrap()
+        },
+    );
+}
+
+// prost (128-bit integer payload as decimal strings)
+fn benchmark_prost_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_wide_ints", "prost (wide ints)", &WideInts::sample(),
+        |data| payloads::prost_wide_ints(data).encode_to_vec(),
+        |bytes| {
+            let message = prost_proto::WideInts::decode(bytes).unwrap();
+            WideInts::from_decimal(&message.amount, &message.delta).unwrap()
+        },
+    );
+}
+
+// Borsh (128-bit integer payload, native 16-byte integers)
+fn benchmark_borsh_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_wide_ints", "Borsh (wide ints)", &WideInts::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| WideInts::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (128-bit integer payload, storage and wire encodings)
+fn benchmark_musli_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_wide_ints", "Musli (storage, wide ints)", &WideInts::sample(),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<WideInts>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_wide_ints", "Musli (wire, wide ints)", &WideInts::sample(),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<WideInts>(bytes).unwrap(),
+    );
+}
+
+// Savefile (128-bit integer payload)
+fn benchmark_savefile_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_wide_ints", "Savefile (wide ints)", &WideInts::sample(),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<WideInts>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (128-bit integer payload)
+fn benchmark_speedy_wide_ints(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_wide_ints", "Speedy (wide ints)", &WideInts::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| WideInts::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (deep nesting payload, one length-prefixed sub-message per level)
+fn benchmark_protobuf_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_deep", "Protobuf (deep)", &payloads::proto_deep(&DeepNested::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::DeepNested::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (deep nesting payload)
+fn benchmark_quick_protobuf_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "quick_protobuf_deep", "quick-protobuf (deep)", &payloads::quick_proto_deep(&DeepNested::sample()),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::DeepNested::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap(),
+    );
+}
+
+// prost (deep nesting payload)
+fn benchmark_prost_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_deep", "prost (deep)", &payloads::prost_deep(&DeepNested::sample()),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::DeepNested::decode(bytes).unwrap(),
+    );
+}
+
+// Borsh (deep nesting payload)
+fn benchmark_borsh_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_deep", "Borsh (deep)", &DeepNested::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| DeepNested::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (deep nesting payload, storage and wire encodings)
+fn benchmark_musli_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_deep", "Musli (storage, deep)", &DeepNested::sample(),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<DeepNested>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_deep", "Musli (wire, deep)", &DeepNested::sample(),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<DeepNested>(bytes).unwrap(),
+    );
+}
+
+// Savefile (deep nesting payload)
+fn benchmark_savefile_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_deep", "Savefile (deep)", &DeepNested::sample(),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<DeepNested>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (deep nesting payload)
+fn benchmark_speedy_deep(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_deep", "Speedy (deep)", &DeepNested::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| DeepNested::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (newtype payload, wrappers unwrapped into plain fields)
+fn benchmark_protobuf_newtype(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_newtype", "Protobuf (newtype)", &payloads::proto_newtype(&NewtypeData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::NewtypeData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (newtype payload, borrowing the email from the input)
+fn benchmark_quick_protobuf_newtype(c: &mut Criterion) {
+    let data = NewtypeData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_newtype", "quick-protobuf (newtype)", &payloads::quick_proto_newtype(&data),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::NewtypeData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().email.len(),
+    );
+}
+
+// prost (newtype payload)
+fn benchmark_prost_newtype(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_newtype", "prost (newtype)", &payloads::prost_newtype(&NewtypeData::sample()),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::NewtypeData::decode(bytes).unwrap(),
+    );
+}
+
+// Borsh (newtype payload)
+fn benchmark_borsh_newtype(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_newtype", "Borsh (newtype)", &NewtypeData::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| NewtypeData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (newtype payload, storage and wire encodings)
+fn benchmark_musli_newtype(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_newtype", "Musli (storage, newtype)", &NewtypeData::sample(),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<NewtypeData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_newtype", "Musli (wire, newtype)", &NewtypeData::sample(),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<NewtypeData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (newtype payload)
+fn benchmark_savefile_newtype(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_newtype", "Savefile (newtype)", &NewtypeData::sample(),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<NewtypeData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (newtype payload)
+fn benchmark_speedy_newtype(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_newtype", "Speedy (newtype)", &NewtypeData::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| NewtypeData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (UUID payload, ids as `bytes` fields)
+fn benchmark_protobuf_uuid(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_uuid", "Protobuf (UUID)", &payloads::proto_uuid_data(&UuidTestData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::UuidTestData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (UUID payload, borrowing the ids and name from the input)
+fn benchmark_quick_protobuf_uuid(c: &mut Criterion) {
+    let data = UuidTestData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_uuid", "quick-protobuf (UUID)", &payloads::quick_proto_uuid_data(&data),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::UuidTestData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().name.len(),
+    );
+}
+
+// prost (UUID payload)
+fn benchmark_prost_uuid(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_uuid", "prost (UUID)", &payloads::prost_uuid_data(&UuidTestData::sample()),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::UuidTestData::decode(bytes).unwrap(),
+    );
+}
+
+// Borsh (UUID payload)
+fn benchmark_borsh_uuid(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_uuid", "Borsh (UUID)", &UuidTestData::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| UuidTestData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Speedy (UUID payload)
+fn benchmark_speedy_uuid(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_uuid", "Speedy (UUID)", &UuidTestData::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| UuidTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (enum data payload, the status as a oneof and the commands as protobuf enums)
+fn benchmark_protobuf_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_enum_data", "Protobuf (enum data)", &payloads::proto_enum_data(&EnumTestData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::EnumTestData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (enum data payload, the status strings borrowed from the input)
+fn benchmark_quick_protobuf_enum_data(c: &mut Criterion) {
+    let data = EnumTestData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_enum_data", "quick-protobuf (enum data)", &payloads::quick_proto_enum_data(&data),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::EnumTestData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().commands.len(),
+    );
+}
+
+// prost (enum data payload)
+fn benchmark_prost_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "prost_enum_data", "prost (enum data)", &payloads::prost_enum_data(&EnumTestData::sample()),
+        |message| message.encode_to_vec(),
+        |bytes| prost_proto::EnumTestData::decode(bytes).unwrap(),
+    );
+}
+
+// Borsh (enum data payload)
+fn benchmark_borsh_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_enum_data", "Borsh (enum data)", &EnumTestData::sample(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| EnumTestData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (enum data payload, storage and wire encodings)
+fn benchmark_musli_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_enum_data", "Musli (storage, enum data)", &EnumTestData::sample(),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<EnumTestData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_enum_data", "Musli (wire, enum data)", &EnumTestData::sample(),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<EnumTestData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (enum data payload)
+fn benchmark_savefile_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_enum_data", "Savefile (enum data)", &EnumTestData::sample(),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<EnumTestData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (enum data payload)
+fn benchmark_speedy_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_enum_data", "Speedy (enum data)", &EnumTestData::sample(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| EnumTestData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Cap'n Proto (enum data payload, the status as a union, decoded into
+// `EnumTestData` like the serde formats)
+fn benchmark_capnp_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "capnp_enum_data", "Cap'n Proto (enum data)", &EnumTestData::sample(),
+        serialize_capnp_enum_data,
+        |bytes| {
+            let mut input = bytes;
+            let message = capnp::serialize::read_message_from_flat_slice(&mut input, capnp::message::ReaderOptions::new()).unwrap();
+            enum_data_from_capnp(message.get_root().unwrap()).unwrap()
+        },
+    );
+}
+
+// FlatBuffers (enum data payload, the status as a union, verified and
+// decoded into `EnumTestData`)
+fn benchmark_flatbuffers_enum_data(c: &mut Criterion) {
+    benchmark_payload(
+        c, "flatbuffers_enum_data", "FlatBuffers (enum data)", &EnumTestData::sample(),
+        build_flatbuffer_enum_data,
+        |bytes| enum_data_from_flatbuffer(flatbuffers::root::<fbs::EnumTestData>(bytes).unwrap()).unwrap(),
+    );
+}
+
+// Borsh (fixture payload)
+fn benchmark_borsh_fixture(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_fixture", "Borsh (fixture)", &fixture_data(),
+        |fixture| borsh::to_vec(fixture).unwrap(),
+        |bytes| FixtureData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (fixture payload, storage and wire encodings)
+fn benchmark_musli_fixture(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_fixture", "Musli (storage, fixture)", &fixture_data(),
+        |fixture| musli::storage::to_vec(fixture).unwrap(),
+        |bytes| musli::storage::from_slice::<FixtureData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_fixture", "Musli (wire, fixture)", &fixture_data(),
+        |fixture| musli::wire::to_vec(fixture).unwrap(),
+        |bytes| musli::wire::from_slice::<FixtureData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (fixture payload)
+fn benchmark_savefile_fixture(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_fixture", "Savefile (fixture)", &fixture_data(),
+        |fixture| savefile::save_to_mem(SAVEFILE_VERSION, fixture).unwrap(),
+        |bytes| savefile::load_from_mem::<FixtureData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (fixture payload)
+fn benchmark_speedy_fixture(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_fixture", "Speedy (fixture)", &fixture_data(),
+        |fixture| fixture.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| FixtureData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Protobuf (unicode payload)
+fn benchmark_protobuf_unicode(c: &mut Criterion) {
+    benchmark_payload(
+        c, "protobuf_unicode", "Protobuf (unicode)", &payloads::proto_unicode(&UnicodeData::sample()),
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::UnicodeData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (unicode payload, strings borrowed from the input after UTF-8 validation)
+fn benchmark_quick_protobuf_unicode(c: &mut Criterion) {
+    let text = UnicodeData::sample();
+    benchmark_payload(
+        c, "quick_protobuf_unicode", "quick-protobuf (unicode)", &payloads::quick_proto_unicode(&text),
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::UnicodeData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().escapes.len(),
+    );
+}
+
+// Borsh (unicode payload)
+fn benchmark_borsh_unicode(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_unicode", "Borsh (unicode)", &UnicodeData::sample(),
+        |text| borsh::to_vec(text).unwrap(),
+        |bytes| UnicodeData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (unicode payload, storage and wire encodings)
+fn benchmark_musli_unicode(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_unicode", "Musli (storage, unicode)", &UnicodeData::sample(),
+        |text| musli::storage::to_vec(text).unwrap(),
+        |bytes| musli::storage::from_slice::<UnicodeData>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_unicode", "Musli (wire, unicode)", &UnicodeData::sample(),
+        |text| musli::wire::to_vec(text).unwrap(),
+        |bytes| musli::wire::from_slice::<UnicodeData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (unicode payload)
+fn benchmark_savefile_unicode(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_unicode", "Savefile (unicode)", &UnicodeData::sample(),
+        |text| savefile::save_to_mem(SAVEFILE_VERSION, text).unwrap(),
+        |bytes| savefile::load_from_mem::<UnicodeData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Speedy (unicode payload)
+fn benchmark_speedy_unicode(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_unicode", "Speedy (unicode)", &UnicodeData::sample(),
+        |text| text.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| UnicodeData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Bincode (batch)
+fn benchmark_bincode_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "bincode_batch", "Bincode (batch)", &test_data_batch(),
+        |batch| serialize(batch).unwrap(),
+        |bytes| deserialize::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// BCS (batch)
+fn benchmark_bcs_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "bcs_batch", "BCS (batch)", &test_data_batch(),
+        |batch| to_bytes(batch).unwrap(),
+        |bytes| from_bytes::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Protobuf (batch as a repeated field)
+fn benchmark_protobuf_batch(c: &mut Criterion) {
+    let batch = proto::TestDataBatch {
+        items: test_data_batch().iter().map(proto_test_data).collect(),
+        ..Default::default()
+    };
+    benchmark_payload(
+        c, "protobuf_batch", "Protobuf (batch)", &batch,
+        |batch| batch.write_to_bytes().unwrap(),
+        |bytes| proto::TestDataBatch::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// Serde JSON (batch)
+fn benchmark_serde_json_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "serde_json_batch", "Serde JSON (batch)", &test_data_batch(),
+        |batch| serde_json::to_vec(batch).unwrap(),
+        |bytes| serde_json::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// SIMD JSON (batch). simd-json parses in place, so the copy of the input is
+// part of the measured deserialize time here
+#[cfg(target_arch = "x86_64")]
+fn benchmark_simd_json_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "simd_json_batch", "SIMD JSON (batch)", &test_data_batch(),
+        |batch| simd_json::serde::to_vec(batch).unwrap(),
+        |bytes| simd_json::serde::from_slice::<Vec<TestData>>(&mut bytes.to_vec()).unwrap(),
+    );
+}
+
+// sonic-rs (batch)
+fn benchmark_sonic_rs_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "sonic_rs_batch", "sonic-rs (batch)", &test_data_batch(),
+        |batch| sonic_rs::to_vec(batch).unwrap(),
+        |bytes| sonic_rs::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Serde YAML (batch)
+fn benchmark_serde_yaml_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "serde_yaml_batch", "Serde YAML (batch)", &test_data_batch(),
+        |batch| serde_yaml::to_string(batch).unwrap().into_bytes(),
+        |bytes| serde_yaml::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// RON (batch)
+fn benchmark_ron_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "ron_batch", "RON (batch)", &test_data_batch(),
+        |batch| ron::to_string(batch).unwrap().into_bytes(),
+        |bytes| ron::de::from_bytes::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Borsh (batch)
+fn benchmark_borsh_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "borsh_batch", "Borsh (batch)", &test_data_batch(),
+        |batch| borsh::to_vec(batch).unwrap(),
+        |bytes| Vec::<TestData>::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (batch, storage and wire encodings)
+fn benchmark_musli_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "musli_storage_batch", "Musli (storage, batch)", &test_data_batch(),
+        |batch| musli::storage::to_vec(batch).unwrap(),
+        |bytes| musli::storage::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+    benchmark_payload(
+        c, "musli_wire_batch", "Musli (wire, batch)", &test_data_batch(),
+        |batch| musli::wire::to_vec(batch).unwrap(),
+        |bytes| musli::wire::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Nanoserde (batch, JSON and binary modes)
+fn benchmark_nanoserde_batch(c: &mut Criterion) {
+    let batch: Vec<NanoTestData> = test_data_batch().iter().map(nano_test_data).collect();
+    benchmark_payload(
+        c, "nanoserde_json_batch", "Nanoserde (JSON, batch)", &batch,
+        |batch| batch.serialize_json().into_bytes(),
+        |bytes| Vec::<NanoTestData>::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+    benchmark_payload(
+        c, "nanoserde_bin_batch", "Nanoserde (binary, batch)", &batch,
+        |batch| batch.serialize_bin(),
+        |bytes| Vec::<NanoTestData>::deserialize_bin(bytes).unwrap(),
+    );
+}
+
+// FlatBuffers (batch as a vector of tables). As in the single-value bench,
+// "deserialize" verifies the buffer and reads every field in place
+fn benchmark_flatbuffers_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "flatbuffers_batch", "FlatBuffers (batch)", &test_data_batch(),
+        |batch| build_flatbuffer_batch(batch),
+        |bytes| {
+            let batch = flatbuffers::root::<fbs::TestDataBatch>(bytes).unwrap();
+            for item in batch.items().unwrap() {
+                black_box((item.id(), item.name(), item.active()));
+            }
+        },
+    );
+}
+
+// FlexBuffers (batch)
+fn benchmark_flexbuffers_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "flexbuffers_batch", "FlexBuffers (batch)", &test_data_batch(),
+        |batch| flexbuffers::to_vec(batch).unwrap(),
+        |bytes| flexbuffers::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// XDR (batch)
+fn benchmark_xdr_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "xdr_batch", "XDR (batch)", &test_data_batch(),
+        |batch| serde_xdr::to_bytes(batch).unwrap(),
+        |bytes| serde_xdr::from_bytes::<_, Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Savefile (batch)
+fn benchmark_savefile_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "savefile_batch", "Savefile (batch)", &test_data_batch(),
+        |batch| savefile::save_to_mem(SAVEFILE_VERSION, batch).unwrap(),
+        |bytes| savefile::load_from_mem::<Vec<TestData>>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Pickle (batch)
+fn benchmark_pickle_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "pickle_batch", "Pickle (batch)", &test_data_batch(),
+        |batch| serde_pickle::to_vec(batch, SerOptions::new()).unwrap(),
+        |bytes| serde_pickle::from_slice::<Vec<TestData>>(bytes, DeOptions::new()).unwrap(),
+    );
+}
+
+// ASN.1 DER (batch as a SEQUENCE OF)
+fn benchmark_der_batch(c: &mut Criterion) {
+    let batch: Vec<DerTestData> = test_data_batch().iter().map(der_test_data).collect();
+    benchmark_payload(
+        c, "der_batch", "ASN.1 DER (batch)", &batch,
+        |batch| rasn::der::encode(batch).unwrap(),
+        |bytes| rasn::der::decode::<Vec<DerTestData>>(bytes).unwrap(),
+    );
+}
+
+// Alkahest (batch as a `Vec` formula), read into views that borrow the names
+fn benchmark_alkahest_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "alkahest_batch", "Alkahest (batch)", &test_data_batch(),
+        |batch| {
+            let mut buffer = Vec::new();
+            let (len, _) = alkahest::serialize_to_vec::<Vec<AlkahestTestData>, _>(batch, &mut buffer);
+            buffer.truncate(len);
+            buffer
+        },
+        |bytes| alkahest::deserialize::<Vec<AlkahestTestData>, Vec<AlkahestTestDataView>>(bytes).unwrap().len(),
+    );
+}
+
+// Speedy (batch)
+fn benchmark_speedy_batch(c: &mut Criterion) {
+    benchmark_payload(
+        c, "speedy_batch", "Speedy (batch)", &test_data_batch(),
+        |batch| batch.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| Vec::<TestData>::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Avro (batch as an array of records)
+fn benchmark_avro_batch(c: &mut Criterion) {
+    let schema = avro_schema();
+    let batch_schema = avro_batch_schema();
+    benchmark_payload(
+        c, "avro_batch", "Avro (batch)", &test_data_batch(),
+        |batch| apache_avro::to_avro_datum(&batch_schema, avro_array(&schema, batch)).unwrap(),
+        |bytes| apache_avro::from_avro_datum(&batch_schema, &mut &bytes[..], None).unwrap(),
+    );
+}
+
+// Serialize and deserialize each payload size in one group, with the size as
+// the criterion parameter (`<group>/serialize/<size>`)
+fn benchmark_sizes<T, R, S, D>(c: &mut Criterion, group_name: &str, label: &str, payloads: &[(&'static str, T)], serialize_fn: S, deserialize_fn: D)
+where
+    S: Fn(&T) -> Vec<u8>,
+    D: Fn(&[u8]) -> R,
+{
+    let mut group = c.benchmark_group(group_name);
+    // The large payload takes milliseconds per iteration for the text formats
+    group.sample_size(10);
+
+    for (size, data) in payloads {
+        // Memory is tracked and reported per size, under `<group>/<size>`
+        let mut tracker = MemoryTracker::new();
+        let serialized_data = serialize_fn(data);
+        record_payload_size(&format!("{}/{}", group_name, size), serialized_data.len());
+
+        tracker.track_serialize(|| {
+            group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+            group.bench_with_input(BenchmarkId::new("serialize", size), data, |b, data| {
+                b.iter(|| serialize_fn(black_box(data)))
+            });
+        });
+
+        tracker.track_deserialize(|| {
+            group.bench_with_input(BenchmarkId::new("deserialize", size), &serialized_data, |b, bytes| {
+                b.iter(|| deserialize_fn(black_box(bytes)))
+            });
+        });
+
+        tracker.print_summary(&format!("{}/{}", group_name, size), &format!("{} ({})", label, size));
+    }
+
+    group.finish();
+}
+
+// Like `benchmark_sizes`, for payloads built from `COLLECTION_LENGTHS` in order;
+// each length also sets `Throughput::Elements`, so criterion reports records/sec
+fn benchmark_lengths<T, R, S, D>(c: &mut Criterion, group_name: &str, label: &str, payloads: &[(&'static str, T)], serialize_fn: S, deserialize_fn: D)
+where
+    S: Fn(&T) -> Vec<u8>,
+    D: Fn(&[u8]) -> R,
+{
+    let mut group = c.benchmark_group(group_name);
+    // 10,000 records take milliseconds per iteration for the text formats
+    group.sample_size(10);
+
+    for ((length, data), (_, records)) in payloads.iter().zip(COLLECTION_LENGTHS) {
+        // Memory is tracked and reported per length, under `<group>/<length>`
+        let mut tracker = MemoryTracker::new();
+        let serialized_data = serialize_fn(data);
+        record_payload_size(&format!("{}/{}", group_name, length), serialized_data.len());
+        group.throughput(Throughput::Elements(records as u64));
+
+        tracker.track_serialize(|| {
+            group.bench_with_input(BenchmarkId::new("serialize", length), data, |b, data| {
+                b.iter(|| serialize_fn(black_box(data)))
+            });
+        });
+
+        tracker.track_deserialize(|| {
+            group.bench_with_input(BenchmarkId::new("deserialize", length), &serialized_data, |b, bytes| {
+                b.iter(|| deserialize_fn(black_box(bytes)))
+            });
+        });
+
+        tracker.print_summary(&format!("{}/{}", group_name, length), &format!("{} ({})", label, length));
+    }
+
+    group.finish();
+}
+
+// Bincode (medium and large payloads)
+fn benchmark_bincode_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "bincode_sizes", "Bincode (sizes)", &sized_payloads(),
+        |records| serialize(records).unwrap(),
+        |bytes| deserialize::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// BCS (medium and large payloads)
+fn benchmark_bcs_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "bcs_sizes", "BCS (sizes)", &sized_payloads(),
+        |records| to_bytes(records).unwrap(),
+        |bytes| from_bytes::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Protobuf (medium and large payloads as a repeated field)
+fn benchmark_protobuf_sizes(c: &mut Criterion) {
+    let payloads: Vec<(&str, proto::TestDataBatch)> = sized_payloads().into_iter()
+        .map(|(size, records)| (size, proto::TestDataBatch {
+            items: records.iter().map(proto_test_data).collect(),
+            ..Default::default()
+        }))
+        .collect();
+    benchmark_sizes(
+        c, "protobuf_sizes", "Protobuf (sizes)", &payloads,
+        |batch| batch.write_to_bytes().unwrap(),
+        |bytes| proto::TestDataBatch::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// Serde JSON (medium and large payloads)
+fn benchmark_serde_json_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "serde_json_sizes", "Serde JSON (sizes)", &sized_payloads(),
+        |records| serde_json::to_vec(records).unwrap(),
+        |bytes| serde_json::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// SIMD JSON (medium and large payloads), copying the input as in the batch bench
+#[cfg(target_arch = "x86_64")]
+fn benchmark_simd_json_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "simd_json_sizes", "SIMD JSON (sizes)", &sized_payloads(),
+        |records| simd_json::serde::to_vec(records).unwrap(),
+        |bytes| simd_json::serde::from_slice::<Vec<TestData>>(&mut bytes.to_vec()).unwrap(),
+    );
+}
+
+// sonic-rs (medium and large payloads)
+fn benchmark_sonic_rs_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "sonic_rs_sizes", "sonic-rs (sizes)", &sized_payloads(),
+        |records| sonic_rs::to_vec(records).unwrap(),
+        |bytes| sonic_rs::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Serde YAML (medium and large payloads)
+fn benchmark_serde_yaml_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "serde_yaml_sizes", "Serde YAML (sizes)", &sized_payloads(),
+        |records| serde_yaml::to_string(records).unwrap().into_bytes(),
+        |bytes| serde_yaml::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// RON (medium and large payloads)
+fn benchmark_ron_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "ron_sizes", "RON (sizes)", &sized_payloads(),
+        |records| ron::to_string(records).unwrap().into_bytes(),
+        |bytes| ron::de::from_bytes::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Borsh (medium and large payloads)
+fn benchmark_borsh_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "borsh_sizes", "Borsh (sizes)", &sized_payloads(),
+        |records| borsh::to_vec(records).unwrap(),
+        |bytes| Vec::<TestData>::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (medium and large payloads, storage and wire encodings)
+fn benchmark_musli_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "musli_storage_sizes", "Musli (storage, sizes)", &sized_payloads(),
+        |records| musli::storage::to_vec(records).unwrap(),
+        |bytes| musli::storage::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+    benchmark_sizes(
+        c, "musli_wire_sizes", "Musli (wire, sizes)", &sized_payloads(),
+        |records| musli::wire::to_vec(records).unwrap(),
+        |bytes| musli::wire::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Nanoserde (medium and large payloads, JSON and binary modes)
+fn benchmark_nanoserde_sizes(c: &mut Criterion) {
+    let payloads: Vec<(&str, Vec<NanoTestData>)> = sized_payloads().into_iter()
+        .map(|(size, records)| (size, records.iter().map(nano_test_data).collect()))
+        .collect();
+    benchmark_sizes(
+        c, "nanoserde_json_sizes", "Nanoserde (JSON, sizes)", &payloads,
+        |records| records.serialize_json().into_bytes(),
+        |bytes| Vec::<NanoTestData>::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+    benchmark_sizes(
+        c, "nanoserde_bin_sizes", "Nanoserde (binary, sizes)", &payloads,
+        |records| records.serialize_bin(),
+        |bytes| Vec::<NanoTestData>::deserialize_bin(bytes).unwrap(),
+    );
+}
+
+// FlatBuffers (medium and large payloads as a vector of tables, read in place)
+fn benchmark_flatbuffers_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "flatbuffers_sizes", "FlatBuffers (sizes)", &sized_payloads(),
+        |records| build_flatbuffer_batch(records),
+        |bytes| {
+            let batch = flatbuffers::root::<fbs::TestDataBatch>(bytes).unwrap();
+            for item in batch.items().unwrap() {
+                black_box((item.id(), item.name(), item.active()));
+            }
+        },
+    );
+}
+
+// FlexBuffers (medium and large payloads)
+fn benchmark_flexbuffers_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "flexbuffers_sizes", "FlexBuffers (sizes)", &sized_payloads(),
+        |records| flexbuffers::to_vec(records).unwrap(),
+        |bytes| flexbuffers::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// XDR (medium and large payloads)
+fn benchmark_xdr_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "xdr_sizes", "XDR (sizes)", &sized_payloads(),
+        |records| serde_xdr::to_bytes(records).unwrap(),
+        |bytes| serde_xdr::from_bytes::<_, Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Savefile (medium and large payloads)
+fn benchmark_savefile_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "savefile_sizes", "Savefile (sizes)", &sized_payloads(),
+        |records| savefile::save_to_mem(SAVEFILE_VERSION, records).unwrap(),
+        |bytes| savefile::load_from_mem::<Vec<TestData>>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Pickle (medium and large payloads)
+fn benchmark_pickle_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "pickle_sizes", "Pickle (sizes)", &sized_payloads(),
+        |records| serde_pickle::to_vec(records, SerOptions::new()).unwrap(),
+        |bytes| serde_pickle::from_slice::<Vec<TestData>>(bytes, DeOptions::new()).unwrap(),
+    );
+}
+
+// ASN.1 DER (medium and large payloads as a SEQUENCE OF)
+fn benchmark_der_sizes(c: &mut Criterion) {
+    let payloads: Vec<(&str, Vec<DerTestData>)> = sized_payloads().into_iter()
+        .map(|(size, records)| (size, records.iter().map(der_test_data).collect()))
+        .collect();
+    benchmark_sizes(
+        c, "der_sizes", "ASN.1 DER (sizes)", &payloads,
+        |records| rasn::der::encode(records).unwrap(),
+        |bytes| rasn::der::decode::<Vec<DerTestData>>(bytes).unwrap(),
+    );
+}
+
+// Alkahest (medium and large payloads as a `Vec` formula, read into views)
+fn benchmark_alkahest_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "alkahest_sizes", "Alkahest (sizes)", &sized_payloads(),
+        |records| {
+            let mut buffer = Vec::new();
+            let (len, _) = alkahest::serialize_to_vec::<Vec<AlkahestTestData>, _>(records, &mut buffer);
+            buffer.truncate(len);
+            buffer
+        },
+        |bytes| alkahest::deserialize::<Vec<AlkahestTestData>, Vec<AlkahestTestDataView>>(bytes).unwrap().len(),
+    );
+}
+
+// Speedy (medium and large payloads)
+fn benchmark_speedy_sizes(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "speedy_sizes", "Speedy (sizes)", &sized_payloads(),
+        |records| records.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| Vec::<TestData>::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Avro (medium and large payloads as an array of records)
+fn benchmark_avro_sizes(c: &mut Criterion) {
+    let schema = avro_schema();
+    let batch_schema = avro_batch_schema();
+    benchmark_sizes(
+        c, "avro_sizes", "Avro (sizes)", &sized_payloads(),
+        |records| apache_avro::to_avro_datum(&batch_schema, avro_array(&schema, records)).unwrap(),
+        |bytes| apache_avro::from_avro_datum(&batch_schema, &mut &bytes[..], None).unwrap(),
+    );
+}
+
+// Bincode (collections of 1 to 10,000 records)
+fn benchmark_bincode_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "bincode_collection", "Bincode (collection)", &collection_payloads(),
+        |records| serialize(records).unwrap(),
+        |bytes| deserialize::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// BCS (collections of 1 to 10,000 records)
+fn benchmark_bcs_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "bcs_collection", "BCS (collection)", &collection_payloads(),
+        |records| to_bytes(records).unwrap(),
+        |bytes| from_bytes::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Protobuf (collections of 1 to 10,000 records as a repeated field)
+fn benchmark_protobuf_collection(c: &mut Criterion) {
+    let payloads: Vec<(&str, proto::TestDataBatch)> = collection_payloads().into_iter()
+        .map(|(size, records)| (size, proto::TestDataBatch {
+            items: records.iter().map(proto_test_data).collect(),
+            ..Default::default()
+        }))
+        .collect();
+    benchmark_lengths(
+        c, "protobuf_collection", "Protobuf (collection)", &payloads,
+        |batch| batch.write_to_bytes().unwrap(),
+        |bytes| proto::TestDataBatch::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// Serde JSON (collections of 1 to 10,000 records)
+fn benchmark_serde_json_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "serde_json_collection", "Serde JSON (collection)", &collection_payloads(),
+        |records| serde_json::to_vec(records).unwrap(),
+        |bytes| serde_json::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// SIMD JSON (collections of 1 to 10,000 records), copying the input as in the batch bench
+#[cfg(target_arch = "x86_64")]
+fn benchmark_simd_json_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "simd_json_collection", "SIMD JSON (collection)", &collection_payloads(),
+        |records| simd_json::serde::to_vec(records).unwrap(),
+        |bytes| simd_json::serde::from_slice::<Vec<TestData>>(&mut bytes.to_vec()).unwrap(),
+    );
+}
+
+// sonic-rs (collections of 1 to 10,000 records)
+fn benchmark_sonic_rs_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "sonic_rs_collection", "sonic-rs (collection)", &collection_payloads(),
+        |records| sonic_rs::to_vec(records).unwrap(),
+        |bytes| sonic_rs::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Serde YAML (collections of 1 to 10,000 records)
+fn benchmark_serde_yaml_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "serde_yaml_collection", "Serde YAML (collection)", &collection_payloads(),
+        |records| serde_yaml::to_string(records).unwrap().into_bytes(),
+        |bytes| serde_yaml::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// RON (collections of 1 to 10,000 records)
+fn benchmark_ron_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "ron_collection", "RON (collection)", &collection_payloads(),
+        |records| ron::to_string(records).unwrap().into_bytes(),
+        |bytes| ron::de::from_bytes::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Borsh (collections of 1 to 10,000 records)
+fn benchmark_borsh_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "borsh_collection", "Borsh (collection)", &collection_payloads(),
+        |records| borsh::to_vec(records).unwrap(),
+        |bytes| Vec::<TestData>::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (collections of 1 to 10,000 records, storage and wire encodings)
+fn benchmark_musli_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "musli_storage_collection", "Musli (storage, collection)", &collection_payloads(),
+        |records| musli::storage::to_vec(records).unwrap(),
+        |bytes| musli::storage::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+    benchmark_lengths(
+        c, "musli_wire_collection", "Musli (wire, collection)", &collection_payloads(),
+        |records| musli::wire::to_vec(records).unwrap(),
+        |bytes| musli::wire::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Nanoserde (collections of 1 to 10,000 records, JSON and binary modes)
+fn benchmark_nanoserde_collection(c: &mut Criterion) {
+    let payloads: Vec<(&str, Vec<NanoTestData>)> = collection_payloads().into_iter()
+        .map(|(size, records)| (size, records.iter().map(nano_test_data).collect()))
+        .collect();
+    benchmark_lengths(
+        c, "nanoserde_json_collection", "Nanoserde (JSON, collection)", &payloads,
+        |records| records.serialize_json().into_bytes(),
+        |bytes| Vec::<NanoTestData>::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+    benchmark_lengths(
+        c, "nanoserde_bin_collection", "Nanoserde (binary, collection)", &payloads,
+        |records| records.serialize_bin(),
+        |bytes| Vec::<NanoTestData>::deserialize_bin(bytes).unwrap(),
+    );
+}
+
+// FlatBuffers (collections of 1 to 10,000 records as a vector of tables, read in place)
+fn benchmark_flatbuffers_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "flatbuffers_collection", "FlatBuffers (collection)", &collection_payloads(),
+        |records| build_flatbuffer_batch(records),
+        |bytes| {
+            let batch = flatbuffers::root::<fbs::TestDataBatch>(bytes).unwrap();
+            for item in batch.items().unwrap() {
+                black_box((item.id(), item.name(), item.active()));
+            }
+        },
+    );
+}
+
+// FlexBuffers (collections of 1 to 10,000 records)
+fn benchmark_flexbuffers_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "flexbuffers_collection", "FlexBuffers (collection)", &collection_payloads(),
+        |records| flexbuffers::to_vec(records).unwrap(),
+        |bytes| flexbuffers::from_slice::<Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// XDR (collections of 1 to 10,000 records)
+fn benchmark_xdr_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "xdr_collection", "XDR (collection)", &collection_payloads(),
+        |records| serde_xdr::to_bytes(records).unwrap(),
+        |bytes| serde_xdr::from_bytes::<_, Vec<TestData>>(bytes).unwrap(),
+    );
+}
+
+// Savefile (collections of 1 to 10,000 records)
+fn benchmark_savefile_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "savefile_collection", "Savefile (collection)", &collection_payloads(),
+        |records| savefile::save_to_mem(SAVEFILE_VERSION, records).unwrap(),
+        |bytes| savefile::load_from_mem::<Vec<TestData>>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Pickle (collections of 1 to 10,000 records)
+fn benchmark_pickle_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "pickle_collection", "Pickle (collection)", &collection_payloads(),
+        |records| serde_pickle::to_vec(records, SerOptions::new()).unwrap(),
+        |bytes| serde_pickle::from_slice::<Vec<TestData>>(bytes, DeOptions::new()).unwrap(),
+    );
+}
+
+// ASN.1 DER (collections of 1 to 10,000 records as a SEQUENCE OF)
+fn benchmark_der_collection(c: &mut Criterion) {
+    let payloads: Vec<(&str, Vec<DerTestData>)> = collection_payloads().into_iter()
+        .map(|(size, records)| (size, records.iter().map(der_test_data).collect()))
+        .collect();
+    benchmark_lengths(
+        c, "der_collection", "ASN.1 DER (collection)", &payloads,
+        |records| rasn::der::encode(records).unwrap(),
+        |bytes| rasn::der::decode::<Vec<DerTestData>>(bytes).unwrap(),
+    );
+}
+
+// Alkahest (collections of 1 to 10,000 records as a `Vec` formula, read into views)
+fn benchmark_alkahest_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "alkahest_collection", "Alkahest (collection)", &collection_payloads(),
+        |records| {
+            let mut buffer = Vec::new();
+            let (len, _) = alkahest::serialize_to_vec::<Vec<AlkahestTestData>, _>(records, &mut buffer);
+            buffer.truncate(len);
+            buffer
+        },
+        |bytes| alkahest::deserialize::<Vec<AlkahestTestData>, Vec<AlkahestTestDataView>>(bytes).unwrap().len(),
+    );
+}
+
+// Speedy (collections of 1 to 10,000 records)
+fn benchmark_speedy_collection(c: &mut Criterion) {
+    benchmark_lengths(
+        c, "speedy_collection", "Speedy (collection)", &collection_payloads(),
+        |records| records.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| Vec::<TestData>::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Avro (collections of 1 to 10,000 records as an array of records)
+fn benchmark_avro_collection(c: &mut Criterion) {
+    let schema = avro_schema();
+    let batch_schema = avro_batch_schema();
+    benchmark_lengths(
+        c, "avro_collection", "Avro (collection)", &collection_payloads(),
+        |records| apache_avro::to_avro_datum(&batch_schema, avro_array(&schema, records)).unwrap(),
+        |bytes| apache_avro::from_avro_datum(&batch_schema, &mut &bytes[..], None).unwrap(),
+    );
+}
+
+// Bincode (blob payloads)
+fn benchmark_bincode_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "bincode_blob", "Bincode (blob)", &blob_payloads(),
+        |blob| serialize(blob).unwrap(),
+        |bytes| deserialize::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// BCS (blob payloads)
+fn benchmark_bcs_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "bcs_blob", "BCS (blob)", &blob_payloads(),
+        |blob| to_bytes(blob).unwrap(),
+        |bytes| from_bytes::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// Protobuf (blob payloads as a `bytes` field)
+fn benchmark_protobuf_blob(c: &mut Criterion) {
+    let payloads: Vec<(&str, _)> = blob_payloads().iter()
+        .map(|(size, blob)| (*size, payloads::proto_blob(blob)))
+        .collect();
+    benchmark_sizes(
+        c, "protobuf_blob", "Protobuf (blob)", &payloads,
+        |blob| blob.write_to_bytes().unwrap(),
+        |bytes| proto::BlobData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (blob payloads, borrowed from the input)
+fn benchmark_quick_protobuf_blob(c: &mut Criterion) {
+    let blobs = blob_payloads();
+    let payloads: Vec<(&str, quick_proto::BlobData)> = blobs.iter()
+        .map(|(size, blob)| (*size, payloads::quick_proto_blob(blob)))
+        .collect();
+    benchmark_sizes(
+        c, "quick_protobuf_blob", "quick-protobuf (blob)", &payloads,
+        |blob| {
+            let mut buffer = Vec::with_capacity(blob.get_size());
+            blob.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::BlobData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().payload.len(),
+    );
+}
+
+// Serde JSON (blob payloads). serde encodes `Vec<u8>` as an array of numbers,
+// so a second group sends the payload as a base64 string instead
+fn benchmark_serde_json_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "serde_json_blob", "Serde JSON (blob)", &blob_payloads(),
+        |blob| serde_json::to_vec(blob).unwrap(),
+        |bytes| serde_json::from_slice::<BlobData>(bytes).unwrap(),
+    );
+
+    let payloads: Vec<(&str, Base64BlobData)> = blob_payloads().iter()
+        .map(|(size, blob)| (*size, payloads::base64_blob(blob)))
+        .collect();
+    benchmark_sizes(
+        c, "serde_json_base64_blob", "Serde JSON (base64 blob)", &payloads,
+        |blob| serde_json::to_vec(blob).unwrap(),
+        |bytes| serde_json::from_slice::<Base64BlobData>(bytes).unwrap(),
+    );
+}
+
+// SIMD JSON (blob payloads), copying the input as in the batch bench
+#[cfg(target_arch = "x86_64")]
+fn benchmark_simd_json_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "simd_json_blob", "SIMD JSON (blob)", &blob_payloads(),
+        |blob| simd_json::serde::to_vec(blob).unwrap(),
+        |bytes| simd_json::serde::from_slice::<BlobData>(&mut bytes.to_vec()).unwrap(),
+    );
+}
+
+// sonic-rs (blob payloads)
+fn benchmark_sonic_rs_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "sonic_rs_blob", "sonic-rs (blob)", &blob_payloads(),
+        |blob| sonic_rs::to_vec(blob).unwrap(),
+        |bytes| sonic_rs::from_slice::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// Serde YAML (blob payloads)
+fn benchmark_serde_yaml_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "serde_yaml_blob", "Serde YAML (blob)", &blob_payloads(),
+        |blob| serde_yaml::to_string(blob).unwrap().into_bytes(),
+        |bytes| serde_yaml::from_slice::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// RON (blob payloads)
+fn benchmark_ron_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "ron_blob", "RON (blob)", &blob_payloads(),
+        |blob| ron::to_string(blob).unwrap().into_bytes(),
+        |bytes| ron::de::from_bytes::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// Borsh (blob payloads)
+fn benchmark_borsh_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "borsh_blob", "Borsh (blob)", &blob_payloads(),
+        |blob| borsh::to_vec(blob).unwrap(),
+        |bytes| BlobData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (blob payloads, storage and wire encodings)
+fn benchmark_musli_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "musli_storage_blob", "Musli (storage, blob)", &blob_payloads(),
+        |blob| musli::storage::to_vec(blob).unwrap(),
+        |bytes| musli::storage::from_slice::<BlobData>(bytes).unwrap(),
+    );
+    benchmark_sizes(
+        c, "musli_wire_blob", "Musli (wire, blob)", &blob_payloads(),
+        |blob| musli::wire::to_vec(blob).unwrap(),
+        |bytes| musli::wire::from_slice::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// Nanoserde (blob payloads, JSON and binary modes)
+fn benchmark_nanoserde_blob(c: &mut Criterion) {
+    let payloads: Vec<(&str, _)> = blob_payloads().iter()
+        .map(|(size, blob)| (*size, payloads::nano_blob(blob)))
+        .collect();
+    benchmark_sizes(
+        c, "nanoserde_json_blob", "Nanoserde (JSON, blob)", &payloads,
+        |blob| blob.serialize_json().into_bytes(),
+        |bytes| NanoBlobData::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+    benchmark_sizes(
+        c, "nanoserde_bin_blob", "Nanoserde (binary, blob)", &payloads,
+        |blob| blob.serialize_bin(),
+        |bytes| NanoBlobData::deserialize_bin(bytes).unwrap(),
+    );
+}
+
+// FlexBuffers (blob payloads)
+fn benchmark_flexbuffers_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "flexbuffers_blob", "FlexBuffers (blob)", &blob_payloads(),
+        |blob| flexbuffers::to_vec(blob).unwrap(),
+        |bytes| flexbuffers::from_slice::<BlobData>(bytes).unwrap(),
+    );
+}
+
+// XDR (blob payloads)
+fn benchmark_xdr_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "xdr_blob", "XDR (blob)", &blob_payloads(),
+        |blob| serde_xdr::to_bytes(blob).unwrap(),
+        |bytes| serde_xdr::from_bytes::<_, BlobData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (blob payloads)
+fn benchmark_savefile_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "savefile_blob", "Savefile (blob)", &blob_payloads(),
+        |blob| savefile::save_to_mem(SAVEFILE_VERSION, blob).unwrap(),
+        |bytes| savefile::load_from_mem::<BlobData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Pickle (blob payloads)
+fn benchmark_pickle_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "pickle_blob", "Pickle (blob)", &blob_payloads(),
+        |blob| serde_pickle::to_vec(blob, SerOptions::new()).unwrap(),
+        |bytes| serde_pickle::from_slice::<BlobData>(bytes, DeOptions::new()).unwrap(),
+    );
+}
+
+// ASN.1 DER (blob payloads as an OCTET STRING)
+fn benchmark_der_blob(c: &mut Criterion) {
+    let payloads: Vec<(&str, _)> = blob_payloads().iter()
+        .map(|(size, blob)| (*size, payloads::der_blob(blob)))
+        .collect();
+    benchmark_sizes(
+        c, "der_blob", "ASN.1 DER (blob)", &payloads,
+        |blob| rasn::der::encode(blob).unwrap(),
+        |bytes| rasn::der::decode::<DerBlobData>(bytes).unwrap(),
+    );
+}
+
+// Speedy (blob payloads)
+fn benchmark_speedy_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "speedy_blob", "Speedy (blob)", &blob_payloads(),
+        |blob| blob.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| BlobData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// Cap'n Proto (blob payloads as `Data`, read in place)
+fn benchmark_capnp_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "capnp_blob", "Cap'n Proto (blob)", &blob_payloads(),
+        serialize_capnp_blob,
+        |bytes| {
+            let mut input = bytes;
+            let message = capnp::serialize::read_message_from_flat_slice(&mut input, capnp::message::ReaderOptions::new()).unwrap();
+            let blob = message.get_root::<message_capnp::blob_data::Reader>().unwrap();
+            (blob.get_id(), blob.get_payload().unwrap().len())
+        },
+    );
+}
+
+// FlatBuffers (blob payloads as a `[ubyte]` vector, verified and read in place)
+fn benchmark_flatbuffers_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "flatbuffers_blob", "FlatBuffers (blob)", &blob_payloads(),
+        build_flatbuffer_blob,
+        |bytes| {
+            let blob = flatbuffers::root::<fbs::BlobData>(bytes).unwrap();
+            (blob.id(), blob.payload().unwrap().bytes().len())
+        },
+    );
+}
+
+// Alkahest (blob payloads as `Bytes`, borrowed from the input)
+fn benchmark_alkahest_blob(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "alkahest_blob", "Alkahest (blob)", &blob_payloads(),
+        |blob| {
+            let mut buffer = Vec::new();
+            let (len, _) = alkahest::serialize_to_vec::<AlkahestBlobData, _>(blob, &mut buffer);
+            buffer.truncate(len);
+            buffer
+        },
+        |bytes| {
+            let blob = alkahest::deserialize::<AlkahestBlobData, AlkahestBlobDataView>(bytes).unwrap();
+            (blob.id, blob.payload.len())
+        },
+    );
+}
+
+// Avro (blob payloads as `bytes`)
+fn benchmark_avro_blob(c: &mut Criterion) {
+    let schema = avro_blob_schema();
+    benchmark_sizes(
+        c, "avro_blob", "Avro (blob)", &blob_payloads(),
+        |blob| apache_avro::to_avro_datum(&schema, avro_blob_record(&schema, blob)).unwrap(),
+        |bytes| apache_avro::from_avro_datum(&schema, &mut &bytes[..], None).unwrap(),
+    );
+}
+
+// Bincode (sparse payloads), one tag byte per `Option`
+fn benchmark_bincode_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "bincode_sparse", "Bincode (sparse)", &sparse_payloads(),
+        |data| serialize(data).unwrap(),
+        |bytes| deserialize::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// Protobuf (sparse payloads as proto3 `optional` fields, omitted when unset)
+fn benchmark_protobuf_sparse(c: &mut Criterion) {
+    let payloads: Vec<(&str, _)> = sparse_payloads().iter()
+        .map(|(variant, data)| (*variant, payloads::proto_sparse(data)))
+        .collect();
+    benchmark_sizes(
+        c, "protobuf_sparse", "Protobuf (sparse)", &payloads,
+        |message| message.write_to_bytes().unwrap(),
+        |bytes| proto::SparseData::parse_from_bytes(bytes).unwrap(),
+    );
+}
+
+// quick-protobuf (sparse payloads, strings borrowed from the input)
+fn benchmark_quick_protobuf_sparse(c: &mut Criterion) {
+    let sparse = sparse_payloads();
+    let payloads: Vec<(&str, quick_proto::SparseData)> = sparse.iter()
+        .map(|(variant, data)| (*variant, payloads::quick_proto_sparse(data)))
+        .collect();
+    benchmark_sizes(
+        c, "quick_protobuf_sparse", "quick-protobuf (sparse)", &payloads,
+        |message| {
+            let mut buffer = Vec::with_capacity(message.get_size());
+            message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+            buffer
+        },
+        |bytes| quick_proto::SparseData::from_reader(&mut BytesReader::from_bytes(bytes), bytes).unwrap().id,
+    );
+}
+
+// Serde JSON (sparse payloads); `None` fields are written as `null`
+fn benchmark_serde_json_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "serde_json_sparse", "Serde JSON (sparse)", &sparse_payloads(),
+        |data| serde_json::to_vec(data).unwrap(),
+        |bytes| serde_json::from_slice::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// SIMD JSON (sparse payloads), copying the input as in the batch bench
+#[cfg(target_arch = "x86_64")]
+fn benchmark_simd_json_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "simd_json_sparse", "SIMD JSON (sparse)", &sparse_payloads(),
+        |data| simd_json::serde::to_vec(data).unwrap(),
+        |bytes| simd_json::serde::from_slice::<SparseData>(&mut bytes.to_vec()).unwrap(),
+    );
+}
+
+// sonic-rs (sparse payloads)
+fn benchmark_sonic_rs_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "sonic_rs_sparse", "sonic-rs (sparse)", &sparse_payloads(),
+        |data| sonic_rs::to_vec(data).unwrap(),
+        |bytes| sonic_rs::from_slice::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// Serde YAML (sparse payloads)
+fn benchmark_serde_yaml_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "serde_yaml_sparse", "Serde YAML (sparse)", &sparse_payloads(),
+        |data| serde_yaml::to_string(data).unwrap().into_bytes(),
+        |bytes| serde_yaml::from_slice::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// RON (sparse payloads)
+fn benchmark_ron_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "ron_sparse", "RON (sparse)", &sparse_payloads(),
+        |data| ron::to_string(data).unwrap().into_bytes(),
+        |bytes| ron::de::from_bytes::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// TOML (sparse payloads); TOML has no null, so `None` fields are left out
+fn benchmark_toml_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "toml_sparse", "TOML (sparse)", &sparse_payloads(),
+        |data| toml::to_string(data).unwrap().into_bytes(),
+        |bytes| toml::from_str::<SparseData>(std::str::from_utf8(bytes).unwrap()).unwrap(),
+    );
+}
+
+// Borsh (sparse payloads)
+fn benchmark_borsh_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "borsh_sparse", "Borsh (sparse)", &sparse_payloads(),
+        |data| borsh::to_vec(data).unwrap(),
+        |bytes| SparseData::try_from_slice(bytes).unwrap(),
+    );
+}
+
+// Musli (sparse payloads, storage and wire encodings)
+fn benchmark_musli_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "musli_storage_sparse", "Musli (storage, sparse)", &sparse_payloads(),
+        |data| musli::storage::to_vec(data).unwrap(),
+        |bytes| musli::storage::from_slice::<SparseData>(bytes).unwrap(),
+    );
+    benchmark_sizes(
+        c, "musli_wire_sparse", "Musli (wire, sparse)", &sparse_payloads(),
+        |data| musli::wire::to_vec(data).unwrap(),
+        |bytes| musli::wire::from_slice::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// FlexBuffers (sparse payloads)
+fn benchmark_flexbuffers_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "flexbuffers_sparse", "FlexBuffers (sparse)", &sparse_payloads(),
+        |data| flexbuffers::to_vec(data).unwrap(),
+        |bytes| flexbuffers::from_slice::<SparseData>(bytes).unwrap(),
+    );
+}
+
+// XDR (sparse payloads as optional-data, a 4-byte flag per field)
+fn benchmark_xdr_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "xdr_sparse", "XDR (sparse)", &sparse_payloads(),
+        |data| serde_xdr::to_bytes(data).unwrap(),
+        |bytes| serde_xdr::from_bytes::<_, SparseData>(bytes).unwrap(),
+    );
+}
+
+// Savefile (sparse payloads)
+fn benchmark_savefile_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "savefile_sparse", "Savefile (sparse)", &sparse_payloads(),
+        |data| savefile::save_to_mem(SAVEFILE_VERSION, data).unwrap(),
+        |bytes| savefile::load_from_mem::<SparseData>(bytes, SAVEFILE_VERSION).unwrap(),
+    );
+}
+
+// Pickle (sparse payloads)
+fn benchmark_pickle_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "pickle_sparse", "Pickle (sparse)", &sparse_payloads(),
+        |data| serde_pickle::to_vec(data, SerOptions::new()).unwrap(),
+        |bytes| serde_pickle::from_slice::<SparseData>(bytes, DeOptions::new()).unwrap(),
+    );
+}
+
+// Speedy (sparse payloads)
+fn benchmark_speedy_sparse(c: &mut Criterion) {
+    benchmark_sizes(
+        c, "speedy_sparse", "Speedy (sparse)", &sparse_payloads(),
+        |data| data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(),
+        |bytes| SparseData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(),
+    );
+}
+
+// The `ERROR_VARIANTS` corruptions of a valid encoding
+fn corrupted_inputs(valid: &[u8]) -> [(&'static str, Vec<u8>); 2] {
+    [
+        ("truncated", valid[..valid.len() - 1].to_vec()),
+        ("inverted", valid.iter().map(|byte| !byte).collect()),
+    ]
+}
+
+// Time how long `deserialize_fn` takes to reject each corruption of `valid`.
+// Before timing, each must return an `Err`: accepting it, or panicking, as a
+// read past the end of a truncated buffer would, fails the run
+fn benchmark_error_path<R, E, D>(c: &mut Criterion, group_name: &str, valid: &[u8], deserialize_fn: D)
+where
+    D: Fn(&[u8]) -> Result<R, E>,
+{
+    let mut group = c.benchmark_group(group_name);
+
+    for (variant, input) in corrupted_inputs(valid) {
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| deserialize_fn(&input).is_err())) {
+            Ok(true) => {}
+            Ok(false) => panic!("{}: {} input was accepted", group_name, variant),
+            Err(_) => panic!("{}: {} input panicked instead of returning an error", group_name, variant),
+        }
+
+        group.bench_with_input(BenchmarkId::new("deserialize", variant), &input, |b, input| {
+            b.iter(|| deserialize_fn(black_box(input)).is_err())
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_bincode_error(c: &mut Criterion) {
+    let valid = serialize(&standard_test_data()).unwrap();
+    benchmark_error_path(c, "bincode_error", &valid, |bytes| deserialize::<TestData>(bytes));
+}
+
+fn benchmark_bcs_error(c: &mut Criterion) {
+    let valid = to_bytes(&standard_test_data()).unwrap();
+    benchmark_error_path(c, "bcs_error", &valid, |bytes| from_bytes::<TestData>(bytes));
+}
+
+fn benchmark_protobuf_error(c: &mut Criterion) {
+    let valid = proto_test_data(&standard_test_data()).write_to_bytes().unwrap();
+    benchmark_error_path(c, "protobuf_error", &valid, proto::TestData::parse_from_bytes);
+}
+
+fn benchmark_prost_error(c: &mut Criterion) {
+    let valid = serialize_prost(&prost_test_data(&standard_test_data()));
+    benchmark_error_path(c, "prost_error", &valid, |bytes| prost_proto::TestData::decode(bytes));
+}
+
+fn benchmark_serde_json_error(c: &mut Criterion) {
+    let valid = serde_json::to_vec(&standard_test_data()).unwrap();
+    benchmark_error_path(c, "serde_json_error", &valid, |bytes| serde_json::from_slice::<TestData>(bytes));
+}
+
+fn benchmark_sonic_rs_error(c: &mut Criterion) {
+    let valid = sonic_rs::to_vec(&standard_test_data()).unwrap();
+    benchmark_error_path(c, "sonic_rs_error", &valid, |bytes| sonic_rs::from_slice::<TestData>(bytes));
+}
+
+fn benchmark_borsh_error(c: &mut Criterion) {
+    let valid = borsh::to_vec(&standard_test_data()).unwrap();
+    benchmark_error_path(c, "borsh_error", &valid, TestData::try_from_slice);
+}
+
+fn benchmark_speedy_error(c: &mut Criterion) {
+    let valid = standard_test_data().write_to_vec_with_ctx(Endianness::LittleEndian).unwrap();
+    benchmark_error_path(c, "speedy_error", &valid, |bytes| TestData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes));
+}
+
+// Time each format took to reject each corruption, read from `<group>/deserialize/<variant>`
+fn print_error_path_table(groups: &[&str]) {
+    if groups.is_empty() {
+        return;
+    }
+
+    println!("\n{:-^80}", " Error Path: time to reject corrupted input ");
+    println!("{:<20} | {:<24} | {:<24}", "Format", "Truncated (ns)", "Inverted (ns)");
+    println!("{:-<20}-+-{:-<24}-+-{:-<24}", "", "", "");
+    for group_name in groups {
+        let cells: Vec<String> = ERROR_VARIANTS.iter()
+            .map(|variant| read_point_estimate(group_name, &format!("deserialize/{}", variant))
+                .map_or_else(|| "n/a".to_string(), |ns| format!("{:.2}", ns)))
+            .collect();
+        let format = group_name.strip_suffix("_error").unwrap_or(group_name);
+        println!("{:<20} | {:<24} | {:<24}", format, cells[0], cells[1]);
+    }
+    println!("{:-^80}", "");
+}
+
+fn gzip_compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::new(GZIP_LEVEL));
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn gzip_decompress(data: &[u8]) -> Vec<u8> {
+    let mut decompressed = Vec::new();
+    GzDecoder::new(data).read_to_end(&mut decompressed).unwrap();
+    decompressed
+}
+
+fn print_wire_sizes(label: &str, uncompressed: usize, compressed: usize) {
+    println!("{} wire size: {} bytes uncompressed, {} bytes gzip (level {})", label, uncompressed, compressed, GZIP_LEVEL);
+}
+
+// Bincode piped through gzip
+fn benchmark_bincode_compressed(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let serialize_compressed = |data: &TestData| {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::new(GZIP_LEVEL));
+        bincode::serialize_into(&mut encoder, data).unwrap();
+        encoder.finish().unwrap()
+    };
+
+    let serialized_data = serialize_compressed(&test_data);
+    record_payload_size("bincode_compressed", serialized_data.len());
+
+    let mut group = c.benchmark_group("bincode_compressed");
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_compressed(black_box(&test_data)))
+        });
+    });
+
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| deserialize::<TestData>(&gzip_decompress(black_box(&serialized_data))).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("bincode_compressed", "Bincode (gzip)");
+    print_wire_sizes("Bincode", serialize(&test_data).unwrap().len(), serialized_data.len());
+}
+
+// Serde JSON piped through gzip
+fn benchmark_json_compressed(c: &mut Criterion) {
+    let test_data = standard_test_data();
+    let mut tracker = MemoryTracker::new();
+
+    let serialize_compressed = |data: &TestData| {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::new(GZIP_LEVEL));
+        serde_json::to_writer(&mut encoder, data).unwrap();
+        encoder.finish().unwrap()
+    };
+
+    let serialized_data = serialize_compressed(&test_data);
+    record_payload_size("serde_json_compressed", serialized_data.len());
+
+    let mut group = c.benchmark_group("serde_json_compressed");
+
+    group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+    tracker.track_serialize(|| {
+        group.bench_function("serialize", |b| {
+            b.iter(|| serialize_compressed(black_box(&test_data)))
+        });
+    });
+
+    tracker.track_deserialize(|| {
+        group.bench_function("deserialize", |b| {
+            b.iter(|| serde_json::from_slice::<TestData>(&gzip_decompress(black_box(&serialized_data))).unwrap())
+        });
+    });
+
+    group.finish();
+
+    tracker.print_summary("serde_json_compressed", "Serde JSON (gzip)");
+    print_wire_sizes("Serde JSON", to_string(&test_data).unwrap().len(), serialized_data.len());
+}
+
+fn zstd_compress(data: &[u8]) -> Vec<u8> {
+    zstd::encode_all(data, ZSTD_LEVEL).unwrap()
+}
+
+fn zstd_decompress(data: &[u8]) -> Vec<u8> {
+    zstd::decode_all(data).unwrap()
+}
+
+fn lz4_compress(data: &[u8]) -> Vec<u8> {
+    lz4_flex::compress_prepend_size(data)
+}
+
+fn lz4_decompress(data: &[u8]) -> Vec<u8> {
+    lz4_flex::decompress_size_prepended(data).unwrap()
+}
+
+// Compression on top of every format's serialized bytes
+fn benchmark_with_compression(c: &mut Criterion, groups: &[&str]) {
+    for (format, serialized_data) in serialized_payloads() {
+        if !groups.contains(&format) {
+            continue;
+        }
+
+        for (codec, compress, decompress) in COMPRESSION_CODECS {
+            let mut group = c.benchmark_group(format!("{}_{}", format, codec));
+            // Uncompressed bytes in or out, so both directions are comparable
+            group.throughput(Throughput::Bytes(serialized_data.len() as u64));
+
+            group.bench_function("compress", |b| {
+                b.iter(|| compress(black_box(&serialized_data)))
+            });
+
+            let compressed_data = compress(&serialized_data);
+            assert_eq!(decompress(&compressed_data), serialized_data, "{}: {} roundtrip failed", format, codec);
+
+            group.bench_function("decompress", |b| {
+                b.iter(|| decompress(black_box(&compressed_data)))
+            });
+
+            group.finish();
+        }
+    }
+}
+
+// Time a single call of `f` once per sample and return the median and minimum
+// in nanoseconds, plus the standard deviation of the samples. The warmup
+// calls are not recorded. The process CPU clock is too coarse for single
+// calls, so the CPU time is the mean over all samples, clock reads included
+fn sample_times<F: FnMut()>(iters: usize, mut f: F) -> (f64, f64, f64, Option<f64>) {
+    for _ in 0..(iters / 10).max(1) {
+        f();
+    }
+
+    let cpu_start = process_cpu_ns();
+    let mut samples: Vec<f64> = (0..iters)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed().as_nanos() as f64
+        })
+        .collect();
+    let cpu_ns = cpu_start.zip(process_cpu_ns()).map(|(start, end)| (end - start) / iters as f64);
+    samples.sort_by(f64::total_cmp);
+
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let variance = samples.iter().map(|ns| (ns - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    (samples[samples.len() / 2], samples[0], variance.sqrt(), cpu_ns)
+}
+
+// CPU time consumed by the process so far, in nanoseconds; `None` without the
+// `cpu-time` feature or where the platform does not report it
+#[cfg(feature = "cpu-time")]
+fn process_cpu_ns() -> Option<f64> {
+    cpu_time::ProcessTime::try_now().ok().map(|time| time.as_duration().as_nanos() as f64)
+}
+
+#[cfg(not(feature = "cpu-time"))]
+fn process_cpu_ns() -> Option<f64> {
+    None
+}
+
+// Time `serialize_fn` and `deserialize_fn` `iters` times each with `Instant`
+// and store the medians in `BENCHMARK_RESULTS` under `name`, the way
+// `analyze_criterion_results` stores criterion's estimates
+fn time_format<R, S, D>(name: &str, serialize_fn: S, deserialize_fn: D, iters: usize)
+where
+    S: Fn() -> Vec<u8>,
+    D: Fn(&[u8]) -> R,
+{
+    let serialized_data = serialize_fn();
+    let (serialize_median, serialize_min, serialize_std_dev, serialize_cpu) = sample_times(iters, || {
+        black_box(serialize_fn());
+    });
+    let (deserialize_median, deserialize_min, deserialize_std_dev, deserialize_cpu) = sample_times(iters, || {
+        black_box(deserialize_fn(black_box(&serialized_data)));
+    });
+
+    store_results(name, "small", serialize_median, Some(serialize_std_dev), deserialize_median, Some(deserialize_std_dev));
+    store_cpu_times(name, "small", serialize_cpu, deserialize_cpu);
+    println!(
+        "{}: serialize median {:.1} ns (min {:.1} ns), deserialize median {:.1} ns (min {:.1} ns)",
+        name, serialize_median, serialize_min, deserialize_median, deserialize_min,
+    );
+    if let (Some(serialize_cpu), Some(deserialize_cpu)) = (serialize_cpu, deserialize_cpu) {
+        println!("{}: serialize CPU {:.1} ns, deserialize CPU {:.1} ns per call", name, serialize_cpu, deserialize_cpu);
+    }
+}
+
+// BENCH_MODE=simple: time the standard payload of every selected group with
+// `time_format` instead of criterion. Groups without a simple-mode timer
+// are reported and left out of the results table
+fn run_simple_benchmarks<'a>(groups: &[&'a str]) -> Vec<&'a str> {
+    let iters = simple_iters();
+    let test_data = standard_test_data();
+    let proto_data = proto_test_data(&test_data);
+    let quick_proto_data = quick_proto_test_data(&test_data);
+    let prost_data = prost_test_data(&test_data);
+    let nano_data = nano_test_data(&test_data);
+    let der_data = der_test_data(&test_data);
+    let schema = avro_schema();
+    let value = serde_json::to_value(&test_data).unwrap();
+    // serde_json deserializes from this `String` with `from_str`, as its criterion bench does
+    let json_string = to_string(&test_data).unwrap();
+    println!("Simple mode: {} timed calls per closure after {} warmup calls\n", iters, (iters / 10).max(1));
+
+    let mut timed = Vec::new();
+    for group in groups {
+        let name = *group;
+        match name {
+            "bincode" => time_format(name, || serialize(&test_data).unwrap(), |bytes| deserialize::<TestData>(bytes).unwrap(), iters),
+            "bitcode" => time_format(name, || bitcode::serialize(&test_data).unwrap(), |bytes| bitcode::deserialize::<TestData>(bytes).unwrap(), iters),
+            "bcs" => time_format(name, || to_bytes(&test_data).unwrap(), |bytes| from_bytes::<TestData>(bytes).unwrap(), iters),
+            "protobuf" => time_format(name, || proto_data.write_to_bytes().unwrap(), |bytes| proto::TestData::parse_from_bytes(bytes).unwrap(), iters),
+            "quick_protobuf" => time_format(name, || serialize_quick_protobuf(&quick_proto_data), |bytes| {
+                black_box(parse_quick_protobuf(bytes));
+            }, iters),
+            "prost" => time_format(name, || serialize_prost(&prost_data), |bytes| prost_proto::TestData::decode(bytes).unwrap(), iters),
+            "capnp" => time_format(name, || serialize_capnp(&build_capnp_message(&test_data)), |mut bytes| {
+                let message = capnp::serialize::read_message_from_flat_slice(&mut bytes, capnp::message::ReaderOptions::new()).unwrap();
+                let data = message.get_root::<message_capnp::test_data::Reader>().unwrap();
+                black_box((data.get_id(), data.get_name().unwrap().to_str().unwrap(), data.get_active()));
+            }, iters),
+            "serde_json" => time_format(name, || to_string(&test_data).unwrap().into_bytes(), |_| from_str::<TestData>(black_box(&json_string)).unwrap(), iters),
+            "sonic_rs" => time_format(name, || sonic_rs::to_string(&test_data).unwrap().into_bytes(), |bytes| sonic_rs::from_slice::<TestData>(bytes).unwrap(), iters),
+            "serde_yaml" => time_format(name, || serde_yaml::to_string(&test_data).unwrap().into_bytes(), |bytes| serde_yaml::from_slice::<TestData>(bytes).unwrap(), iters),
+            "ron" => time_format(name, || ron::to_string(&test_data).unwrap().into_bytes(), |bytes| ron::de::from_bytes::<TestData>(bytes).unwrap(), iters),
+            "toml" => time_format(name, || toml::to_string(&test_data).unwrap().into_bytes(), |bytes| toml::from_str::<TestData>(std::str::from_utf8(bytes).unwrap()).unwrap(), iters),
+            "xml" => time_format(name, || quick_xml::se::to_string(&test_data).unwrap().into_bytes(), |bytes| quick_xml::de::from_str::<TestData>(std::str::from_utf8(bytes).unwrap()).unwrap(), iters),
+            "borsh" => time_format(name, || borsh::to_vec(&test_data).unwrap(), |bytes| TestData::try_from_slice(bytes).unwrap(), iters),
+            "musli_storage" => time_format(name, || musli::storage::to_vec(&test_data).unwrap(), |bytes| musli::storage::from_slice::<TestData>(bytes).unwrap(), iters),
+            "musli_wire" => time_format(name, || musli::wire::to_vec(&test_data).unwrap(), |bytes| musli::wire::from_slice::<TestData>(bytes).unwrap(), iters),
+            "nanoserde_json" => time_format(name, || nano_data.serialize_json().into_bytes(), |bytes| NanoTestData::deserialize_json(std::str::from_utf8(bytes).unwrap()).unwrap(), iters),
+            "nanoserde_bin" => time_format(name, || nano_data.serialize_bin(), |bytes| NanoTestData::deserialize_bin(bytes).unwrap(), iters),
+            "flexbuffers" => time_format(name, || flexbuffers::to_vec(&test_data).unwrap(), |bytes| flexbuffers::from_slice::<TestData>(bytes).unwrap(), iters),
+            "json_value" => time_format(name, || serde_json::to_vec(&value).unwrap(), |bytes| serde_json::from_slice::<Value>(bytes).unwrap(), iters),
+            "flexbuffers_value" => time_format(name, || flexbuffers::to_vec(&value).unwrap(), |bytes| flexbuffers::from_slice::<Value>(bytes).unwrap(), iters),
+            "xdr" => time_format(name, || serde_xdr::to_bytes(&test_data).unwrap(), |bytes| serde_xdr::from_bytes::<_, TestData>(bytes).unwrap(), iters),
+            "savefile" => time_format(name, || savefile::save_to_mem(SAVEFILE_VERSION, &test_data).unwrap(), |bytes| savefile::load_from_mem::<TestData>(bytes, SAVEFILE_VERSION).unwrap(), iters),
+            "pickle" => time_format(name, || serde_pickle::to_vec(&test_data, SerOptions::new()).unwrap(), |bytes| serde_pickle::from_slice::<TestData>(bytes, DeOptions::new()).unwrap(), iters),
+            "der" => time_format(name, || rasn::der::encode(&der_data).unwrap(), |bytes| rasn::der::decode::<DerTestData>(bytes).unwrap(), iters),
+            "speedy" => time_format(name, || test_data.write_to_vec_with_ctx(Endianness::LittleEndian).unwrap(), |bytes| TestData::read_from_buffer_with_ctx(Endianness::LittleEndian, bytes).unwrap(), iters),
+            "avro" => time_format(name, || apache_avro::to_avro_datum(&schema, avro_record(&schema, &test_data)).unwrap(), |mut bytes| apache_avro::from_avro_datum(&schema, &mut bytes, None).unwrap(), iters),
+            "flatbuffers" => time_format(name, || {
+                let (buffer, head) = build_flatbuffer(&test_data);
+                buffer[head..].to_vec()
+            }, |bytes| {
+                let data = fbs::root_as_test_data(bytes).unwrap();
+                black_box((data.id(), data.name(), data.active()));
+            }, iters),
+            // simd-json parses in place, so each call would need a fresh copy
+            // of the input timed along with it; alkahest and the zero-alloc,
+            // reuse and borrowed groups measure buffer handling criterion
+            // already covers
+            _ => {
+                println!("{}: no simple-mode timer, skipped", name);
+                continue;
+            }
+        }
+        timed.push(name);
+    }
+    timed
+}
+
+// A payload the suite runs through every `Serializer`; the value itself is
+// what gets benchmarked
+trait BenchmarkPayload: Serialize + DeserializeOwned + PartialEq {
+    // Suffix of its benchmark groups, e.g. `nested` in `bincode_nested`
+    const NAME: &'static str;
+    // How benchmark labels name it, e.g. "Bincode (nested)"
+    const LABEL: &'static str;
+    // Whether its rows land in the main results table; payloads reported in a
+    // table of their own set this to false
+    const IN_RESULTS_TABLE: bool = true;
+
+    // Throughput criterion reports, the serialized length when `None`
+    fn throughput(&self) -> Option<Throughput> {
+        None
+    }
+}
+
+impl BenchmarkPayload for NestedTestData {
+    const NAME: &'static str = "nested";
+    const LABEL: &'static str = "nested";
+}
+
+impl BenchmarkPayload for MapData {
+    const NAME: &'static str = "map";
+    const LABEL: &'static str = "map";
+}
+
+impl BenchmarkPayload for MapTestData {
+    const NAME: &'static str = "string_map";
+    const LABEL: &'static str = "string map";
+}
+
+impl BenchmarkPayload for MetricsData {
+    const NAME: &'static str = "metrics";
+    const LABEL: &'static str = "metrics";
+}
+
+impl BenchmarkPayload for UnicodeData {
+    const NAME: &'static str = "unicode";
+    const LABEL: &'static str = "unicode";
+}
+
+impl BenchmarkPayload for FixtureData {
+    const NAME: &'static str = "fixture";
+    const LABEL: &'static str = "fixture";
+}
+
+impl BenchmarkPayload for TimestampData {
+    const NAME: &'static str = "timestamp";
+    const LABEL: &'static str = "timestamp";
+}
+
+impl BenchmarkPayload for WideInts {
+    const NAME: &'static str = "wide_ints";
+    const LABEL: &'static str = "wide ints";
+}
+
+impl BenchmarkPayload for DeepNested {
+    const NAME: &'static str = "deep";
+    const LABEL: &'static str = "deep";
+}
+
+impl BenchmarkPayload for NewtypeData {
+    const NAME: &'static str = "newtype";
+    const LABEL: &'static str = "newtype";
+}
+
+impl BenchmarkPayload for UuidTestData {
+    const NAME: &'static str = "uuid";
+    const LABEL: &'static str = "UUID";
+}
+
+impl BenchmarkPayload for EnumTestData {
+    const NAME: &'static str = "enum_data";
+    const LABEL: &'static str = "enum data";
+}
+
+impl BenchmarkPayload for Vec<Event> {
+    const NAME: &'static str = "enum";
+    const LABEL: &'static str = "enum";
+    const IN_RESULTS_TABLE: bool = false;
+}
+
+impl BenchmarkPayload for BigStringData {
+    const NAME: &'static str = "big_string";
+    const LABEL: &'static str = "big string";
+    const IN_RESULTS_TABLE: bool = false;
+
+    fn throughput(&self) -> Option<Throughput> {
+        Some(big_string_throughput(self))
+    }
+}
+
+// Whether `format` decodes its own encoding of `payload` back to an equal value.
+// quick-xml, for one, can't read a list of unit variants back, and XDR strings
+// are ASCII only
+fn roundtrips<S: Serializer, P: BenchmarkPayload>(format: &S, payload: &P) -> bool {
+    format.serialize(payload)
+        .and_then(|bytes| format.deserialize::<P>(&bytes))
+        .is_ok_and(|decoded| decoded == *payload)
+}
+
+// `<format>_<payload>`, built once per name and leaked, so it sits in the same
+// `&'static str` lists as the group names of the hand-written benchmarks
+fn group_name(format: &str, payload: &str) -> &'static str {
+    static NAMES: Lazy<Mutex<HashSet<&'static str>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+    let name = format!("{}_{}", format, payload);
+    let mut names = NAMES.lock().unwrap();
+    match names.get(name.as_str()) {
+        Some(name) => name,
+        None => {
+            let name: &'static str = Box::leak(name.into_boxed_str());
+            names.insert(name);
+            name
+        }
+    }
+}
+
+// The serializers of a `BenchmarkSuite`: `()`, or the ones before paired with
+// the last one added, so each keeps its type for the generic `Serializer` methods
+trait Serializers {
+    // (format, group) of every serializer that roundtrips `payload`, in the order they were added
+    fn groups<P: BenchmarkPayload>(&self, payload: &P, groups: &mut Vec<(&'static str, &'static str)>);
+    fn benchmark<P: BenchmarkPayload>(&self, c: &mut Criterion, payload: &P, selected: &dyn Fn(&str) -> bool);
+}
+
+impl Serializers for () {
+    fn groups<P: BenchmarkPayload>(&self, _: &P, _: &mut Vec<(&'static str, &'static str)>) {}
+    fn benchmark<P: BenchmarkPayload>(&self, _: &mut Criterion, _: &P, _: &dyn Fn(&str) -> bool) {}
+}
+
+impl<L: Serializers, S: Serializer> Serializers for (L, S) {
+    fn groups<P: BenchmarkPayload>(&self, payload: &P, groups: &mut Vec<(&'static str, &'static str)>) {
+        self.0.groups(payload, groups);
+        if roundtrips(&self.1, payload) {
+            groups.push((S::NAME, group_name(S::NAME, P::NAME)));
+        }
+    }
+
+    fn benchmark<P: BenchmarkPayload>(&self, c: &mut Criterion, payload: &P, selected: &dyn Fn(&str) -> bool) {
+        self.0.benchmark(c, payload, selected);
+        if !selected(S::NAME) {
+            return;
+        }
+        let group = group_name(S::NAME, P::NAME);
+        if !roundtrips(&self.1, payload) {
+            println!("{}: {} does not roundtrip the {} payload, skipped", group, S::LABEL, P::LABEL);
+            return;
+        }
+        let format = &self.1;
+        benchmark_payload_with_throughput(
+            c, group, &format!("{} ({})", S::LABEL, P::LABEL), payload, payload.throughput(),
+            |payload| format.serialize(payload).unwrap(),
+            |bytes| format.deserialize::<P>(bytes).unwrap(),
+        );
+    }
+}
+
+// The payloads of a `BenchmarkSuite`, nested the same way as `Serializers`
+trait Payloads {
+    // (format, group) of every serializer run on the payload named `payload`
+    fn groups<F: Serializers>(&self, serializers: &F, payload: &str, groups: &mut Vec<(&'static str, &'static str)>);
+    fn benchmark<F: Serializers>(&self, c: &mut Criterion, serializers: &F, payload: &str, selected: &dyn Fn(&str) -> bool);
+}
+
+impl Payloads for () {
+    fn groups<F: Serializers>(&self, _: &F, _: &str, _: &mut Vec<(&'static str, &'static str)>) {}
+    fn benchmark<F: Serializers>(&self, _: &mut Criterion, _: &F, _: &str, _: &dyn Fn(&str) -> bool) {}
+}
+
+impl<L: Payloads, P: BenchmarkPayload> Payloads for (L, P) {
+    fn groups<F: Serializers>(&self, serializers: &F, payload: &str, groups: &mut Vec<(&'static str, &'static str)>) {
+        self.0.groups(serializers, payload, groups);
+        if P::NAME == payload {
+            serializers.groups(&self.1, groups);
+        }
+    }
+
+    fn benchmark<F: Serializers>(&self, c: &mut Criterion, serializers: &F, payload: &str, selected: &dyn Fn(&str) -> bool) {
+        self.0.benchmark(c, serializers, payload, selected);
+        if P::NAME == payload {
+            serializers.benchmark(c, &self.1, selected);
+        }
+    }
+}
+
+// Runs every added `Serializer` on every added `BenchmarkPayload`, plus the
+// hand-written benchmarks of formats without a `Serializer` and of payloads
+// run in several variants. A new serde format or payload needs one
+// `add_format` or `add_payload` call and nothing else
+struct BenchmarkSuite<F, P> {
+    serializers: F,
+    payloads: P,
+    // Hand-written benchmarks, by payload name
+    benchmarks: Vec<(&'static str, &'static [FormatEntry])>,
+    // Payload names in the order they run
+    order: Vec<&'static str>,
+    // Payload names whose groups land in the main results table
+    results_table: Vec<&'static str>,
+    // Formats to run, in this order; every format when `None`
+    selected: Option<Vec<&'static str>>,
+}
+
+impl BenchmarkSuite<(), ()> {
+    fn new() -> Self {
+        BenchmarkSuite {
+            serializers: (),
+            payloads: (),
+            benchmarks: Vec::new(),
+            order: Vec::new(),
+            // The standard `TestData` payload has no `BenchmarkPayload`: each of
+            // its benchmarks also measures the medium and large sizes
+            results_table: vec!["standard"],
+            selected: None,
+        }
+    }
+}
+
+impl<F: Serializers, P: Payloads> BenchmarkSuite<F, P> {
+    fn add_format<S: Serializer>(self, format: S) -> BenchmarkSuite<(F, S), P> {
+        BenchmarkSuite {
+            serializers: (self.serializers, format),
+            payloads: self.payloads,
+            benchmarks: self.benchmarks,
+            order: self.order,
+            results_table: self.results_table,
+            selected: self.selected,
+        }
+    }
+
+    fn add_payload<Q: BenchmarkPayload>(mut self, payload: Q) -> BenchmarkSuite<F, (P, Q)> {
+        self.add_name(Q::NAME);
+        if Q::IN_RESULTS_TABLE {
+            self.results_table.push(Q::NAME);
+        }
+        BenchmarkSuite {
+            serializers: self.serializers,
+            payloads: (self.payloads, payload),
+            benchmarks: self.benchmarks,
+            order: self.order,
+            results_table: self.results_table,
+            selected: self.selected,
+        }
+    }
+
+    // Hand-written benchmarks of the payload named `payload`
+    fn add_benchmarks(mut self, payload: &'static str, formats: &'static [FormatEntry]) -> Self {
+        self.add_name(payload);
+        self.benchmarks.push((payload, formats));
+        self
+    }
+
+    // Only run `formats`, ordering every payload's groups like them
+    fn select(mut self, formats: Vec<&'static str>) -> Self {
+        self.selected = Some(formats);
+        self
+    }
+
+    fn add_name(&mut self, payload: &'static str) {
+        if !self.order.contains(&payload) {
+            self.order.push(payload);
+        }
+    }
+
+    // Position of `format` in the selection, `None` when it isn't selected
+    fn position(&self, format: &str) -> Option<usize> {
+        match &self.selected {
+            Some(selected) => selected.iter().position(|name| *name == format),
+            None => Some(0),
+        }
+    }
+
+    // The hand-written benchmarks of the payload named `payload`, for the selected formats
+    fn entries(&self, payload: &str) -> Vec<FormatEntry> {
+        let mut entries: Vec<FormatEntry> = self.benchmarks.iter()
+            .filter(|(name, _)| *name == payload)
+            .flat_map(|(_, formats)| formats.iter().copied())
+            .filter(|(format, _, _)| self.position(format).is_some())
+            .collect();
+        entries.sort_by_key(|(format, _, _)| self.position(format));
+        entries
+    }
+
+    // The groups the selected formats produce for the payload named `payload`
+    fn groups(&self, payload: &str) -> Vec<&'static str> {
+        let mut groups = Vec::new();
+        self.payloads.groups(&self.serializers, payload, &mut groups);
+        groups.retain(|(format, _)| self.position(format).is_some());
+        for (format, _, entry_groups) in self.entries(payload) {
+            groups.extend(entry_groups.iter().map(|group| (format, *group)));
+        }
+        // Stable, so a format's `Serializer` group stays ahead of its hand-written ones
+        groups.sort_by_key(|(format, _)| self.position(format));
+        groups.into_iter().map(|(_, group)| group).collect()
+    }
+
+    // The groups of every payload that lands in the main results table
+    fn table_groups(&self) -> Vec<&'static str> {
+        self.results_table.iter()
+            .flat_map(|payload| self.groups(payload))
+            .collect()
+    }
+
+    // Run every payload in the order it was added
+    fn run(&self, c: &mut Criterion) {
+        let selected = |format: &str| self.position(format).is_some();
+        for payload in &self.order {
+            self.payloads.benchmark(c, &self.serializers, payload, &selected);
+            for (_, benchmark, _) in self.entries(payload) {
+                benchmark(c);
+            }
+        }
+    }
+}
+
+// Group all benchmarks
+fn criterion_benchmark(c: &mut Criterion) {
+    pin_to_core();
+
+    roundtrip_correctness_check();
+    roundtrip_float_precision_check();
+
+    GLOBAL.reset();
+
+    let formats = selected_formats();
+    let groups: Vec<&str> = formats.iter()
+        .flat_map(|(_, _, groups)| groups.iter().copied())
+        .collect();
+
+    if std::env::var("BENCH_MODE").is_ok_and(|mode| mode == "simple") {
+        let timed_groups = run_simple_benchmarks(&groups);
+        report_size_hints(&timed_groups);
+        print_results_table(&timed_groups);
+        print_ranking_summary(&timed_groups);
+        finish_run(&timed_groups);
+        return;
+    }
+
+    // target/criterion may not be writable (e.g. a read-only container
+    // filesystem), so the results table is then timed in-process instead
+    let skip_analysis = std::env::var("BENCH_SKIP_ANALYSIS").is_ok_and(|skip| !skip.trim().is_empty() && skip.trim() != "0");
+    // In-process timings aren't comparable with a baseline of criterion estimates
+    if skip_analysis && std::env::var("BENCH_BASELINE").is_ok() {
+        panic!("BENCH_BASELINE can't be used with BENCH_SKIP_ANALYSIS: the baseline holds criterion estimates, but this run times its results in-process");
+    }
+
+    // Payloads run in this order; each one only for the selected formats
+    // that have a `Serializer` or a hand-written benchmark for it
+    let suite = BenchmarkSuite::new()
+        .add_format(serializers::Bincode)
+        .add_format(serializers::Bitcode)
+        // BCS doesn't keep a `HashMap`'s key order on the wire: it writes the
+        // entries sorted by their serialized key (and rejects unsorted ones on
+        // decode), so on the string map payload its cost includes that sort
+        .add_format(serializers::Bcs)
+        .add_format(serializers::SerdeJson);
+    #[cfg(target_arch = "x86_64")]
+    let suite = suite.add_format(serializers::SimdJson);
+    let suite = suite
+        .add_format(serializers::SonicRs)
+        .add_format(serializers::SerdeYaml)
+        .add_format(serializers::Ron)
+        .add_format(serializers::Toml)
+        .add_format(serializers::Xml)
+        .add_format(serializers::FlexBuffers)
+        .add_format(serializers::Xdr)
+        .add_format(serializers::Pickle)
+        .add_benchmarks("standard", FORMATS)
+        .add_payload(NestedTestData::sample())
+        .add_payload(MapData::sample())
+        .add_payload(MapTestData::sample())
+        .add_payload(MetricsData::sample())
+        .add_payload(UnicodeData::sample())
+        .add_payload(fixture_data())
+        .add_payload(TimestampData::sample())
+        .add_payload(WideInts::sample())
+        .add_payload(DeepNested::sample())
+        .add_payload(NewtypeData::sample())
+        .add_payload(UuidTestData::sample())
+        .add_payload(EnumTestData::sample())
+        .add_payload(Event::sample())
+        .add_payload(BigStringData::sample())
+        .add_benchmarks("nested", NESTED_FORMATS)
+        .add_benchmarks("map", MAP_FORMATS)
+        .add_benchmarks("string_map", STRING_MAP_FORMATS)
+        .add_benchmarks("metrics", METRICS_FORMATS)
+        .add_benchmarks("unicode", UNICODE_FORMATS)
+        .add_benchmarks("fixture", FIXTURE_FORMATS)
+        .add_benchmarks("timestamp", TIMESTAMP_FORMATS)
+        .add_benchmarks("wide_ints", WIDE_INT_FORMATS)
+        .add_benchmarks("deep", DEEP_FORMATS)
+        .add_benchmarks("newtype", NEWTYPE_FORMATS)
+        .add_benchmarks("uuid", UUID_FORMATS)
+        .add_benchmarks("enum_data", ENUM_DATA_FORMATS)
+        .add_benchmarks("enum", ENUM_FORMATS)
+        .add_benchmarks("big_string", BIG_STRING_FORMATS)
+        .add_benchmarks("throughput", THROUGHPUT_FORMATS)
+        .add_benchmarks("sized", SIZED_FORMATS)
+        .add_benchmarks("collection", COLLECTION_FORMATS)
+        .add_benchmarks("blob", BLOB_FORMATS)
+        .add_benchmarks("sparse", SPARSE_FORMATS)
+        .add_benchmarks("error", ERROR_FORMATS)
+        .select(formats.iter().map(|&(format, _, _)| format).collect());
+
+    let table_groups = suite.table_groups();
+    let enum_groups = suite.groups("enum");
+    let map_groups = suite.groups("map");
+    let newtype_groups = suite.groups("newtype");
+    let throughput_groups = suite.groups("throughput");
+    let big_string_groups = suite.groups("big_string");
+    let sized_groups = suite.groups("sized");
+    let collection_groups = suite.groups("collection");
+    let blob_groups = suite.groups("blob");
+    let sparse_groups = suite.groups("sparse");
+    let error_groups = suite.groups("error");
+
+    suite.run(c);
+    benchmark_with_compression(c, &groups);
+
+    // Everything below reads criterion's estimates back from target/criterion;
+    // without it only the results table is filled, from the simple mode timers
+    if skip_analysis {
+        println!("\nBENCH_SKIP_ANALYSIS is set: timing the results table in-process instead of reading target/criterion\n");
+        let timed_groups = run_simple_benchmarks(&table_groups);
+        report_size_hints(&timed_groups);
+        print_results_table(&timed_groups);
+        print_ranking_summary(&timed_groups);
+        finish_run(&timed_groups);
+        return;
+    }
+
+    // After criterion runs, we can parse the json files.
+    for group in &table_groups {
+        if let Err(err) = analyze_criterion_results(group) {
+            eprintln!("Warning: skipping '{}' in the results table: {}", group, err);
+        }
+    }
+
+    analyze_compression_results(&groups);
+    analyze_enum_results(&enum_groups);
+    analyze_throughput_results(&throughput_groups);
+    analyze_big_string_results(&big_string_groups);
+    analyze_sized_results(&sized_groups);
+    analyze_variant_results(&COLLECTION_RESULTS, "collection", &collection_groups, &COLLECTION_LENGTHS.map(|(length, _)| length));
+    analyze_variant_results(&BLOB_RESULTS, "blob", &blob_groups, &BLOB_SIZES.map(|(size, _)| size));
+    analyze_variant_results(&SPARSE_RESULTS, "sparse", &sparse_groups, &SPARSE_VARIANTS);
+
+    // Print the formatted table after all benchmarks are run
+    report_size_hints(&groups);
+    print_results_table(&table_groups);
+    print_ranking_summary(&groups);
+    if groups.contains(&"serde_json") {
+        print_json_reader_report();
+    }
+    if groups.contains(&"flexbuffers") {
+        print_flexbuffers_access_report();
+    }
+    print_map_allocation_report(&map_groups);
+    print_newtype_size_report(&newtype_groups);
+    print_borrowed_allocation_report(&groups);
+    print_enum_results_table();
+    print_throughput_table();
+    print_big_string_table();
+    print_collection_table();
+    print_variant_results_table("Blob Payload Results", &BLOB_RESULTS, &BLOB_SIZES.map(|(size, _)| size));
+    print_variant_results_table("Sparse Payload Results", &SPARSE_RESULTS, &SPARSE_VARIANTS);
+    print_error_path_table(&error_groups);
+    print_compression_table();
+
+    finish_run(&table_groups);
+}
+
+// Shared end of both modes: compare against BENCH_BASELINE, write the JSON,
+// HTML and Markdown results, then exit non-zero on a regression
+fn finish_run(table_groups: &[&str]) {
+    // Compared before the results are written, as they may overwrite the baseline file
+    let regressed = match std::env::var("BENCH_BASELINE") {
+        Ok(baseline_path) => compare_against_baseline(&baseline_path, regression_threshold()),
+        Err(_) => false,
+    };
+
+    let json_path = std::env::var("BENCH_JSON_OUT").unwrap_or_else(|_| DEFAULT_RESULTS_PATH.to_string());
+    write_results_json(&json_path);
+
+    let html_path = std::env::var("BENCH_HTML_OUT").unwrap_or_else(|_| DEFAULT_HTML_PATH.to_string());
+    write_results_html(&html_path);
+
+    let markdown_path = std::env::var("BENCH_MD_OUT").unwrap_or_else(|_| DEFAULT_MARKDOWN_PATH.to_string());
+    write_results_markdown(table_groups, &markdown_path);
+
+    #[cfg(feature = "persist")]
+    persist_results();
+
+    if regressed {
+        std::process::exit(1);
+    }
+}
+use serde_json::Value;
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+
+// Store the serialize/deserialize estimates of a group. Nothing is stored if
+// either estimate is missing or unusable, so the table never shows inf ops/sec
+fn analyze_criterion_results(group_name: &str) -> Result<(), String> {
+    let serialize_ns = read_point_estimate(group_name, "serialize")
+        .ok_or_else(|| format!("no usable serialize estimate under target/criterion/{}", group_name))?;
+    let deserialize_ns = read_point_estimate(group_name, "deserialize")
+        .ok_or_else(|| format!("no usable deserialize estimate under target/criterion/{}", group_name))?;
+
+    let total_serialize_ops = (1_000_000_000.0 / serialize_ns) as u64;
+    let total_deserialize_ops = (1_000_000_000.0 / deserialize_ns) as u64;
+
+    let serialize_std_dev = read_std_dev(group_name, "serialize");
+    let deserialize_std_dev = read_std_dev(group_name, "deserialize");
+
+    store_results(group_name, "small", serialize_ns, serialize_std_dev, deserialize_ns, deserialize_std_dev);
+    println!("Total estimated serialize time of group '{}': {:.3} ns", group_name, serialize_ns);
+    println!("Total estimated deserialize time of group '{}': {:.3} ns", group_name, deserialize_ns);
+    println!("Total estimated serialize ops of group '{}': {:.3} ops/sec", group_name, total_serialize_ops);
     println!("Total estimated deserialize ops of group '{}': {:.3} ops/sec", group_name, total_deserialize_ops);
+    Ok(())
+}
+
+// Read the estimate criterion recorded for a single bench function. Criterion
+// omits `slope` when it could not fit one (e.g. very fast benches with few
+// samples), so fall back to `mean`; zero or non-finite values are rejected
+fn read_point_estimate(group_name: &str, bench_name: &str) -> Option<f64> {
+    let json = read_estimates(group_name, bench_name)?;
+    ["slope", "mean"].iter()
+        .filter_map(|estimate| json[estimate]["point_estimate"].as_f64())
+        .find(|ns| ns.is_finite() && *ns > 0.0)
+}
+
+// Read the standard deviation of a bench function's samples, if criterion recorded one
+fn read_std_dev(group_name: &str, bench_name: &str) -> Option<f64> {
+    let json = read_estimates(group_name, bench_name)?;
+    json["std_dev"]["point_estimate"].as_f64()
+        .filter(|ns| ns.is_finite() && *ns >= 0.0)
+}
+
+fn read_estimates(group_name: &str, bench_name: &str) -> Option<Value> {
+    let estimates_path = format!("target/criterion/{}/{}/base/estimates.json", group_name, bench_name);
+    let file = File::open(estimates_path).ok()?;
+    serde_json::from_reader::<_, Value>(BufReader::new(file)).ok()
+}
+
+// Store the medium and large estimates of each `<format>_sizes` group under
+// `<format>`, next to its small result
+fn analyze_sized_results(groups: &[&str]) {
+    for group_name in groups {
+        let format = group_name.strip_suffix("_sizes").unwrap_or(group_name);
+        for size in PAYLOAD_SIZE_TIERS.into_iter().filter(|size| *size != "small") {
+            let serialize_bench = format!("serialize/{}", size);
+            let deserialize_bench = format!("deserialize/{}", size);
+
+            match (read_point_estimate(group_name, &serialize_bench), read_point_estimate(group_name, &deserialize_bench)) {
+                (Some(serialize_ns), Some(deserialize_ns)) => store_results(
+                    format, size,
+                    serialize_ns, read_std_dev(group_name, &serialize_bench),
+                    deserialize_ns, read_std_dev(group_name, &deserialize_bench),
+                ),
+                _ => eprintln!("Warning: skipping '{}' ({}) in the results table: no usable estimates under target/criterion/{}", format, size, group_name),
+            }
+        }
+    }
+}
+
+// Store every variant of each group, read from `<group>/serialize/<variant>`
+// and `<group>/deserialize/<variant>`
+fn analyze_variant_results(results: &Mutex<VariantResultsMap>, table: &str, groups: &[&str], variants: &[&'static str]) {
+    for group_name in groups {
+        for variant in variants {
+            let serialize_ns = read_point_estimate(group_name, &format!("serialize/{}", variant));
+            let deserialize_ns = read_point_estimate(group_name, &format!("deserialize/{}", variant));
+
+            match (serialize_ns, deserialize_ns) {
+                (Some(serialize_ns), Some(deserialize_ns)) => store_variant_results(results, group_name, variant, serialize_ns, deserialize_ns),
+                _ => eprintln!("Warning: skipping '{}' ({}) in the {} table: no usable estimates under target/criterion/{}", group_name, variant, table, group_name),
+            }
+        }
+    }
+}
+
+fn analyze_compression_results(groups: &[&str]) {
+    for (format, serialized_data) in serialized_payloads() {
+        if !groups.contains(&format) {
+            continue;
+        }
+
+        for (codec, compress, _) in COMPRESSION_CODECS {
+            let group_name = format!("{}_{}", format, codec);
+            match (read_point_estimate(&group_name, "compress"), read_point_estimate(&group_name, "decompress")) {
+                (Some(compress_ns), Some(decompress_ns)) => store_compression_results(&group_name, serialized_data.len(), compress(&serialized_data).len(), compress_ns, decompress_ns),
+                _ => eprintln!("Warning: skipping '{}' in the compression table: no usable estimates under target/criterion/{}", group_name, group_name),
+            }
+        }
+    }
+}
+
+fn analyze_enum_results(groups: &[&str]) {
+    for group_name in groups {
+        match (read_point_estimate(group_name, "serialize"), read_point_estimate(group_name, "deserialize")) {
+            (Some(serialize_ns), Some(deserialize_ns)) => store_enum_results(group_name, serialize_ns, deserialize_ns),
+            _ => eprintln!("Warning: skipping '{}' in the enum table: no usable estimates under target/criterion/{}", group_name, group_name),
+        }
+    }
+}
+
+fn analyze_big_string_results(groups: &[&str]) {
+    for group_name in groups {
+        match (read_point_estimate(group_name, "serialize"), read_point_estimate(group_name, "deserialize")) {
+            (Some(serialize_ns), Some(deserialize_ns)) => store_big_string_results(group_name, serialize_ns, deserialize_ns),
+            _ => eprintln!("Warning: skipping '{}' in the big string table: no usable estimates under target/criterion/{}", group_name, group_name),
+        }
+    }
+}
+
+fn analyze_throughput_results(groups: &[&str]) {
+    let batch_size = batch_size();
+    for group_name in groups {
+        let serialize_ns = read_point_estimate(group_name, "serialize");
+        let deserialize_ns = read_point_estimate(group_name, "deserialize");
+
+        match (serialize_ns, deserialize_ns) {
+            (Some(serialize_ns), Some(deserialize_ns)) => store_throughput_results(group_name, batch_size, serialize_ns, deserialize_ns),
+            _ => eprintln!("Warning: skipping '{}' in the throughput table: no usable estimates under target/criterion/{}", group_name, group_name),
+        }
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
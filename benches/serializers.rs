@@ -0,0 +1,217 @@
+// The serde formats, each one implementation of `Serializer` that
+// `BenchmarkSuite` runs on every `BenchmarkPayload`. Formats with their own
+// derive or schema (protobuf, Borsh, Musli, ...) keep hand-written benchmarks
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_pickle::{DeOptions, SerOptions};
+use std::error::Error;
+
+// A format that encodes any serde type; its benchmark group for a payload is
+// `<NAME>_<payload name>`
+pub trait Serializer {
+    // The identifier BENCH_FORMATS selects it by
+    const NAME: &'static str;
+    // How benchmark labels name it, e.g. "Serde JSON (nested)"
+    const LABEL: &'static str;
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>>;
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>>;
+}
+
+pub struct Bincode;
+
+impl Serializer for Bincode {
+    const NAME: &'static str = "bincode";
+    const LABEL: &'static str = "Bincode";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(bincode::serialize(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+}
+
+pub struct Bitcode;
+
+impl Serializer for Bitcode {
+    const NAME: &'static str = "bitcode";
+    const LABEL: &'static str = "bitcode";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(bitcode::serialize(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(bitcode::deserialize(bytes)?)
+    }
+}
+
+pub struct Bcs;
+
+impl Serializer for Bcs {
+    const NAME: &'static str = "bcs";
+    const LABEL: &'static str = "BCS";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(bcs::to_bytes(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+}
+
+pub struct SerdeJson;
+
+impl Serializer for SerdeJson {
+    const NAME: &'static str = "serde_json";
+    const LABEL: &'static str = "Serde JSON";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(serde_json::to_vec(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+// Deserializing copies the input, as simd-json parses in place
+#[cfg(target_arch = "x86_64")]
+pub struct SimdJson;
+
+#[cfg(target_arch = "x86_64")]
+impl Serializer for SimdJson {
+    const NAME: &'static str = "simd_json";
+    const LABEL: &'static str = "SIMD JSON";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(simd_json::serde::to_vec(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(simd_json::serde::from_slice(&mut bytes.to_vec())?)
+    }
+}
+
+pub struct SonicRs;
+
+impl Serializer for SonicRs {
+    const NAME: &'static str = "sonic_rs";
+    const LABEL: &'static str = "sonic-rs";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(sonic_rs::to_vec(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(sonic_rs::from_slice(bytes)?)
+    }
+}
+
+pub struct SerdeYaml;
+
+impl Serializer for SerdeYaml {
+    const NAME: &'static str = "serde_yaml";
+    const LABEL: &'static str = "Serde YAML";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(serde_yaml::to_string(value)?.into_bytes())
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(serde_yaml::from_slice(bytes)?)
+    }
+}
+
+pub struct Ron;
+
+impl Serializer for Ron {
+    const NAME: &'static str = "ron";
+    const LABEL: &'static str = "RON";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(ron::to_string(value)?.into_bytes())
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(ron::de::from_bytes(bytes)?)
+    }
+}
+
+pub struct Toml;
+
+impl Serializer for Toml {
+    const NAME: &'static str = "toml";
+    const LABEL: &'static str = "TOML";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(toml::to_string(value)?.into_bytes())
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(toml::from_str(std::str::from_utf8(bytes)?)?)
+    }
+}
+
+pub struct Xml;
+
+impl Serializer for Xml {
+    const NAME: &'static str = "xml";
+    const LABEL: &'static str = "XML";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(quick_xml::se::to_string(value)?.into_bytes())
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(quick_xml::de::from_str(std::str::from_utf8(bytes)?)?)
+    }
+}
+
+pub struct FlexBuffers;
+
+impl Serializer for FlexBuffers {
+    const NAME: &'static str = "flexbuffers";
+    const LABEL: &'static str = "FlexBuffers";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(flexbuffers::to_vec(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(flexbuffers::from_slice(bytes)?)
+    }
+}
+
+pub struct Xdr;
+
+impl Serializer for Xdr {
+    const NAME: &'static str = "xdr";
+    const LABEL: &'static str = "XDR";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(serde_xdr::to_bytes(value)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(serde_xdr::from_bytes(bytes)?)
+    }
+}
+
+pub struct Pickle;
+
+impl Serializer for Pickle {
+    const NAME: &'static str = "pickle";
+    const LABEL: &'static str = "Pickle";
+
+    fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Box<dyn Error>> {
+        Ok(serde_pickle::to_vec(value, SerOptions::new())?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, Box<dyn Error>> {
+        Ok(serde_pickle::from_slice(bytes, DeOptions::new())?)
+    }
+}
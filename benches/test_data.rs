@@ -0,0 +1,211 @@
+// `TestData`, the standard payload, and its conversions to and from each
+// format's own types. Shared by path with tests/roundtrip.rs, since the
+// package has no library target
+use alkahest::alkahest;
+use benchdata_derive::BenchmarkData;
+use borsh::{BorshSerialize, BorshDeserialize};
+use flatbuffers::FlatBufferBuilder;
+use musli::{Encode, Decode};
+use nanoserde::{DeBin, DeJson, SerBin, SerJson};
+// Imported anonymously: its name clashes with rust-protobuf's `Message`
+use prost::Message as _;
+use quick_protobuf::{MessageWrite, Writer};
+use rand::Rng;
+// `rasn::Decode`'s derive output calls `Decoder` methods unqualified
+use rasn::Decoder;
+use savefile_derive::Savefile;
+use serde::{Serialize, Deserialize};
+use speedy::{Readable, Writable};
+use std::borrow::Cow;
+use std::ops::RangeInclusive;
+
+use crate::{fbs, message_capnp, prost_proto, proto, quick_proto};
+
+// Avro writer (and reader) schema for `TestData`; `long` because Avro's `int` is signed 32-bit
+const AVRO_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "TestData",
+    "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "name", "type": "string"},
+        {"name": "active", "type": "boolean"}
+    ]
+}"#;
+
+// Schema version written into (and checked against) every savefile payload
+pub const SAVEFILE_VERSION: u32 = 0;
+
+// Characters `TestData::random` builds names from, mixing 1-, 2-, 3- and
+// 4-byte UTF-8 encodings
+pub const RANDOM_NAME_CHARS: &[char] = &[
+    'a', 'e', 'r', 's', 't', 'R', 'Z', '0', '7', '-',
+    'é', 'ß', 'ж', 'λ',
+    '中', '文', 'ツ', '한',
+    '🦀', '🚀',
+];
+
+#[derive(Serialize, Deserialize, BorshSerialize, BorshDeserialize, Encode, Decode, Savefile, Readable, Writable, BenchmarkData, Debug, Clone, PartialEq)]
+#[alkahest(SerializeRef<AlkahestTestData>)]
+pub struct TestData {
+    pub id: u32,
+    pub name: String,
+    pub active: bool,
+}
+
+// alkahest schema (`Formula`) with the same layout as `TestData`
+#[alkahest(Formula)]
+pub struct AlkahestTestData {
+    id: u32,
+    name: String,
+    active: bool,
+}
+
+// Zero-copy read side of `AlkahestTestData`: `name` borrows from the buffer
+#[alkahest(Deserialize<'de, AlkahestTestData>)]
+pub struct AlkahestTestDataView<'de> {
+    pub id: u32,
+    pub name: &'de str,
+    pub active: bool,
+}
+
+impl TestData {
+    pub fn new() -> Self {
+        TestData {
+            id: 1,
+            name: "Rust".to_string(),
+            active: true,
+        }
+    }
+
+    // A `TestData` with a random id and flag, and a name of `name_chars`
+    // characters drawn from `RANDOM_NAME_CHARS`
+    pub fn random(rng: &mut impl Rng, name_chars: &RangeInclusive<usize>) -> Self {
+        let len = rng.random_range(name_chars.clone());
+        TestData {
+            id: rng.random(),
+            name: (0..len)
+                .map(|_| RANDOM_NAME_CHARS[rng.random_range(0..RANDOM_NAME_CHARS.len())])
+                .collect(),
+            active: rng.random(),
+        }
+    }
+}
+
+// Mirror of `TestData` for nanoserde, which uses its own derives instead of serde
+#[derive(SerJson, DeJson, SerBin, DeBin, Debug, Clone, PartialEq)]
+pub struct NanoTestData {
+    pub id: u32,
+    pub name: String,
+    pub active: bool,
+}
+
+// Mirror of `TestData` as an ASN.1 SEQUENCE { INTEGER, UTF8String, BOOLEAN };
+// rasn's derives share their names with musli's, hence the full paths
+#[derive(rasn::AsnType, rasn::Encode, rasn::Decode, Debug, Clone, PartialEq)]
+pub struct DerTestData {
+    pub id: u32,
+    pub name: rasn::types::Utf8String,
+    pub active: bool,
+}
+
+pub fn proto_test_data(test_data: &TestData) -> proto::TestData {
+    proto::TestData {
+        id: test_data.id,
+        name: test_data.name.clone(),
+        active: test_data.active,
+        ..Default::default()
+    }
+}
+
+// quick-protobuf mirror of `TestData`; the name is borrowed rather than copied
+pub fn quick_proto_test_data(test_data: &TestData) -> quick_proto::TestData<'_> {
+    quick_proto::TestData {
+        id: test_data.id,
+        name: Cow::Borrowed(&test_data.name),
+        active: test_data.active,
+    }
+}
+
+pub fn serialize_quick_protobuf(message: &quick_proto::TestData) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(message.get_size());
+    message.write_message(&mut Writer::new(&mut buffer)).unwrap();
+    buffer
+}
+
+// prost mirror of `TestData`
+pub fn prost_test_data(test_data: &TestData) -> prost_proto::TestData {
+    prost_proto::TestData {
+        id: test_data.id,
+        name: test_data.name.clone(),
+        active: test_data.active,
+    }
+}
+
+pub fn serialize_prost(message: &prost_proto::TestData) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(message.encoded_len());
+    message.encode(&mut buffer).unwrap();
+    buffer
+}
+
+pub fn build_capnp_message(test_data: &TestData) -> capnp::message::Builder<capnp::message::HeapAllocator> {
+    let mut message = capnp::message::Builder::new_default();
+    let mut root = message.init_root::<message_capnp::test_data::Builder>();
+    root.set_id(test_data.id);
+    root.set_name(test_data.name.as_str());
+    root.set_active(test_data.active);
+    message
+}
+
+pub fn serialize_capnp(message: &capnp::message::Builder<capnp::message::HeapAllocator>) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    capnp::serialize::write_message(&mut buffer, message).unwrap();
+    buffer
+}
+
+// Build a finished FlatBuffer; the returned Vec holds the data from the offset onwards
+pub fn build_flatbuffer(test_data: &TestData) -> (Vec<u8>, usize) {
+    let mut builder = FlatBufferBuilder::with_capacity(64);
+    let name = builder.create_string(&test_data.name);
+    let root = fbs::TestData::create(&mut builder, &fbs::TestDataArgs {
+        id: test_data.id,
+        name: Some(name),
+        active: test_data.active,
+    });
+    fbs::finish_test_data_buffer(&mut builder, root);
+    builder.collapse()
+}
+
+pub fn nano_test_data(test_data: &TestData) -> NanoTestData {
+    NanoTestData {
+        id: test_data.id,
+        name: test_data.name.clone(),
+        active: test_data.active,
+    }
+}
+
+pub fn avro_schema() -> apache_avro::Schema {
+    apache_avro::Schema::parse_str(AVRO_SCHEMA).unwrap()
+}
+
+pub fn avro_record(schema: &apache_avro::Schema, test_data: &TestData) -> apache_avro::types::Value {
+    let mut record = apache_avro::types::Record::new(schema).unwrap();
+    record.put("id", i64::from(test_data.id));
+    record.put("name", test_data.name.as_str());
+    record.put("active", test_data.active);
+    record.into()
+}
+
+pub fn serialize_alkahest(test_data: &TestData) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let (len, _) = alkahest::serialize_to_vec::<AlkahestTestData, _>(test_data, &mut buffer);
+    buffer.truncate(len);
+    buffer
+}
+
+pub fn der_test_data(test_data: &TestData) -> DerTestData {
+    DerTestData {
+        id: test_data.id,
+        name: test_data.name.clone(),
+        active: test_data.active,
+    }
+}
@@ -1,68 +1,52 @@
 use std::alloc::{GlobalAlloc, Layout};
 use std::sync::atomic::{AtomicU64, Ordering};
 
-pub struct Trallocator<A: GlobalAlloc>(pub A, AtomicU64);
+// Tracks live bytes, the number of `alloc` calls, and the bytes released and
+// number of `dealloc` calls
+pub struct Trallocator<A: GlobalAlloc>(pub A, AtomicU64, AtomicU64, AtomicU64, AtomicU64);
 
 unsafe impl<A: GlobalAlloc> GlobalAlloc for Trallocator<A> {
     unsafe fn alloc(&self, l: Layout) -> *mut u8 {
         self.1.fetch_add(l.size() as u64, Ordering::SeqCst);
+        self.2.fetch_add(1, Ordering::SeqCst);
         self.0.alloc(l)
     }
     unsafe fn dealloc(&self, ptr: *mut u8, l: Layout) {
         self.0.dealloc(ptr, l);
         self.1.fetch_sub(l.size() as u64, Ordering::SeqCst);
+        self.3.fetch_add(l.size() as u64, Ordering::SeqCst);
+        self.4.fetch_add(1, Ordering::SeqCst);
     }
 }
 
 impl<A: GlobalAlloc> Trallocator<A> {
     pub const fn new(a: A) -> Self {
-        Trallocator(a, AtomicU64::new(0))
+        Trallocator(a, AtomicU64::new(0), AtomicU64::new(0), AtomicU64::new(0), AtomicU64::new(0))
     }
 
     pub fn reset(&self) {
         self.1.store(0, Ordering::SeqCst);
+        self.3.store(0, Ordering::SeqCst);
+        self.4.store(0, Ordering::SeqCst);
     }
     pub fn get(&self) -> u64 {
         self.1.load(Ordering::SeqCst)
     }
+    pub fn allocations(&self) -> u64 {
+        self.2.load(Ordering::SeqCst)
+    }
+    pub fn get_dealloc_bytes(&self) -> u64 {
+        self.3.load(Ordering::SeqCst)
+    }
+    pub fn get_dealloc_count(&self) -> u64 {
+        self.4.load(Ordering::SeqCst)
+    }
 }
 
-
-
-// use jemalloc_sys::malloc_stats_print;
-// use std::ffi::{CStr, CString};
-// use std::ptr::{null, null_mut};
-// use libc::{c_char, c_void};
-// use std::sync::Mutex;
-// use std::io::{Write};
-
-// // Global buffer to store memory stats
-// static OUTPUT_BUFFER: Mutex<Vec<u8>> = Mutex::new(Vec::new());
-
-// // Custom callback to capture jemalloc stats output
-// extern "C" fn write_cb(_: *mut c_void, message: *const c_char) {
-//     let msg = unsafe { CStr::from_ptr(message).to_string_lossy() };
-
-//     // Only capture key memory stats
-//     let keywords = ["allocated", "active", "mapped", "resident"];
-//     if keywords.iter().any(|&key| msg.contains(key)) {
-//         let mut buffer = OUTPUT_BUFFER.lock().unwrap();
-//         writeln!(buffer, "{}", msg).unwrap();
-//     }
-// }
-
-// // Function to print filtered memory statistics
-// fn mem_print() {
-//     {
-//         let mut buffer = OUTPUT_BUFFER.lock().unwrap();
-//         buffer.clear(); // Clear previous output
-//     }
-
-//     unsafe {
-//         malloc_stats_print(Some(write_cb), null_mut(), null());
-//     }
-
-//     // Print the filtered memory stats
-//     let buffer = OUTPUT_BUFFER.lock().unwrap();
-//     print!("{}", String::from_utf8_lossy(&buffer));
-// }
\ No newline at end of file
+// Bytes currently allocated by the application according to jemalloc. Its
+// statistics are cached, so the epoch is advanced first to refresh them
+#[cfg(feature = "jemalloc")]
+pub fn jemalloc_allocated() -> Option<u64> {
+    tikv_jemalloc_ctl::epoch::advance().ok()?;
+    tikv_jemalloc_ctl::stats::allocated::read().ok().map(|bytes| bytes as u64)
+}
@@ -1,4 +1,141 @@
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::process::{self, Command, Stdio};
+
+// Where the benchmark writes its results for the CLI to read back
+const RESULTS_PATH: &str = "target/cli_results.json";
+
+// Payload sizes the benchmark reports results for
+const PAYLOADS: [&str; 3] = ["small", "medium", "large"];
+
+const USAGE: &str = "Usage:
+    rust-serializers-comparison run [--formats <a,b,...>] [--payload <small|medium|large>] [--out <table|json>]
+    rust-serializers-comparison help
+
+run   Runs the criterion benchmarks for the given formats (all by default) and
+      prints the results for one payload size (small by default), as a table
+      or as JSON. Criterion's own output goes to stderr.";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Output {
+    Table,
+    Json,
+}
+
+struct RunOptions {
+    formats: Option<String>,
+    payload: String,
+    out: Output,
+}
 
 fn main() {
-    println!("benchmark");
-}
\ No newline at end of file
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => parse_run_options(&args[1..]).and_then(|options| run(&options)),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(other) => Err(format!("unknown subcommand '{}'", other)),
+        None => Err("missing subcommand".to_string()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("Error: {}\n\n{}", err, USAGE);
+        process::exit(2);
+    }
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, String> {
+    let mut options = RunOptions {
+        formats: None,
+        payload: "small".to_string(),
+        out: Output::Table,
+    };
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", flag));
+        match flag.as_str() {
+            "--formats" => options.formats = Some(value()?.clone()),
+            "--payload" => {
+                let payload = value()?;
+                if !PAYLOADS.contains(&payload.as_str()) {
+                    return Err(format!("unknown payload '{}', expected one of: {}", payload, PAYLOADS.join(", ")));
+                }
+                options.payload = payload.clone();
+            }
+            "--out" => {
+                options.out = match value()?.as_str() {
+                    "table" => Output::Table,
+                    "json" => Output::Json,
+                    other => return Err(format!("unknown output '{}', expected table or json", other)),
+                };
+            }
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+    }
+
+    Ok(options)
+}
+
+// Run the benchmark through cargo, which hands the format filter and the
+// results path to it via BENCH_FORMATS and BENCH_JSON_OUT
+fn run(options: &RunOptions) -> Result<(), String> {
+    // A stale file from an earlier run must not pass for this run's results
+    let _ = fs::remove_file(RESULTS_PATH);
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["bench", "--bench", "serialization_benchmark"])
+        .env("BENCH_JSON_OUT", RESULTS_PATH)
+        .stdout(Stdio::from(io::stderr()));
+    if let Some(formats) = &options.formats {
+        command.env("BENCH_FORMATS", formats);
+    }
+
+    let status = command.status().map_err(|err| format!("could not run cargo bench: {}", err))?;
+    if !status.success() {
+        return Err(format!("cargo bench failed with {}", status));
+    }
+
+    let json = fs::read_to_string(RESULTS_PATH)
+        .map_err(|err| format!("could not read {}: {}", RESULTS_PATH, err))?;
+    let results: Value = serde_json::from_str(&json)
+        .map_err(|err| format!("could not parse {}: {}", RESULTS_PATH, err))?;
+
+    // Results sit under `results`, nested by format, then payload size; keep
+    // the requested size
+    let selected: serde_json::Map<String, Value> = results["results"].as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(format, sizes)| Some((format.clone(), sizes.get(&options.payload)?.clone())))
+        .collect();
+
+    match options.out {
+        Output::Json => println!("{}", serde_json::to_string_pretty(&selected).unwrap()),
+        Output::Table => print_table(&options.payload, &selected),
+    }
+    Ok(())
+}
+
+fn print_table(payload: &str, results: &serde_json::Map<String, Value>) {
+    println!("{:<24} | {:<16} | {:<18} | {:<18} | {:<20}",
+             format!("Format ({})", payload),
+             "Serialize (ns)",
+             "Serialize ops/sec",
+             "Deserialize (ns)",
+             "Deserialize ops/sec");
+    println!("{:-<24}-+-{:-<16}-+-{:-<18}-+-{:-<18}-+-{:-<20}", "", "", "", "", "");
+
+    for (format, result) in results {
+        println!("{:<24} | {:<16.2} | {:<18} | {:<18.2} | {:<20}",
+                 format,
+                 result["serialize_time_ns"].as_f64().unwrap_or(0.0),
+                 result["serialize_ops_per_sec"].as_u64().unwrap_or(0),
+                 result["deserialize_time_ns"].as_f64().unwrap_or(0.0),
+                 result["deserialize_ops_per_sec"].as_u64().unwrap_or(0));
+    }
+}
@@ -0,0 +1,76 @@
+// The results file the benchmark emits must keep the schema CI and
+// BENCH_BASELINE read: run metadata under `metadata`, and the results under
+// `results`, nested by format, then payload size, in sorted key order
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[path = "../benches/results_file.rs"]
+mod results_file;
+use results_file::{BenchmarkResults, ResultsFile, RunMetadata};
+
+fn sample_result(payload_bytes: usize) -> BenchmarkResults {
+    BenchmarkResults {
+        serialize_time_ns: 25.0,
+        serialize_std_dev_ns: Some(0.5),
+        serialize_ops_per_sec: 40_000_000,
+        deserialize_time_ns: 50.0,
+        deserialize_std_dev_ns: None,
+        deserialize_ops_per_sec: 20_000_000,
+        payload_bytes,
+        serialize_cpu_ns: None,
+        deserialize_cpu_ns: None,
+        serialize_mb_per_sec: Some(payload_bytes as f64 * 1000.0 / 25.0),
+        deserialize_mb_per_sec: Some(payload_bytes as f64 * 1000.0 / 50.0),
+    }
+}
+
+// Write a file with two formats, the first with two payload sizes, the way
+// the benchmark does at the end of a run
+fn emit(name: &str) -> String {
+    let path = format!("{}/{}/results.json", env!("CARGO_TARGET_TMPDIR"), name);
+    let mut results: BTreeMap<String, BTreeMap<String, BenchmarkResults>> = BTreeMap::new();
+    results.entry("serde_json".to_string()).or_default().insert("small".to_string(), sample_result(37));
+    results.entry("serde_json".to_string()).or_default().insert("medium".to_string(), sample_result(4_000));
+    results.entry("bincode".to_string()).or_default().insert("small".to_string(), sample_result(17));
+    ResultsFile { metadata: RunMetadata::current(), results }.write(&path).unwrap();
+    path
+}
+
+#[test]
+fn emitted_file_has_the_documented_schema() {
+    let path = emit("schema");
+    let json: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+    let top: Vec<&str> = json.as_object().unwrap().keys().map(String::as_str).collect();
+    assert_eq!(top, ["metadata", "results"]);
+
+    let timestamp = json["metadata"]["timestamp"].as_str().unwrap();
+    assert!(chrono::DateTime::parse_from_rfc3339(timestamp).is_ok(), "timestamp {:?} is not RFC 3339", timestamp);
+    assert!(json["metadata"]["rustc_version"].is_string());
+
+    let results = json["results"].as_object().unwrap();
+    assert_eq!(results.keys().collect::<Vec<_>>(), ["bincode", "serde_json"]);
+    assert_eq!(results["serde_json"].as_object().unwrap().keys().collect::<Vec<_>>(), ["medium", "small"]);
+    for sizes in results.values() {
+        for result in sizes.as_object().unwrap().values() {
+            for field in ["serialize_time_ns", "deserialize_time_ns"] {
+                assert!(result[field].is_f64(), "{} is not a number", field);
+            }
+            for field in ["serialize_ops_per_sec", "deserialize_ops_per_sec", "payload_bytes"] {
+                assert!(result[field].is_u64(), "{} is not an unsigned integer", field);
+            }
+            for field in ["serialize_std_dev_ns", "deserialize_std_dev_ns", "serialize_cpu_ns", "deserialize_cpu_ns",
+                          "serialize_mb_per_sec", "deserialize_mb_per_sec"] {
+                assert!(result[field].is_f64() || result[field].is_null(), "{} is neither a number nor null", field);
+            }
+        }
+    }
+}
+
+#[test]
+fn emitted_file_reads_back() {
+    let path = emit("read_back");
+    let file = ResultsFile::read(&path).unwrap();
+    assert_eq!(file.results["serde_json"]["medium"].payload_bytes, 4_000);
+    assert_eq!(file.results["bincode"]["small"].serialize_ops_per_sec, 40_000_000);
+}
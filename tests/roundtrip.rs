@@ -0,0 +1,149 @@
+// Every format must decode random `TestData` values back to exactly the input.
+// proptest shrinks a failing value to the smallest one it can find and the
+// failure names the format that broke. Set PROPTEST_CASES to run more values
+// than proptest's default of 256
+use bcs::{from_bytes, to_bytes};
+use bincode::{deserialize, serialize};
+use borsh::BorshDeserialize;
+use nanoserde::{DeBin, DeJson, SerBin, SerJson};
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use protobuf::Message;
+// Imported anonymously: its name clashes with rust-protobuf's `Message`
+use prost::Message as _;
+use quick_protobuf::{BytesReader, MessageRead};
+use serde_json::{from_str, to_string};
+use serde_pickle::{DeOptions, SerOptions};
+use speedy::{Endianness, Readable, Writable};
+
+// The bench target's generated code and `TestData`, included by path
+#[allow(dead_code)]
+#[path = "../benches/message.rs"]
+mod message;
+#[allow(dead_code)]
+#[path = "../benches/message_capnp.rs"]
+mod message_capnp;
+#[allow(dead_code)]
+#[path = "../benches/message_generated.rs"]
+mod message_generated;
+#[allow(dead_code)]
+#[path = "../benches/quick_message.rs"]
+mod quick_message;
+#[allow(dead_code)]
+#[path = "../benches/prost_message.rs"]
+mod prost_message;
+#[allow(dead_code)]
+#[path = "../benches/quick_timestamp.rs"]
+mod quick_timestamp;
+mod google {
+    pub mod protobuf {
+        pub use crate::quick_timestamp::Timestamp;
+    }
+}
+#[allow(dead_code)]
+#[path = "../benches/test_data.rs"]
+mod test_data;
+use message as proto;
+use message_generated as fbs;
+use prost_message as prost_proto;
+use quick_message as quick_proto;
+use test_data::{
+    avro_record, avro_schema, build_capnp_message, build_flatbuffer, der_test_data, nano_test_data, prost_test_data,
+    proto_test_data, quick_proto_test_data, serialize_alkahest, serialize_capnp, serialize_prost, serialize_quick_protobuf,
+    AlkahestTestData, AlkahestTestDataView, DerTestData, NanoTestData, TestData, RANDOM_NAME_CHARS, SAVEFILE_VERSION,
+};
+
+// Encode-then-decode of `TestData` in one format, with `None` for an encode or
+// decode error
+type Codec = fn(&TestData) -> Option<TestData>;
+
+// Every format that has a standard benchmark
+const TEST_DATA_CODECS: &[(&str, Codec)] = &[
+    ("bincode", |data| deserialize(&serialize(data).ok()?).ok()),
+    ("bitcode", |data| bitcode::deserialize(&bitcode::serialize(data).ok()?).ok()),
+    ("bcs", |data| from_bytes(&to_bytes(data).ok()?).ok()),
+    ("protobuf", |data| {
+        let message = proto::TestData::parse_from_bytes(&proto_test_data(data).write_to_bytes().ok()?).ok()?;
+        Some(TestData { id: message.id, name: message.name, active: message.active })
+    }),
+    ("quick_protobuf", |data| {
+        let bytes = serialize_quick_protobuf(&quick_proto_test_data(data));
+        let message = quick_proto::TestData::from_reader(&mut BytesReader::from_bytes(&bytes), &bytes).ok()?;
+        Some(TestData { id: message.id, name: message.name.into_owned(), active: message.active })
+    }),
+    ("prost", |data| {
+        let message = prost_proto::TestData::decode(serialize_prost(&prost_test_data(data)).as_slice()).ok()?;
+        Some(TestData { id: message.id, name: message.name, active: message.active })
+    }),
+    ("capnp", |data| {
+        let bytes = serialize_capnp(&build_capnp_message(data));
+        let message = capnp::serialize::read_message_from_flat_slice(&mut bytes.as_slice(), capnp::message::ReaderOptions::new()).ok()?;
+        let reader = message.get_root::<message_capnp::test_data::Reader>().ok()?;
+        Some(TestData { id: reader.get_id(), name: reader.get_name().ok()?.to_str().ok()?.to_string(), active: reader.get_active() })
+    }),
+    ("serde_json", |data| from_str(&to_string(data).ok()?).ok()),
+    #[cfg(target_arch = "x86_64")]
+    ("simd_json", |data| simd_json::serde::from_slice(&mut simd_json::serde::to_vec(data).ok()?).ok()),
+    ("sonic_rs", |data| sonic_rs::from_str(&sonic_rs::to_string(data).ok()?).ok()),
+    ("serde_yaml", |data| serde_yaml::from_str(&serde_yaml::to_string(data).ok()?).ok()),
+    ("ron", |data| ron::from_str(&ron::to_string(data).ok()?).ok()),
+    ("toml", |data| toml::from_str(&toml::to_string(data).ok()?).ok()),
+    ("xml", |data| quick_xml::de::from_str(&quick_xml::se::to_string(data).ok()?).ok()),
+    ("borsh", |data| TestData::try_from_slice(&borsh::to_vec(data).ok()?).ok()),
+    ("musli_storage", |data| musli::storage::from_slice(&musli::storage::to_vec(data).ok()?).ok()),
+    ("musli_wire", |data| musli::wire::from_slice(&musli::wire::to_vec(data).ok()?).ok()),
+    ("nanoserde_json", |data| {
+        let decoded = NanoTestData::deserialize_json(&nano_test_data(data).serialize_json()).ok()?;
+        Some(TestData { id: decoded.id, name: decoded.name, active: decoded.active })
+    }),
+    ("nanoserde_bin", |data| {
+        let decoded = NanoTestData::deserialize_bin(&nano_test_data(data).serialize_bin()).ok()?;
+        Some(TestData { id: decoded.id, name: decoded.name, active: decoded.active })
+    }),
+    ("flatbuffers", |data| {
+        let (buffer, head) = build_flatbuffer(data);
+        let table = fbs::root_as_test_data(&buffer[head..]).ok()?;
+        Some(TestData { id: table.id(), name: table.name().unwrap_or_default().to_string(), active: table.active() })
+    }),
+    ("flexbuffers", |data| flexbuffers::from_slice(&flexbuffers::to_vec(data).ok()?).ok()),
+    ("xdr", |data| serde_xdr::from_bytes::<_, TestData>(serde_xdr::to_bytes(data).ok()?).ok()),
+    ("savefile", |data| savefile::load_from_mem(&savefile::save_to_mem(SAVEFILE_VERSION, data).ok()?, SAVEFILE_VERSION).ok()),
+    ("pickle", |data| serde_pickle::from_slice(&serde_pickle::to_vec(data, SerOptions::new()).ok()?, DeOptions::new()).ok()),
+    ("der", |data| {
+        let decoded = rasn::der::decode::<DerTestData>(&rasn::der::encode(&der_test_data(data)).ok()?).ok()?;
+        Some(TestData { id: decoded.id, name: decoded.name, active: decoded.active })
+    }),
+    ("alkahest", |data| {
+        let bytes = serialize_alkahest(data);
+        let view = alkahest::deserialize::<AlkahestTestData, AlkahestTestDataView>(&bytes).ok()?;
+        Some(TestData { id: view.id, name: view.name.to_string(), active: view.active })
+    }),
+    ("speedy", |data| TestData::read_from_buffer_with_ctx(Endianness::LittleEndian, &data.write_to_vec_with_ctx(Endianness::LittleEndian).ok()?).ok()),
+    ("avro", |data| {
+        let schema = avro_schema();
+        let datum = apache_avro::to_avro_datum(&schema, avro_record(&schema, data)).ok()?;
+        apache_avro::from_value(&apache_avro::from_avro_datum(&schema, &mut datum.as_slice(), None).ok()?).ok()
+    }),
+];
+
+// XDR strings are ASCII (RFC 4506) and serde-xdr refuses to encode any other,
+// so these formats must reject non-ASCII names instead of roundtripping them
+const ASCII_ONLY: [&str; 1] = ["xdr"];
+
+// Names are 0 to 64 characters of `RANDOM_NAME_CHARS`, mixing 1- to 4-byte
+// UTF-8 encodings, and include the empty name
+fn test_data() -> impl Strategy<Value = TestData> {
+    (any::<u32>(), vec(select(RANDOM_NAME_CHARS), 0..=64), any::<bool>())
+        .prop_map(|(id, name, active)| TestData { id, name: name.into_iter().collect(), active })
+}
+
+proptest! {
+    #[test]
+    fn every_format_roundtrips_test_data(data in test_data()) {
+        for (format, roundtrip) in TEST_DATA_CODECS {
+            let expected = if ASCII_ONLY.contains(format) && !data.name.is_ascii() { None } else { Some(data.clone()) };
+            prop_assert_eq!(roundtrip(&data), expected, "{}: TestData did not survive the roundtrip", format);
+        }
+    }
+}